@@ -1,3 +1,4 @@
+use bon::Builder;
 use reqwest::{
     Client as ReqwestClient, Method,
     header::{HeaderMap, HeaderValue},
@@ -9,6 +10,7 @@ use super::types::{
     SupportedAssetsResponse, WithdrawRequest, WithdrawResponse,
 };
 use crate::Result;
+use crate::retry::RetryPolicy;
 
 /// Client for the Polymarket Bridge API.
 ///
@@ -36,9 +38,22 @@ use crate::Result;
 /// # }
 /// ```
 #[derive(Clone, Debug)]
+#[expect(
+    clippy::struct_field_names,
+    reason = "`client` is the underlying HTTP client rather than a reference to this type"
+)]
 pub struct Client {
     host: Url,
     client: ReqwestClient,
+    config: Config,
+}
+
+/// Configuration for [`Client`]
+#[derive(Clone, Debug, Default, Builder)]
+pub struct Config {
+    /// How failed requests are retried. By default, only read-only `GET` requests are retried.
+    #[builder(default)]
+    retry: RetryPolicy,
 }
 
 impl Default for Client {
@@ -55,6 +70,15 @@ impl Client {
     ///
     /// Returns an error if the host URL is invalid or the HTTP client fails to build.
     pub fn new(host: &str) -> Result<Client> {
+        Self::with_config(host, Config::default())
+    }
+
+    /// Creates a new Bridge API client with a custom host URL and [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL is invalid or the HTTP client cannot be created.
+    pub fn with_config(host: &str, config: Config) -> Result<Client> {
         let mut headers = HeaderMap::new();
 
         headers.insert("User-Agent", HeaderValue::from_static("rs_clob_client"));
@@ -66,6 +90,7 @@ impl Client {
         Ok(Self {
             host: Url::parse(host)?,
             client,
+            config,
         })
     }
 
@@ -111,7 +136,7 @@ impl Client {
             .json(request)
             .build()?;
 
-        crate::request(&self.client, request, None).await
+        crate::request(&self.client, request, None, &self.config.retry, None).await
     }
 
     /// Generate unique deposit addresses for withdrawing USDC.e
@@ -123,7 +148,7 @@ impl Client {
             .json(request)
             .build()?;
 
-        crate::request(&self.client, request, None).await
+        crate::request(&self.client, request, None, &self.config.retry, None).await
     }
 
     /// Get all supported chains and tokens for deposits.
@@ -158,7 +183,7 @@ impl Client {
             .request(Method::GET, format!("{}supported-assets", self.host()))
             .build()?;
 
-        crate::request(&self.client, request, None).await
+        crate::request(&self.client, request, None, &self.config.retry, None).await
     }
 
     /// Get the transaction status for all deposits associated with a given deposit address.
@@ -199,7 +224,7 @@ impl Client {
             )
             .build()?;
 
-        crate::request(&self.client, request, None).await
+        crate::request(&self.client, request, None, &self.config.retry, None).await
     }
 
     /// Get an estimated quote for a deposit or withdrawal,
@@ -211,6 +236,6 @@ impl Client {
             .json(request)
             .build()?;
 
-        crate::request(&self.client, request, None).await
+        crate::request(&self.client, request, None, &self.config.retry, None).await
     }
 }
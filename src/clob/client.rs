@@ -1,21 +1,21 @@
-use std::borrow::Cow;
+use std::collections::HashMap;
 use std::marker::PhantomData;
 use std::mem;
-use std::sync::Arc;
-#[cfg(feature = "heartbeats")]
+use std::slice;
+use std::str::FromStr as _;
+use std::sync::{Arc, Mutex, PoisonError};
 use std::time::Duration;
 
-use alloy::dyn_abi::Eip712Domain;
-use alloy::primitives::U256;
+use alloy::primitives::{ChainId, U256};
 use alloy::signers::Signer;
 use alloy::sol_types::SolStruct as _;
 use async_stream::try_stream;
 use bon::Builder;
-use chrono::{NaiveDate, Utc};
+use chrono::{DateTime, NaiveDate, TimeDelta, Utc};
 use dashmap::DashMap;
-use futures::Stream;
+use futures::{Stream, TryStreamExt as _, future, stream};
 use reqwest::header::{HeaderMap, HeaderValue};
-use reqwest::{Client as ReqwestClient, Method, Request};
+use reqwest::{Client as ReqwestClient, Method, Request, StatusCode};
 use serde_json::json;
 #[cfg(all(feature = "tracing", feature = "heartbeats"))]
 use tracing::{debug, error};
@@ -27,23 +27,26 @@ use {tokio::sync::oneshot::Receiver, tokio::time, tokio_util::sync::Cancellation
 use crate::auth::builder::{Builder, Config as BuilderConfig};
 use crate::auth::state::{Authenticated, State, Unauthenticated};
 use crate::auth::{Credentials, Kind, Normal};
-use crate::clob::order_builder::{Limit, Market, OrderBuilder, generate_seed};
+use crate::clob::order_builder::{
+    Ladder, Limit, Market, OrderBuilder, USDC_DECIMALS, generate_seed,
+};
 use crate::clob::types::request::{
     BalanceAllowanceRequest, CancelMarketOrderRequest, DeleteNotificationsRequest,
     LastTradePriceRequest, MidpointRequest, OrderBookSummaryRequest, OrdersRequest,
-    PriceHistoryRequest, PriceRequest, SpreadRequest, TradesRequest, UpdateBalanceAllowanceRequest,
-    UserRewardsEarningRequest,
+    PriceHistoryRequest, PriceRequest, ReplaceOrderRequest, SpreadRequest, TradesRequest,
+    UpdateBalanceAllowanceRequest, UserRewardsEarningRequest,
 };
 use crate::clob::types::response::{
-    ApiKeysResponse, BalanceAllowanceResponse, BanStatusResponse, BuilderApiKeyResponse,
-    BuilderTradeResponse, CancelOrdersResponse, CurrentRewardResponse, FeeRateResponse,
-    GeoblockResponse, HeartbeatResponse, LastTradePriceResponse, LastTradesPricesResponse,
-    MarketResponse, MarketRewardResponse, MidpointResponse, MidpointsResponse, NegRiskResponse,
-    NotificationResponse, OpenOrderResponse, OrderBookSummaryResponse, OrderScoringResponse,
-    OrdersScoringResponse, Page, PostOrderResponse, PriceHistoryResponse, PriceResponse,
-    PricesResponse, RewardsPercentagesResponse, SimplifiedMarketResponse, SpreadResponse,
-    SpreadsResponse, TickSizeResponse, TotalUserEarningResponse, TradeResponse,
-    UserEarningResponse, UserRewardsEarningResponse,
+    ApiKeysResponse, BalanceAllowanceResponse, BanStatusResponse, BatchResult,
+    BuilderApiKeyResponse, BuilderTradeResponse, CancelOrdersResponse, CancelStatus,
+    CurrentRewardResponse, FeeRateResponse, GeoblockResponse, HeartbeatResponse,
+    LastTradePriceResponse, LastTradesPricesResponse, MarketResponse, MarketRewardResponse,
+    MidpointResponse, MidpointsResponse, NegRiskResponse, NotificationResponse, OpenOrderResponse,
+    OrderBookSummaryResponse, OrderScoringResponse, OrdersScoringResponse, Page, PostOrderResponse,
+    PriceHistoryResponse, PriceResponse, PricesResponse, ReplaceOrderResponse, Resubmission,
+    RewardsPercentagesResponse, SimplifiedMarketResponse, SpreadResponse, SpreadsResponse,
+    TickSizeResponse, TotalUserEarningResponse, TradeResponse, UserEarningResponse,
+    UserRewardsEarningResponse,
 };
 #[cfg(feature = "rfq")]
 use crate::clob::types::{
@@ -52,19 +55,30 @@ use crate::clob::types::{
     CreateRfqRequestRequest, CreateRfqRequestResponse, RfqQuote, RfqQuotesRequest, RfqRequest,
     RfqRequestsRequest,
 };
-use crate::clob::types::{SignableOrder, SignatureType, SignedOrder, TickSize};
-use crate::error::{Error, Kind as ErrorKind, Synchronization};
-use crate::types::Address;
+use crate::clob::types::{
+    AssetType, CacheEntry, CacheSnapshot, ClockOffset, InsufficientFunds, MarketState, OrderType,
+    ReplaceFailed, ReplaceStrategy, Side, SignableOrder, SignatureType, SignedOrder, TickSize,
+    exchange_domain,
+};
+use crate::error::{Error, Kind as ErrorKind, Status, Synchronization};
+use crate::rate_limit::RateLimiter;
+use crate::retry::RetryPolicy;
+use crate::types::{Address, Decimal};
 use crate::{
     AMOY, POLYGON, Result, Timestamp, ToQueryParams as _, auth, contract_config,
     derive_proxy_wallet, derive_safe_wallet,
 };
 
-const ORDER_NAME: Option<Cow<'static, str>> = Some(Cow::Borrowed("Polymarket CTF Exchange"));
-const VERSION: Option<Cow<'static, str>> = Some(Cow::Borrowed("1"));
-
 const TERMINAL_CURSOR: &str = "LTE="; // base64("-1")
 
+/// Maximum number of orders the CLOB accepts in a single [`Client::post_orders`] request, and the
+/// default for [`Config`]'s `post_orders_chunk_size`.
+pub const POST_ORDERS_CHUNK_SIZE: usize = 15;
+
+/// Maximum number of order IDs the CLOB accepts in a single [`Client::cancel_orders`] request,
+/// and the default for [`Config`]'s `cancel_orders_chunk_size`.
+pub const CANCEL_ORDERS_CHUNK_SIZE: usize = 3000;
+
 /// The type used to build a request to authenticate the inner [`Client<Unauthorized>`]. Calling
 /// `authenticate` on this will elevate that inner `client` into an [`Client<Authenticated<K>>`].
 pub struct AuthenticationBuilder<'signer, S: Signer, K: Kind = Normal> {
@@ -222,6 +236,10 @@ impl<S: Signer, K: Kind> AuthenticationBuilder<'_, S, K> {
                 tick_sizes: inner.tick_sizes,
                 neg_risk: inner.neg_risk,
                 fee_rate_bps: inner.fee_rate_bps,
+                market_states: inner.market_states,
+                clock_offset: inner.clock_offset,
+                clock_sync: inner.clock_sync,
+                chain_id,
                 funder,
                 signature_type: self.signature_type.unwrap_or(SignatureType::Eoa),
                 salt_generator: self.salt_generator.unwrap_or(generate_seed),
@@ -364,10 +382,36 @@ pub struct Config {
     /// headers. This adds another round trip to the requests.
     #[builder(default)]
     use_server_time: bool,
+    /// How often the [`Client`] re-measures its clock offset from the server. When set, auth
+    /// headers use the local time corrected by the last measured [`ClockOffset`] instead of a
+    /// round trip per request, and the offset is measured again once it is older than this.
+    /// Only one request re-measures a stale offset at a time, while the others sign with the last
+    /// one, which is also kept if re-measuring fails. Takes precedence over `use_server_time`.
+    clock_sync_interval: Option<Duration>,
+    /// How far the local clock may be from the server's before a measured [`ClockOffset`] is
+    /// flagged as skewed. Defaults to five (5) seconds.
+    #[builder(default = DEFAULT_CLOCK_SKEW_THRESHOLD)]
+    clock_skew_threshold: Duration,
     /// Override for the geoblock API host. Defaults to `https://polymarket.com`.
     /// This is primarily useful for testing.
     #[builder(into)]
     geoblock_host: Option<String>,
+    /// How failed requests are retried. By default, only read-only `GET` requests are retried.
+    #[builder(default)]
+    retry: RetryPolicy,
+    /// Client-side rate limiter applied before each request. Requests are not limited by default.
+    rate_limiter: Option<RateLimiter>,
+    /// How long cached tick sizes, neg risk flags and fee rates remain valid before they are
+    /// fetched again. Cached entries never expire by default.
+    cache_ttl: Option<Duration>,
+    /// How many orders [`Client::post_orders_chunked`] sends per request. Defaults to
+    /// [`POST_ORDERS_CHUNK_SIZE`].
+    #[builder(default = POST_ORDERS_CHUNK_SIZE)]
+    post_orders_chunk_size: usize,
+    /// How many order IDs [`Client::cancel_orders_chunked`] sends per request. Defaults to
+    /// [`CANCEL_ORDERS_CHUNK_SIZE`].
+    #[builder(default = CANCEL_ORDERS_CHUNK_SIZE)]
+    cancel_orders_chunk_size: usize,
     #[cfg(feature = "heartbeats")]
     #[builder(default = Duration::from_secs(5))]
     /// How often the [`Client`] will automatically submit heartbeats. The default is five (5) seconds.
@@ -377,6 +421,12 @@ pub struct Config {
 /// The default geoblock API host (separate from CLOB host)
 const DEFAULT_GEOBLOCK_HOST: &str = "https://polymarket.com";
 
+/// The default [`Config`] `clock_skew_threshold`
+const DEFAULT_CLOCK_SKEW_THRESHOLD: Duration = Duration::from_secs(5);
+
+/// The maximum number of tokens [`Client::warm_caches`] fetches at once
+const WARM_CACHES_CONCURRENCY: usize = 16;
+
 #[derive(Debug)]
 struct ClientInner<S: State> {
     config: Config,
@@ -389,11 +439,21 @@ struct ClientInner<S: State> {
     /// The inner [`ReqwestClient`] used to make requests to `host`.
     client: ReqwestClient,
     /// Local cache of [`TickSize`] per token ID
-    tick_sizes: DashMap<U256, TickSize>,
+    tick_sizes: DashMap<U256, CacheEntry<TickSize>>,
     /// Local cache representing whether this token is part of a `neg_risk` market
-    neg_risk: DashMap<U256, bool>,
+    neg_risk: DashMap<U256, CacheEntry<bool>>,
     /// Local cache representing the fee rate in basis points per token ID
-    fee_rate_bps: DashMap<U256, u32>,
+    fee_rate_bps: DashMap<U256, CacheEntry<u32>>,
+    /// Local cache of the [`MarketState`] of the market each token ID belongs to
+    market_states: DashMap<U256, CacheEntry<MarketState>>,
+    /// The last [`ClockOffset`] measured against the server clock
+    clock_offset: Mutex<Option<ClockOffset>>,
+    /// Held while a stale [`ClockOffset`] is re-measured for signing, so that only one request
+    /// re-measures it at a time
+    clock_sync: tokio::sync::Mutex<()>,
+    /// The chain of the signer this client authenticated with. Defaults to [`POLYGON`] before
+    /// authentication.
+    chain_id: ChainId,
     /// The funder for this [`ClientInner`]. If funder is present, then `signature_type` cannot
     /// be [`SignatureType::Eoa`]. Conversely, if funder is absent, then `signature_type` cannot be
     /// [`SignatureType::Proxy`] or [`SignatureType::GnosisSafe`].
@@ -411,7 +471,121 @@ impl<S: State> ClientInner<S> {
             .request(Method::GET, format!("{}time", self.host))
             .build()?;
 
-        crate::request(&self.client, request, None).await
+        crate::request(
+            &self.client,
+            request,
+            None,
+            &self.config.retry,
+            self.config.rate_limiter.as_ref(),
+        )
+        .await
+    }
+
+    /// Measures the offset of the server clock the way NTP does, assuming the server read its
+    /// clock halfway through the round trip.
+    async fn sync_clock(&self) -> Result<ClockOffset> {
+        let sent_at = Utc::now();
+        let server_time = self.server_time().await?;
+        let received_at = Utc::now();
+
+        let round_trip = received_at - sent_at;
+        // `/time` truncates to whole seconds, so the server read its clock half a second later
+        // than reported on average.
+        let server_now = DateTime::from_timestamp(server_time, 0).ok_or(Error::validation(
+            format!("Invalid server timestamp {server_time}"),
+        ))? + TimeDelta::milliseconds(500);
+        let offset = server_now - (sent_at + round_trip / 2);
+        let skewed =
+            offset.abs().to_std().unwrap_or(Duration::MAX) > self.config.clock_skew_threshold;
+
+        let mut last = self
+            .clock_offset
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let measured = ClockOffset {
+            offset,
+            round_trip,
+            drift: last.map(|previous| offset - previous.offset),
+            measured_at: received_at,
+            skewed,
+        };
+        *last = Some(measured);
+        drop(last);
+
+        #[cfg(feature = "tracing")]
+        if skewed {
+            tracing::warn!(
+                offset_ms = offset.num_milliseconds(),
+                threshold_ms = self.config.clock_skew_threshold.as_millis(),
+                "local clock is skewed from the CLOB server clock"
+            );
+        }
+
+        Ok(measured)
+    }
+
+    fn clock_offset(&self) -> Option<ClockOffset> {
+        *self
+            .clock_offset
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// The local time, corrected by the last measured [`ClockOffset`] if any.
+    fn corrected_now(&self) -> DateTime<Utc> {
+        let offset = self
+            .clock_offset()
+            .map_or(TimeDelta::zero(), |clock| clock.offset);
+        Utc::now() + offset
+    }
+
+    /// The timestamp to sign auth headers with, as chosen by [`Config`].
+    async fn timestamp(&self) -> Result<Timestamp> {
+        if let Some(interval) = self.config.clock_sync_interval {
+            let stale = |offset: Option<ClockOffset>| {
+                offset.is_none_or(|clock| {
+                    (Utc::now() - clock.measured_at)
+                        .to_std()
+                        .is_ok_and(|age| age >= interval)
+                })
+            };
+            if stale(self.clock_offset()) {
+                let guard = match self.clock_sync.try_lock() {
+                    Ok(guard) => Some(guard),
+                    // Another request is re-measuring, so sign with the last offset
+                    Err(_) if self.clock_offset().is_some() => None,
+                    // There is no offset to sign with until the first measurement completes,
+                    // which is retried here if it failed
+                    Err(_) => Some(self.clock_sync.lock().await),
+                };
+
+                // The offset may have just been re-measured by the request holding the lock
+                if guard.is_some() && stale(self.clock_offset()) {
+                    match self.sync_clock().await {
+                        Ok(_) => {}
+                        // A failed re-measurement keeps signing with the last offset
+                        Err(e) if self.clock_offset().is_some() => {
+                            #[cfg(feature = "tracing")]
+                            tracing::warn!(
+                                error = %e,
+                                "unable to re-measure the clock offset, using the last one"
+                            );
+                            #[cfg(not(feature = "tracing"))]
+                            let _: &_ = &e;
+                        }
+                        Err(e) => return Err(e),
+                    }
+                }
+            }
+
+            return Ok(self.corrected_now().timestamp());
+        }
+
+        if self.config.use_server_time {
+            self.server_time().await
+        } else {
+            Ok(Utc::now().timestamp())
+        }
     }
 }
 
@@ -427,7 +601,14 @@ impl ClientInner<Unauthenticated> {
             .build()?;
         let headers = self.create_headers(signer, nonce).await?;
 
-        crate::request(&self.client, request, Some(headers)).await
+        crate::request(
+            &self.client,
+            request,
+            Some(headers),
+            &self.config.retry,
+            self.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     pub async fn derive_api_key<S: Signer>(
@@ -441,7 +622,14 @@ impl ClientInner<Unauthenticated> {
             .build()?;
         let headers = self.create_headers(signer, nonce).await?;
 
-        crate::request(&self.client, request, Some(headers)).await
+        crate::request(
+            &self.client,
+            request,
+            Some(headers),
+            &self.config.retry,
+            self.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     async fn create_or_derive_api_key<S: Signer>(
@@ -465,16 +653,16 @@ impl ClientInner<Unauthenticated> {
             "Chain id not set, be sure to provide one on the signer",
         ))?;
 
-        let timestamp = if self.config.use_server_time {
-            self.server_time().await?
-        } else {
-            Utc::now().timestamp()
-        };
+        let timestamp = self.timestamp().await?;
 
         auth::l1::create_headers(signer, chain_id, timestamp, nonce).await
     }
 }
 
+#[expect(
+    clippy::same_name_method,
+    reason = "The market data trait mirrors these methods so callers can swap in other backends"
+)]
 impl<S: State> Client<S> {
     /// Returns the CLOB API host URL.
     ///
@@ -493,7 +681,7 @@ impl<S: State> Client<S> {
         &self.inner.host
     }
 
-    /// Invalidates all internal caches (tick sizes, neg risk flags, and fee rates).
+    /// Invalidates all internal caches (tick sizes, neg risk flags, fee rates, and market states).
     ///
     /// This method clears the cached market configuration data, forcing subsequent
     /// requests to fetch fresh data from the API. Use this when you suspect
@@ -502,6 +690,87 @@ impl<S: State> Client<S> {
         self.inner.tick_sizes.clear();
         self.inner.fee_rate_bps.clear();
         self.inner.neg_risk.clear();
+        self.inner.market_states.clear();
+    }
+
+    /// Returns a copy of the tick size, neg risk and fee rate caches, excluding expired entries.
+    ///
+    /// Persist the snapshot with [`CacheSnapshot::save`] or [`CacheSnapshot::to_bytes`] and
+    /// restore it on the next start with [`Client::import_caches`].
+    #[must_use]
+    pub fn export_caches(&self) -> CacheSnapshot {
+        let ttl = self.inner.config.cache_ttl;
+
+        CacheSnapshot {
+            tick_sizes: fresh_entries(&self.inner.tick_sizes, ttl),
+            neg_risk: fresh_entries(&self.inner.neg_risk, ttl),
+            fee_rate_bps: fresh_entries(&self.inner.fee_rate_bps, ttl),
+            market_states: fresh_entries(&self.inner.market_states, ttl),
+        }
+    }
+
+    /// Loads a snapshot produced by [`Client::export_caches`] into the internal caches.
+    ///
+    /// Entries keep their original `cached_at` time, so the configured cache TTL still applies
+    /// and expired entries are skipped. Existing entries for the same token are replaced.
+    pub fn import_caches(&self, snapshot: CacheSnapshot) {
+        let ttl = self.inner.config.cache_ttl;
+
+        for (token_id, entry) in snapshot.tick_sizes {
+            if entry.is_fresh(ttl) {
+                self.inner.tick_sizes.insert(token_id, entry);
+            }
+        }
+        for (token_id, entry) in snapshot.neg_risk {
+            if entry.is_fresh(ttl) {
+                self.inner.neg_risk.insert(token_id, entry);
+            }
+        }
+        for (token_id, entry) in snapshot.fee_rate_bps {
+            if entry.is_fresh(ttl) {
+                self.inner.fee_rate_bps.insert(token_id, entry);
+            }
+        }
+        for (token_id, entry) in snapshot.market_states {
+            if entry.is_fresh(ttl) {
+                self.inner.market_states.insert(token_id, entry);
+            }
+        }
+    }
+
+    /// Fetches the tick size, neg risk flag and fee rate for every token in `token_ids`
+    /// concurrently, populating the internal caches. Tokens that are already cached are not
+    /// requested again.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error encountered while fetching. Values fetched before the error
+    /// remain cached.
+    pub async fn warm_caches(&self, token_ids: &[U256]) -> Result<()> {
+        stream::iter(token_ids.iter().copied().map(Ok))
+            .try_for_each_concurrent(WARM_CACHES_CONCURRENCY, |token_id| async move {
+                future::try_join3(
+                    self.tick_size(token_id),
+                    self.neg_risk(token_id),
+                    self.fee_rate_bps(token_id),
+                )
+                .await
+                .map(drop)
+            })
+            .await
+    }
+
+    /// Returns the cached value for `token_id` if present and not expired, dropping expired
+    /// entries from `cache`.
+    fn cached<T: Copy>(&self, cache: &DashMap<U256, CacheEntry<T>>, token_id: U256) -> Option<T> {
+        let ttl = self.inner.config.cache_ttl;
+        let entry = *cache.get(&token_id)?;
+        if entry.is_fresh(ttl) {
+            return Some(entry.value);
+        }
+
+        cache.remove_if(&token_id, |_, entry| !entry.is_fresh(ttl));
+        None
     }
 
     /// Pre-populates the tick size cache for a token, avoiding the HTTP call.
@@ -522,7 +791,15 @@ impl<S: State> Client<S> {
     /// # }
     /// ```
     pub fn set_tick_size(&self, token_id: U256, tick_size: TickSize) {
-        self.inner.tick_sizes.insert(token_id, tick_size);
+        self.inner
+            .tick_sizes
+            .insert(token_id, CacheEntry::new(tick_size));
+    }
+
+    /// Drops the cached tick size for a token, so that it is fetched again when next needed.
+    #[cfg(feature = "ws")]
+    pub(crate) fn invalidate_tick_size(&self, token_id: U256) {
+        self.inner.tick_sizes.remove(&token_id);
     }
 
     /// Pre-populates the neg risk cache for a token, avoiding the HTTP call.
@@ -543,7 +820,9 @@ impl<S: State> Client<S> {
     /// # }
     /// ```
     pub fn set_neg_risk(&self, token_id: U256, neg_risk: bool) {
-        self.inner.neg_risk.insert(token_id, neg_risk);
+        self.inner
+            .neg_risk
+            .insert(token_id, CacheEntry::new(neg_risk));
     }
 
     /// Pre-populates the fee rate cache for a token, avoiding the HTTP call.
@@ -565,7 +844,115 @@ impl<S: State> Client<S> {
     /// # }
     /// ```
     pub fn set_fee_rate_bps(&self, token_id: U256, fee_rate_bps: u32) {
-        self.inner.fee_rate_bps.insert(token_id, fee_rate_bps);
+        self.inner
+            .fee_rate_bps
+            .insert(token_id, CacheEntry::new(fee_rate_bps));
+    }
+
+    /// Pre-populates the market state cache for a token, avoiding the HTTP calls.
+    ///
+    /// Use this when you already have the market data from another source, such as a Gamma
+    /// market, which converts into a [`MarketState`] with the `gamma` feature.
+    pub fn set_market_state(&self, token_id: U256, market_state: MarketState) {
+        self.inner
+            .market_states
+            .insert(token_id, CacheEntry::new(market_state));
+    }
+
+    /// Retrieves whether the market a token belongs to can take orders, and its minimum order
+    /// size.
+    ///
+    /// Results are cached internally. On a cache miss, the token's market is looked up from its
+    /// order book and fetched with [`Client::market`], which caches the state for every token of
+    /// that market. Closed markets no longer have an order book, so their state can only be
+    /// resolved this way once the market has been fetched by condition ID with
+    /// [`Client::market`], or set with [`Client::set_market_state`].
+    ///
+    /// If the token has no order book, the returned state has `enable_order_book` and
+    /// `accepting_orders` unset. Its other flags are unknown and left permissive, and it is not
+    /// cached.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the requests fail or the market does not list the token.
+    pub async fn market_state(&self, token_id: U256) -> Result<MarketState> {
+        if let Some(market_state) = self.cached(&self.inner.market_states, token_id) {
+            #[cfg(feature = "tracing")]
+            tracing::trace!(token_id = %token_id, "cache hit: market_state");
+            return Ok(market_state);
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::trace!(token_id = %token_id, "cache miss: market_state");
+
+        let book = match self
+            .order_book(&OrderBookSummaryRequest {
+                token_id,
+                side: None,
+            })
+            .await
+        {
+            Ok(book) => book,
+            Err(e)
+                if e.downcast_ref::<Status>()
+                    .is_some_and(|status| status.status_code == StatusCode::NOT_FOUND) =>
+            {
+                return Ok(MarketState {
+                    active: true,
+                    closed: false,
+                    enable_order_book: false,
+                    accepting_orders: false,
+                    minimum_order_size: Decimal::ZERO,
+                });
+            }
+            Err(e) => return Err(e),
+        };
+        let market = self.market(&book.market.to_string()).await?;
+
+        if market.tokens.iter().any(|token| token.token_id == token_id) {
+            Ok(MarketState::from(&market))
+        } else {
+            Err(Error::validation(format!(
+                "Market {} does not list token {token_id}",
+                book.market
+            )))
+        }
+    }
+
+    /// Checks that `order` is allowed by the [`MarketState`] of its token, which is fetched with
+    /// [`Client::market_state`] unless cached.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`ErrorKind::Validation`] wrapping
+    /// [`MarketStateViolation`](crate::clob::types::MarketStateViolation) if the market is closed,
+    /// inactive, has no order book or is not accepting orders, or if the order is below the
+    /// minimum order size. Returns an error if fetching the market state fails.
+    pub async fn check_market(&self, order: &SignableOrder) -> Result<()> {
+        self.check_market_orders(slice::from_ref(order)).await
+    }
+
+    /// Checks that `orders`, which are all for the same token, are allowed by its market state.
+    /// See [`check_market`](Self::check_market).
+    pub(crate) async fn check_market_orders(&self, orders: &[SignableOrder]) -> Result<()> {
+        let Some(first) = orders.first() else {
+            return Ok(());
+        };
+        let token_id = first.order.tokenId;
+        let market_state = self.market_state(token_id).await?;
+
+        for order in orders {
+            let size = match order.size {
+                Some(size) => size,
+                None => order.order.size()?,
+            };
+
+            market_state
+                .check(token_id, size)
+                .map_err(|violation| Error::with_source(ErrorKind::Validation, violation))?;
+        }
+
+        Ok(())
     }
 
     /// Checks if the CLOB API is healthy and operational.
@@ -582,7 +969,14 @@ impl<S: State> Client<S> {
             .request(Method::GET, self.host().to_owned())
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        crate::request(
+            &self.inner.client,
+            request,
+            None,
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Returns the current server timestamp in milliseconds since Unix epoch.
@@ -594,6 +988,34 @@ impl<S: State> Client<S> {
         self.inner.server_time().await
     }
 
+    /// Measures how far the server clock is from the local clock, estimating the round-trip time
+    /// the way NTP does, and stores the result for [`Self::corrected_now`].
+    ///
+    /// When [`Config`]'s `clock_sync_interval` is set this happens automatically whenever the
+    /// last measurement is older than the interval. A warning is logged (with the `tracing`
+    /// feature) when the offset exceeds `clock_skew_threshold`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn sync_clock(&self) -> Result<ClockOffset> {
+        self.inner.sync_clock().await
+    }
+
+    /// Returns the last [`ClockOffset`] measured by [`Self::sync_clock`], including its drift
+    /// from the measurement before it, or `None` if the clock has never been synced.
+    #[must_use]
+    pub fn clock_offset(&self) -> Option<ClockOffset> {
+        self.inner.clock_offset()
+    }
+
+    /// Returns the local time corrected by the last measured [`ClockOffset`], or the local time
+    /// unchanged if the clock has never been synced.
+    #[must_use]
+    pub fn corrected_now(&self) -> DateTime<Utc> {
+        self.inner.corrected_now()
+    }
+
     /// Retrieves the midpoint price for a single market outcome token.
     ///
     /// The midpoint is the average of the best bid and best ask prices,
@@ -610,7 +1032,14 @@ impl<S: State> Client<S> {
             .request(Method::GET, format!("{}midpoint{params}", self.host()))
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        crate::request(
+            &self.inner.client,
+            request,
+            None,
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Retrieves midpoint prices for multiple market outcome tokens in a single request.
@@ -628,7 +1057,14 @@ impl<S: State> Client<S> {
             .json(requests)
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        crate::request(
+            &self.inner.client,
+            request,
+            None,
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Retrieves the current price for a market outcome token on a specific side.
@@ -646,7 +1082,14 @@ impl<S: State> Client<S> {
             .request(Method::GET, format!("{}price{params}", self.host()))
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        crate::request(
+            &self.inner.client,
+            request,
+            None,
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Retrieves prices for multiple market outcome tokens on their specific sides.
@@ -664,7 +1107,14 @@ impl<S: State> Client<S> {
             .json(requests)
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        crate::request(
+            &self.inner.client,
+            request,
+            None,
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Retrieves prices for all available market outcome tokens.
@@ -681,7 +1131,14 @@ impl<S: State> Client<S> {
             .request(Method::GET, format!("{}prices", self.host()))
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        crate::request(
+            &self.inner.client,
+            request,
+            None,
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Retrieves historical price data for a market outcome token.
@@ -702,7 +1159,14 @@ impl<S: State> Client<S> {
             format!("{}prices-history{params}", self.host()),
         );
 
-        crate::request(&self.inner.client, req.build()?, None).await
+        crate::request(
+            &self.inner.client,
+            req.build()?,
+            None,
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Retrieves the bid-ask spread for a single market outcome token.
@@ -721,7 +1185,14 @@ impl<S: State> Client<S> {
             .request(Method::GET, format!("{}spread{params}", self.host()))
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        crate::request(
+            &self.inner.client,
+            request,
+            None,
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Retrieves bid-ask spreads for multiple market outcome tokens.
@@ -739,7 +1210,14 @@ impl<S: State> Client<S> {
             .json(requests)
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        crate::request(
+            &self.inner.client,
+            request,
+            None,
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Retrieves the minimum tick size for a market outcome token.
@@ -752,11 +1230,11 @@ impl<S: State> Client<S> {
     ///
     /// Returns an error if the request fails or the token ID is invalid.
     pub async fn tick_size(&self, token_id: U256) -> Result<TickSizeResponse> {
-        if let Some(tick_size) = self.inner.tick_sizes.get(&token_id) {
+        if let Some(tick_size) = self.cached(&self.inner.tick_sizes, token_id) {
             #[cfg(feature = "tracing")]
-            tracing::trace!(token_id = %token_id, tick_size = ?tick_size.value(), "cache hit: tick_size");
+            tracing::trace!(token_id = %token_id, tick_size = ?tick_size, "cache hit: tick_size");
             return Ok(TickSizeResponse {
-                minimum_tick_size: *tick_size,
+                minimum_tick_size: tick_size,
             });
         }
 
@@ -769,12 +1247,18 @@ impl<S: State> Client<S> {
             .query(&[("token_id", token_id.to_string())])
             .build()?;
 
-        let response =
-            crate::request::<TickSizeResponse>(&self.inner.client, request, None).await?;
+        let response = crate::request::<TickSizeResponse>(
+            &self.inner.client,
+            request,
+            None,
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await?;
 
         self.inner
             .tick_sizes
-            .insert(token_id, response.minimum_tick_size);
+            .insert(token_id, CacheEntry::new(response.minimum_tick_size));
 
         #[cfg(feature = "tracing")]
         tracing::trace!(token_id = %token_id, "cached tick_size");
@@ -792,12 +1276,10 @@ impl<S: State> Client<S> {
     ///
     /// Returns an error if the request fails or the token ID is invalid.
     pub async fn neg_risk(&self, token_id: U256) -> Result<NegRiskResponse> {
-        if let Some(neg_risk) = self.inner.neg_risk.get(&token_id) {
+        if let Some(neg_risk) = self.cached(&self.inner.neg_risk, token_id) {
             #[cfg(feature = "tracing")]
-            tracing::trace!(token_id = %token_id, neg_risk = *neg_risk, "cache hit: neg_risk");
-            return Ok(NegRiskResponse {
-                neg_risk: *neg_risk,
-            });
+            tracing::trace!(token_id = %token_id, neg_risk = neg_risk, "cache hit: neg_risk");
+            return Ok(NegRiskResponse { neg_risk });
         }
 
         #[cfg(feature = "tracing")]
@@ -809,9 +1291,18 @@ impl<S: State> Client<S> {
             .query(&[("token_id", token_id.to_string())])
             .build()?;
 
-        let response = crate::request::<NegRiskResponse>(&self.inner.client, request, None).await?;
+        let response = crate::request::<NegRiskResponse>(
+            &self.inner.client,
+            request,
+            None,
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await?;
 
-        self.inner.neg_risk.insert(token_id, response.neg_risk);
+        self.inner
+            .neg_risk
+            .insert(token_id, CacheEntry::new(response.neg_risk));
 
         #[cfg(feature = "tracing")]
         tracing::trace!(token_id = %token_id, "cached neg_risk");
@@ -828,12 +1319,10 @@ impl<S: State> Client<S> {
     ///
     /// Returns an error if the request fails or the token ID is invalid.
     pub async fn fee_rate_bps(&self, token_id: U256) -> Result<FeeRateResponse> {
-        if let Some(base_fee) = self.inner.fee_rate_bps.get(&token_id) {
+        if let Some(base_fee) = self.cached(&self.inner.fee_rate_bps, token_id) {
             #[cfg(feature = "tracing")]
-            tracing::trace!(token_id = %token_id, base_fee = *base_fee, "cache hit: fee_rate_bps");
-            return Ok(FeeRateResponse {
-                base_fee: *base_fee,
-            });
+            tracing::trace!(token_id = %token_id, base_fee = base_fee, "cache hit: fee_rate_bps");
+            return Ok(FeeRateResponse { base_fee });
         }
 
         #[cfg(feature = "tracing")]
@@ -845,9 +1334,18 @@ impl<S: State> Client<S> {
             .query(&[("token_id", token_id.to_string())])
             .build()?;
 
-        let response = crate::request::<FeeRateResponse>(&self.inner.client, request, None).await?;
+        let response = crate::request::<FeeRateResponse>(
+            &self.inner.client,
+            request,
+            None,
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await?;
 
-        self.inner.fee_rate_bps.insert(token_id, response.base_fee);
+        self.inner
+            .fee_rate_bps
+            .insert(token_id, CacheEntry::new(response.base_fee));
 
         #[cfg(feature = "tracing")]
         tracing::trace!(token_id = %token_id, "cached fee_rate_bps");
@@ -908,7 +1406,14 @@ impl<S: State> Client<S> {
             )
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        crate::request(
+            &self.inner.client,
+            request,
+            None,
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Retrieves the full orderbook for a market outcome token.
@@ -930,7 +1435,14 @@ impl<S: State> Client<S> {
             .request(Method::GET, format!("{}book{params}", self.host()))
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        crate::request(
+            &self.inner.client,
+            request,
+            None,
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Retrieves orderbooks for multiple market outcome tokens.
@@ -951,7 +1463,14 @@ impl<S: State> Client<S> {
             .json(requests)
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        crate::request(
+            &self.inner.client,
+            request,
+            None,
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Retrieves the price of the most recent trade for a market outcome token.
@@ -975,7 +1494,14 @@ impl<S: State> Client<S> {
             )
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        crate::request(
+            &self.inner.client,
+            request,
+            None,
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Retrieves the last trade prices for multiple market outcome tokens.
@@ -996,7 +1522,14 @@ impl<S: State> Client<S> {
             .json(token_ids)
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        crate::request(
+            &self.inner.client,
+            request,
+            None,
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Retrieves detailed information for a single market by condition ID.
@@ -1016,7 +1549,21 @@ impl<S: State> Client<S> {
             )
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        let response = crate::request::<MarketResponse>(
+            &self.inner.client,
+            request,
+            None,
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await?;
+
+        let market_state = MarketState::from(&response);
+        for token in &response.tokens {
+            self.set_market_state(token.token_id, market_state);
+        }
+
+        Ok(response)
     }
 
     /// Retrieves a page of all active markets.
@@ -1035,7 +1582,14 @@ impl<S: State> Client<S> {
             .request(Method::GET, format!("{}markets{cursor}", self.host()))
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        crate::request(
+            &self.inner.client,
+            request,
+            None,
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Retrieves a page of sampling markets.
@@ -1060,7 +1614,14 @@ impl<S: State> Client<S> {
             )
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        crate::request(
+            &self.inner.client,
+            request,
+            None,
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Retrieves a page of simplified market data.
@@ -1085,7 +1646,14 @@ impl<S: State> Client<S> {
             )
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        crate::request(
+            &self.inner.client,
+            request,
+            None,
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Retrieves a page of simplified sampling market data.
@@ -1110,7 +1678,14 @@ impl<S: State> Client<S> {
             )
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        crate::request(
+            &self.inner.client,
+            request,
+            None,
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Returns a stream of results, using `self` to repeatedly invoke the provided closure,
@@ -1201,6 +1776,10 @@ impl Client<Unauthenticated> {
                 tick_sizes: DashMap::new(),
                 neg_risk: DashMap::new(),
                 fee_rate_bps: DashMap::new(),
+                market_states: DashMap::new(),
+                clock_offset: Mutex::new(None),
+                clock_sync: tokio::sync::Mutex::new(()),
+                chain_id: POLYGON,
                 state: Unauthenticated,
                 funder: None,
                 signature_type: SignatureType::Eoa,
@@ -1287,6 +1866,10 @@ impl Client<Unauthenticated> {
     }
 }
 
+#[expect(
+    clippy::same_name_method,
+    reason = "The trading trait mirrors these methods so callers can swap in other backends"
+)]
 impl<K: Kind> Client<Authenticated<K>> {
     /// Demotes this authenticated [`Client<Authenticated<K>>`] to an unauthenticated one
     #[cfg_attr(
@@ -1313,7 +1896,11 @@ impl<K: Kind> Client<Authenticated<K>> {
                 tick_sizes: inner.tick_sizes,
                 neg_risk: inner.neg_risk,
                 fee_rate_bps: inner.fee_rate_bps,
+                market_states: inner.market_states,
+                clock_offset: inner.clock_offset,
+                clock_sync: inner.clock_sync,
                 // Reset the order parameters that were previously stored on the client
+                chain_id: POLYGON,
                 funder: None,
                 signature_type: SignatureType::Eoa,
                 salt_generator: generate_seed,
@@ -1359,7 +1946,14 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        crate::request(
+            &self.inner.client,
+            request,
+            Some(headers),
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Deletes the current API key used by this authenticated client.
@@ -1377,7 +1971,14 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        crate::request(
+            &self.inner.client,
+            request,
+            Some(headers),
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Checks if the account is in closed-only mode (banned from opening new positions).
@@ -1399,7 +2000,14 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        crate::request(
+            &self.inner.client,
+            request,
+            Some(headers),
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Creates an [`OrderBuilder<Limit, K>`] used to construct a limit order.
@@ -1414,7 +2022,17 @@ impl<K: Kind> Client<Authenticated<K>> {
         self.order_builder()
     }
 
+    /// Creates an [`OrderBuilder<Ladder, K>`] used to construct a ladder of limit orders at evenly
+    /// spaced prices, for posting together with [`post_orders`](Self::post_orders).
+    #[must_use]
+    pub fn ladder_order(&self) -> OrderBuilder<Ladder, K> {
+        self.order_builder()
+    }
+
     /// Attempts to sign the provided [`SignableOrder`] using the inner signer of [`Authenticated<K>`]
+    ///
+    /// Whether the order's market is a neg risk market is fetched from the CLOB, unless the order
+    /// already carries it (see [`OrderBuilder::neg_risk`]).
     #[expect(
         clippy::missing_panics_doc,
         reason = "No need to publicly document as we are guarded by the typestate pattern. \
@@ -1427,25 +2045,19 @@ impl<K: Kind> Client<Authenticated<K>> {
             order,
             order_type,
             post_only,
+            neg_risk,
+            ..
         }: SignableOrder,
     ) -> Result<SignedOrder> {
-        let token_id = order.tokenId;
-        let neg_risk = self.neg_risk(token_id).await?.neg_risk;
+        let neg_risk = match neg_risk {
+            Some(neg_risk) => neg_risk,
+            None => self.neg_risk(order.tokenId).await?.neg_risk,
+        };
         let chain_id = signer
             .chain_id()
             .expect("Validated not none in `authenticate`");
 
-        let exchange_contract = contract_config(chain_id, neg_risk)
-            .ok_or(Error::missing_contract_config(chain_id, neg_risk))?
-            .exchange;
-
-        let domain = Eip712Domain {
-            name: ORDER_NAME,
-            version: VERSION,
-            chain_id: Some(U256::from(chain_id)),
-            verifying_contract: Some(exchange_contract),
-            ..Eip712Domain::default()
-        };
+        let domain = exchange_domain(chain_id, neg_risk)?;
 
         let signature = signer
             .sign_hash(&order.eip712_signing_hash(&domain))
@@ -1457,6 +2069,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             order_type,
             owner: self.state().credentials.key,
             post_only,
+            neg_risk: Some(neg_risk),
         })
     }
 
@@ -1481,19 +2094,120 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        crate::request(
+            &self.inner.client,
+            request,
+            Some(headers),
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
+    }
+
+    /// Posts a signed order unless the CLOB already knows it, such as when a previous
+    /// [`post_order`](Self::post_order) timed out after the CLOB accepted it.
+    ///
+    /// The order ID is precomputed from the order's hash under the exchange domain of the chain
+    /// this client authenticated on, and looked up with [`order`](Self::order) before posting. The
+    /// neg risk flag the order was signed with is used when known, and fetched otherwise. An
+    /// order that has since been matched or canceled is not posted again either. Only an order
+    /// the CLOB reports as not found is posted. Orders built with a
+    /// [`client_order_id`](OrderBuilder::client_order_id) can be rebuilt and re-signed
+    /// identically for this.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order ID cannot be computed, or if any request fails other than
+    /// the lookup of an order that is not found.
+    pub async fn resubmit_order(&self, order: SignedOrder) -> Result<Resubmission> {
+        let neg_risk = match order.neg_risk {
+            Some(neg_risk) => neg_risk,
+            None => self.neg_risk(order.order.tokenId).await?.neg_risk,
+        };
+        let order_id = order.order_id(self.inner.chain_id, neg_risk)?;
+
+        match self.order(&order_id).await {
+            Ok(existing) => Ok(Resubmission::Existing(existing)),
+            Err(e)
+                if e.downcast_ref::<Status>()
+                    .is_some_and(|status| status.status_code == StatusCode::NOT_FOUND) =>
+            {
+                Ok(Resubmission::Posted(self.post_order(order).await?))
+            }
+            Err(e) => Err(e),
+        }
     }
 
     /// Posts multiple signed orders to the orderbook in a single request.
     ///
     /// This is the batch version of [`Self::post_order`], allowing efficient
     /// submission of multiple orders at once. All orders are validated and
-    /// processed atomically.
+    /// processed atomically. The CLOB rejects batches of more than
+    /// [`POST_ORDERS_CHUNK_SIZE`] orders, see [`Self::post_orders_chunked`] for larger ones.
     ///
     /// # Errors
     ///
     /// Returns an error if any order fails validation or the request fails.
     pub async fn post_orders(&self, orders: Vec<SignedOrder>) -> Result<Vec<PostOrderResponse>> {
+        self.post_order_batch(&orders).await
+    }
+
+    /// Posts any number of signed orders, split into concurrent requests of at most
+    /// [`Config`]'s `post_orders_chunk_size` orders each, and pairs each order with its
+    /// [`PostOrderResponse`].
+    ///
+    /// A rejected order still has a response, with `success` unset and an `error_msg`. Only
+    /// the orders of a chunk whose request failed have an error.
+    pub async fn post_orders_chunked(
+        &self,
+        orders: Vec<SignedOrder>,
+    ) -> Vec<BatchResult<SignedOrder, PostOrderResponse>> {
+        let chunk_size = self.inner.config.post_orders_chunk_size.max(1);
+        let chunks = into_chunks(orders, chunk_size);
+        let responses =
+            future::join_all(chunks.iter().map(|chunk| self.post_order_batch(chunk))).await;
+
+        chunks
+            .into_iter()
+            .zip(responses)
+            .flat_map(|(chunk, response)| {
+                let response = response.and_then(|responses| {
+                    if responses.len() == chunk.len() {
+                        Ok(responses)
+                    } else {
+                        Err(Error::validation(format!(
+                            "Expected {} responses to posted orders, got {}",
+                            chunk.len(),
+                            responses.len()
+                        )))
+                    }
+                });
+
+                match response {
+                    Ok(responses) => chunk
+                        .into_iter()
+                        .zip(responses)
+                        .map(|(input, response)| BatchResult {
+                            input,
+                            result: Ok(response),
+                        })
+                        .collect::<Vec<_>>(),
+                    Err(e) => {
+                        let e = Arc::new(e);
+                        chunk
+                            .into_iter()
+                            .map(|input| BatchResult {
+                                input,
+                                result: Err(Arc::clone(&e)),
+                            })
+                            .collect()
+                    }
+                }
+            })
+            .collect()
+    }
+
+    async fn post_order_batch(&self, orders: &[SignedOrder]) -> Result<Vec<PostOrderResponse>> {
         let request = self
             .client()
             .request(Method::POST, format!("{}orders", self.host()))
@@ -1501,7 +2215,14 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        crate::request(
+            &self.inner.client,
+            request,
+            Some(headers),
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Attempts to return the corresponding order at the provided `order_id`
@@ -1512,7 +2233,14 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        crate::request(
+            &self.inner.client,
+            request,
+            Some(headers),
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Retrieves a paginated list of orders matching the specified criteria.
@@ -1536,7 +2264,14 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        crate::request(
+            &self.inner.client,
+            request,
+            Some(headers),
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Cancels a single order by its order ID.
@@ -1556,14 +2291,22 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        crate::request(
+            &self.inner.client,
+            request,
+            Some(headers),
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Cancels multiple orders by their order IDs in a single request.
     ///
     /// This is the batch version of [`Self::cancel_order`], allowing efficient
     /// cancellation of many orders at once. All specified orders must belong
-    /// to the authenticated user.
+    /// to the authenticated user. See [`Self::cancel_orders_chunked`] to split large batches
+    /// across several requests.
     ///
     /// # Errors
     ///
@@ -1576,7 +2319,60 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        crate::request(
+            &self.inner.client,
+            request,
+            Some(headers),
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
+    }
+
+    /// Cancels any number of orders by their order IDs, split into concurrent requests of at most
+    /// [`Config`]'s `cancel_orders_chunk_size` orders each, and pairs each order ID with whether it
+    /// was canceled.
+    ///
+    /// Only the order IDs of a chunk whose request failed, or that the CLOB left out of its
+    /// response, have an error.
+    pub async fn cancel_orders_chunked(
+        &self,
+        order_ids: &[&str],
+    ) -> Vec<BatchResult<String, CancelStatus>> {
+        let chunk_size = self.inner.config.cancel_orders_chunk_size.max(1);
+        let chunks: Vec<_> = order_ids.chunks(chunk_size).collect();
+        let responses =
+            future::join_all(chunks.iter().map(|chunk| self.cancel_orders(chunk))).await;
+
+        chunks
+            .into_iter()
+            .zip(responses)
+            .flat_map(|(chunk, response)| {
+                let response = response.map_err(Arc::new);
+
+                chunk.iter().map(move |&order_id| {
+                    let result = match &response {
+                        Ok(response) if response.canceled.iter().any(|id| id == order_id) => {
+                            Ok(CancelStatus::Canceled)
+                        }
+                        Ok(response) => match response.not_canceled.get(order_id) {
+                            Some(reason) => Ok(CancelStatus::NotCanceled {
+                                reason: reason.clone(),
+                            }),
+                            None => Err(Arc::new(Error::validation(format!(
+                                "Order {order_id} is missing from the cancel response"
+                            )))),
+                        },
+                        Err(e) => Err(Arc::clone(e)),
+                    };
+
+                    BatchResult {
+                        input: order_id.to_owned(),
+                        result,
+                    }
+                })
+            })
+            .collect()
     }
 
     /// Cancels all open orders for the authenticated user.
@@ -1594,7 +2390,14 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        crate::request(
+            &self.inner.client,
+            request,
+            Some(headers),
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Attempts to cancel all open orders for a particular [`CancelMarketOrderRequest::market`]
@@ -1613,7 +2416,129 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        crate::request(
+            &self.inner.client,
+            request,
+            Some(headers),
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
+    }
+
+    /// Replaces a resting order with one at a new price and/or size, in a single call.
+    ///
+    /// The original order is fetched so that the replacement keeps its token, side, order type
+    /// and expiration, and is then built and signed with `signer` like any other
+    /// [`limit_order`](Self::limit_order). The CLOB does not report whether the original was
+    /// post-only, so the replacement is only post-only if
+    /// [`post_only`](ReplaceOrderRequest::post_only) is set. The
+    /// [`strategy`](ReplaceOrderRequest::strategy) decides whether the original is cancelled
+    /// before or after the replacement is posted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the original order cannot be fetched or the replacement cannot be
+    /// built or signed, in which case nothing was changed. If the cancellation or the post then
+    /// fails, the error's source is a [`ReplaceFailed`] describing which orders are left live,
+    /// whose own source is the request error, if there was one.
+    pub async fn replace_order<S: Signer>(
+        &self,
+        signer: &S,
+        request: &ReplaceOrderRequest,
+    ) -> Result<ReplaceOrderResponse> {
+        if request.price.is_none() && request.size.is_none() {
+            return Err(Error::validation(
+                "Unable to replace order without a new price or size",
+            ));
+        }
+
+        let original = self.order(&request.order_id).await?;
+        let mut builder = self
+            .limit_order()
+            .token_id(original.asset_id)
+            .side(original.side)
+            .price(request.price.unwrap_or(original.price))
+            .size(
+                request
+                    .size
+                    .unwrap_or(original.original_size - original.size_matched),
+            )
+            .order_type(original.order_type.clone());
+        if let Some(post_only) = request.post_only {
+            builder = builder.post_only(post_only);
+        }
+        if original.order_type == OrderType::GTD {
+            builder = builder.expiration(original.expiration);
+        }
+        let replacement = self.sign(signer, builder.build().await?).await?;
+
+        let order_id = request.order_id.clone();
+        match request.strategy {
+            ReplaceStrategy::PostFirst => {
+                let posted = self.post_order(replacement).await;
+                let posted = match posted {
+                    Ok(posted) if posted.success => posted,
+                    posted => {
+                        let (kind, reason, source) = post_failure(posted);
+                        let failed = ReplaceFailed::NotPosted {
+                            order_id,
+                            canceled: false,
+                            reason,
+                            source,
+                        };
+                        return Err(Error::with_source(kind, failed));
+                    }
+                };
+
+                let canceled = self.cancel_order(&request.order_id).await;
+                match canceled {
+                    Ok(canceled) if canceled.canceled.contains(&order_id) => {
+                        Ok(ReplaceOrderResponse { canceled, posted })
+                    }
+                    canceled => {
+                        let (kind, reason, source) = cancel_failure(&order_id, canceled);
+                        let failed = ReplaceFailed::NotCanceled {
+                            order_id,
+                            reason,
+                            replacement: Some(posted),
+                            source,
+                        };
+                        Err(Error::with_source(kind, failed))
+                    }
+                }
+            }
+            ReplaceStrategy::CancelFirst => {
+                let canceled = self.cancel_order(&request.order_id).await;
+                let canceled = match canceled {
+                    Ok(canceled) if canceled.canceled.contains(&order_id) => canceled,
+                    canceled => {
+                        let (kind, reason, source) = cancel_failure(&order_id, canceled);
+                        let failed = ReplaceFailed::NotCanceled {
+                            order_id,
+                            reason,
+                            replacement: None,
+                            source,
+                        };
+                        return Err(Error::with_source(kind, failed));
+                    }
+                };
+
+                match self.post_order(replacement).await {
+                    Ok(posted) if posted.success => Ok(ReplaceOrderResponse { canceled, posted }),
+                    posted => {
+                        let (kind, reason, source) = post_failure(posted);
+                        let failed = ReplaceFailed::NotPosted {
+                            order_id,
+                            canceled: true,
+                            reason,
+                            source,
+                        };
+                        Err(Error::with_source(kind, failed))
+                    }
+                }
+            }
+        }
     }
 
     /// Retrieves a paginated list of trades for the authenticated user.
@@ -1637,7 +2562,14 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        crate::request(
+            &self.inner.client,
+            request,
+            Some(headers),
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Retrieves all notifications for the authenticated user.
@@ -1656,7 +2588,14 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        crate::request(
+            &self.inner.client,
+            request,
+            Some(headers),
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Deletes notifications matching the specified IDs.
@@ -1714,7 +2653,14 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        crate::request(
+            &self.inner.client,
+            request,
+            Some(headers),
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Forces an update of the cached balance and allowance data.
@@ -1753,6 +2699,91 @@ impl<K: Kind> Client<Authenticated<K>> {
         Ok(())
     }
 
+    /// Checks that `order` can be funded before it is posted.
+    ///
+    /// Buys are checked against the USDC balance, less the notional of every open buy order.
+    /// Sells are checked against the balance of the token being sold, less the remaining size of
+    /// open sells of that token. Either way, the order's maker amount must also be within the
+    /// allowance granted to the exchange contract that settles it.
+    ///
+    /// Open buys draw on the same USDC balance whatever token they are for, so checking a buy
+    /// pages through every open order of the account, while checking a sell only fetches the open
+    /// orders of the token being sold.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`ErrorKind::Validation`] wrapping [`InsufficientFunds`] if the
+    /// order is underfunded, or an error if fetching the balance or open orders fails.
+    pub async fn check_funds(&self, order: &SignableOrder) -> Result<()> {
+        self.check_total_funds(slice::from_ref(order)).await
+    }
+
+    /// Checks that `orders`, which all buy or all sell the same token, can be funded together.
+    /// See [`check_funds`](Self::check_funds).
+    pub(crate) async fn check_total_funds(&self, orders: &[SignableOrder]) -> Result<()> {
+        let Some(first) = orders.first() else {
+            return Ok(());
+        };
+        let token_id = first.order.tokenId;
+        let side = Side::try_from(first.order.side)?;
+        let required = orders
+            .iter()
+            .map(|order| order.order.maker_amount())
+            .sum::<Result<Decimal>>()?;
+        let neg_risk = match first.neg_risk {
+            Some(neg_risk) => neg_risk,
+            None => self.neg_risk(token_id).await?.neg_risk,
+        };
+
+        let (asset_type, sold_token) = match side {
+            Side::Buy => (AssetType::Collateral, None),
+            _ => (AssetType::Conditional, Some(token_id)),
+        };
+        let request = BalanceAllowanceRequest::builder()
+            .asset_type(asset_type.clone())
+            .maybe_token_id(sold_token)
+            .build();
+        let funds = self.balance_allowance(request).await?;
+
+        let request = match side {
+            Side::Buy => OrdersRequest::default(),
+            _ => OrdersRequest::builder().asset_id(token_id).build(),
+        };
+        let open: Vec<OpenOrderResponse> = self
+            .stream_data(|client, cursor| client.orders(&request, cursor))
+            .try_collect()
+            .await?;
+        let reserved = open
+            .iter()
+            .filter(|open| open.side == side && (side == Side::Buy || open.asset_id == token_id))
+            .map(|open| {
+                let remaining = open.original_size - open.size_matched;
+                match side {
+                    Side::Buy => remaining * open.price,
+                    _ => remaining,
+                }
+            })
+            .sum::<Decimal>();
+
+        let scale = Decimal::from(10_u64.pow(USDC_DECIMALS));
+        let balance = funds.balance / scale;
+        let allowance = exchange_allowance(&funds, self.inner.chain_id, neg_risk)? / scale;
+
+        if required + reserved > balance.min(allowance) {
+            return Err(InsufficientFunds {
+                asset_type,
+                token_id: sold_token,
+                required,
+                reserved,
+                balance,
+                allowance,
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+
     /// Checks if an order is eligible for market maker rewards.
     ///
     /// Returns whether the specified order qualifies for the sampling program
@@ -1770,7 +2801,14 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        crate::request(
+            &self.inner.client,
+            request,
+            Some(headers),
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Checks if multiple orders are eligible for market maker rewards.
@@ -1789,7 +2827,14 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        crate::request(
+            &self.inner.client,
+            request,
+            Some(headers),
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Retrieves detailed market maker earnings for a specific day.
@@ -1819,7 +2864,14 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        crate::request(
+            &self.inner.client,
+            request,
+            Some(headers),
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Retrieves total market maker earnings summary for a specific day.
@@ -1847,7 +2899,14 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        crate::request(
+            &self.inner.client,
+            request,
+            Some(headers),
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Retrieves user earnings along with market reward configurations.
@@ -1877,7 +2936,14 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        crate::request(
+            &self.inner.client,
+            request,
+            Some(headers),
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Retrieves the user's current reward earning percentages.
@@ -1902,7 +2968,14 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        crate::request(
+            &self.inner.client,
+            request,
+            Some(headers),
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Retrieves current active reward programs and their configurations.
@@ -1928,7 +3001,14 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        crate::request(
+            &self.inner.client,
+            request,
+            Some(headers),
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Retrieves detailed reward data for a specific market.
@@ -1954,7 +3034,14 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        crate::request(
+            &self.inner.client,
+            request,
+            Some(headers),
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Creates a new Builder API key for order attribution.
@@ -1973,7 +3060,14 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        crate::request(
+            &self.inner.client,
+            request,
+            Some(headers),
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Posts a heartbeat to maintain order liveness.
@@ -1993,7 +3087,14 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        crate::request(
+            &self.inner.client,
+            request,
+            Some(headers),
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     #[cfg(feature = "heartbeats")]
@@ -2089,11 +3190,7 @@ impl<K: Kind> Client<Authenticated<K>> {
     }
 
     async fn create_headers(&self, request: &Request) -> Result<HeaderMap> {
-        let timestamp = if self.inner.config.use_server_time {
-            self.server_time().await?
-        } else {
-            Utc::now().timestamp()
-        };
+        let timestamp = self.inner.timestamp().await?;
 
         auth::l2::create_headers(self.state(), request, timestamp).await
     }
@@ -2104,6 +3201,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             signature_type: self.inner.signature_type,
             funder: self.inner.funder,
             salt_generator: self.inner.salt_generator,
+            client_order_id: None,
             token_id: None,
             price: None,
             size: None,
@@ -2114,6 +3212,19 @@ impl<K: Kind> Client<Authenticated<K>> {
             taker: None,
             order_type: None,
             post_only: Some(false),
+            tick_size: None,
+            fee_rate_bps: None,
+            neg_risk: None,
+            check_funds: false,
+            check_market: false,
+            max_slippage: None,
+            price_rounding: None,
+            size_rounding: None,
+            crossing_policy: None,
+            top_of_book: None,
+            step_ticks: None,
+            levels: None,
+            sizes: None,
             client: Client {
                 inner: Arc::clone(&self.inner),
                 #[cfg(feature = "heartbeats")]
@@ -2166,6 +3277,10 @@ impl Client<Authenticated<Normal>> {
             tick_sizes: inner.tick_sizes,
             neg_risk: inner.neg_risk,
             fee_rate_bps: inner.fee_rate_bps,
+            market_states: inner.market_states,
+            clock_offset: inner.clock_offset,
+            clock_sync: inner.clock_sync,
+            chain_id: inner.chain_id,
             funder: inner.funder,
             signature_type: inner.signature_type,
             salt_generator: inner.salt_generator,
@@ -2199,7 +3314,14 @@ impl Client<Authenticated<Builder>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        crate::request(
+            &self.inner.client,
+            request,
+            Some(headers),
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     pub async fn revoke_builder_api_key(&self) -> Result<()> {
@@ -2237,7 +3359,14 @@ impl Client<Authenticated<Builder>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        crate::request(
+            &self.inner.client,
+            request,
+            Some(headers),
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 }
 
@@ -2261,7 +3390,14 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&http_request).await?;
 
-        crate::request(&self.inner.client, http_request, Some(headers)).await
+        crate::request(
+            &self.inner.client,
+            http_request,
+            Some(headers),
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Cancels an RFQ request.
@@ -2305,7 +3441,14 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&http_request).await?;
 
-        crate::request(&self.inner.client, http_request, Some(headers)).await
+        crate::request(
+            &self.inner.client,
+            http_request,
+            Some(headers),
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Creates an RFQ Quote in response to a Request.
@@ -2324,7 +3467,14 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&http_request).await?;
 
-        crate::request(&self.inner.client, http_request, Some(headers)).await
+        crate::request(
+            &self.inner.client,
+            http_request,
+            Some(headers),
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Cancels an RFQ quote.
@@ -2366,7 +3516,14 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&http_request).await?;
 
-        crate::request(&self.inner.client, http_request, Some(headers)).await
+        crate::request(
+            &self.inner.client,
+            http_request,
+            Some(headers),
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Requester accepts an RFQ Quote.
@@ -2410,7 +3567,14 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&http_request).await?;
 
-        crate::request(&self.inner.client, http_request, Some(headers)).await
+        crate::request(
+            &self.inner.client,
+            http_request,
+            Some(headers),
+            &self.inner.config.retry,
+            self.inner.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Helper method for RFQ endpoints that return plain text instead of JSON.
@@ -2437,6 +3601,88 @@ impl<K: Kind> Client<Authenticated<K>> {
     }
 }
 
+/// Returns the allowance, in base units, granted to the exchange contract that settles neg risk or
+/// regular market orders on `chain_id`. Allowances too large to represent as a [`Decimal`], such
+/// as the max-uint approval, are treated as unlimited.
+fn exchange_allowance(
+    funds: &BalanceAllowanceResponse,
+    chain_id: ChainId,
+    neg_risk: bool,
+) -> Result<Decimal> {
+    let config = contract_config(chain_id, neg_risk)
+        .ok_or(Error::missing_contract_config(chain_id, neg_risk))?;
+    let Some(allowance) = funds.allowances.get(&config.exchange) else {
+        return Ok(Decimal::ZERO);
+    };
+
+    let allowance = U256::from_str(allowance).map_err(|e| {
+        Error::validation(format!(
+            "Unable to parse exchange allowance {allowance}: {e}"
+        ))
+    })?;
+
+    Ok(i128::try_from(allowance)
+        .ok()
+        .and_then(|allowance| Decimal::try_from_i128_with_scale(allowance, 0).ok())
+        .unwrap_or(Decimal::MAX))
+}
+
+/// Copies the entries of `cache` that are still valid under `ttl`.
+fn fresh_entries<T: Copy>(
+    cache: &DashMap<U256, CacheEntry<T>>,
+    ttl: Option<Duration>,
+) -> HashMap<U256, CacheEntry<T>> {
+    cache
+        .iter()
+        .filter(|entry| entry.is_fresh(ttl))
+        .map(|entry| (*entry.key(), *entry.value()))
+        .collect()
+}
+
+/// Describes why the replacement in [`Client::replace_order`] was not posted.
+fn post_failure(posted: Result<PostOrderResponse>) -> (ErrorKind, String, Option<Error>) {
+    match posted {
+        Ok(posted) => (
+            ErrorKind::Status,
+            posted
+                .error_msg
+                .filter(|message| !message.is_empty())
+                .unwrap_or_else(|| "order was not accepted".to_owned()),
+            None,
+        ),
+        Err(e) => (e.kind(), "post request failed".to_owned(), Some(e)),
+    }
+}
+
+/// Describes why the original order in [`Client::replace_order`] was not cancelled.
+fn cancel_failure(
+    order_id: &str,
+    canceled: Result<CancelOrdersResponse>,
+) -> (ErrorKind, String, Option<Error>) {
+    match canceled {
+        Ok(mut canceled) => (
+            ErrorKind::Status,
+            canceled
+                .not_canceled
+                .remove(order_id)
+                .unwrap_or_else(|| "order was not canceled".to_owned()),
+            None,
+        ),
+        Err(e) => (e.kind(), "cancel request failed".to_owned(), Some(e)),
+    }
+}
+
+/// Splits `items` into chunks of at most `size` items, keeping their order.
+fn into_chunks<T>(items: Vec<T>, size: usize) -> Vec<Vec<T>> {
+    let mut items = items.into_iter().peekable();
+    let mut chunks = Vec::new();
+    while items.peek().is_some() {
+        chunks.push(items.by_ref().take(size).collect());
+    }
+
+    chunks
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2445,4 +3691,13 @@ mod tests {
     fn client_default_should_succeed() {
         _ = Client::default();
     }
+
+    #[test]
+    fn into_chunks_should_keep_order() {
+        assert_eq!(
+            into_chunks(vec![1, 2, 3, 4, 5], 2),
+            vec![vec![1, 2], vec![3, 4], vec![5]]
+        );
+        assert!(into_chunks(Vec::<u8>::new(), 2).is_empty());
+    }
 }
@@ -145,6 +145,7 @@
 
 pub mod client;
 pub mod order_builder;
+pub mod traits;
 pub mod types;
 #[cfg(feature = "ws")]
 pub mod ws;
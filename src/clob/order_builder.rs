@@ -1,8 +1,9 @@
 use std::marker::PhantomData;
 use std::time::{SystemTime, UNIX_EPOCH};
 
-use alloy::primitives::U256;
-use chrono::{DateTime, Utc};
+use alloy::primitives::{U256, keccak256};
+use chrono::{DateTime, TimeDelta, Utc};
+use futures::TryStreamExt as _;
 use rand::RngExt as _;
 use rust_decimal::prelude::ToPrimitive as _;
 
@@ -10,15 +11,21 @@ use crate::Result;
 use crate::auth::Kind as AuthKind;
 use crate::auth::state::Authenticated;
 use crate::clob::Client;
-use crate::clob::types::request::OrderBookSummaryRequest;
+use crate::clob::types::request::{OrderBookSummaryRequest, OrdersRequest};
+use crate::clob::types::response::{FillSimulation, OpenOrderResponse, OrderBookSummaryResponse};
 use crate::clob::types::{
-    Amount, AmountInner, Order, OrderType, Side, SignableOrder, SignatureType,
+    Amount, AmountInner, CrossingDetected, CrossingKind, CrossingPolicy, MaxSlippage, Order,
+    OrderType, Rounding, Side, SignableOrder, SignatureType, SlippageBound, SlippageExceeded,
+    SlippageReference, TickSize, TopOfBook,
 };
-use crate::error::Error;
+use crate::error::{Error, Kind as ErrorKind};
 use crate::types::{Address, Decimal};
 
 pub(crate) const USDC_DECIMALS: u32 = 6;
 
+/// How far past the current time the CLOB requires a GTD order's expiration to be
+const GTD_SECURITY_THRESHOLD: TimeDelta = TimeDelta::seconds(60);
+
 /// Maximum number of decimal places for `size`
 pub(crate) const LOT_SIZE_SCALE: u32 = 2;
 
@@ -32,6 +39,21 @@ pub struct Limit;
 #[derive(Debug)]
 pub struct Market;
 
+/// Placeholder type for compile-time checks on ladder order builders
+#[non_exhaustive]
+#[derive(Debug)]
+pub struct Ladder;
+
+/// How a limit order's crossing was resolved under its [`CrossingPolicy`].
+struct Crossing {
+    /// The price to build the order at.
+    price: Decimal,
+    /// The best of our resting prices that the order crosses, if any are to be canceled.
+    crossed_price: Decimal,
+    /// The IDs of our resting orders to cancel once the order is built.
+    cancel: Vec<String>,
+}
+
 /// Used to create an order iteratively and ensure validity with respect to its order kind.
 #[derive(Debug)]
 pub struct OrderBuilder<OrderKind, K: AuthKind> {
@@ -39,6 +61,7 @@ pub struct OrderBuilder<OrderKind, K: AuthKind> {
     pub(crate) signer: Address,
     pub(crate) signature_type: SignatureType,
     pub(crate) salt_generator: fn() -> u64,
+    pub(crate) client_order_id: Option<String>,
     pub(crate) token_id: Option<U256>,
     pub(crate) price: Option<Decimal>,
     pub(crate) size: Option<Decimal>,
@@ -50,6 +73,19 @@ pub struct OrderBuilder<OrderKind, K: AuthKind> {
     pub(crate) order_type: Option<OrderType>,
     pub(crate) post_only: Option<bool>,
     pub(crate) funder: Option<Address>,
+    pub(crate) tick_size: Option<TickSize>,
+    pub(crate) fee_rate_bps: Option<u32>,
+    pub(crate) neg_risk: Option<bool>,
+    pub(crate) check_funds: bool,
+    pub(crate) check_market: bool,
+    pub(crate) max_slippage: Option<MaxSlippage>,
+    pub(crate) price_rounding: Option<Rounding>,
+    pub(crate) size_rounding: Option<Rounding>,
+    pub(crate) crossing_policy: Option<CrossingPolicy>,
+    pub(crate) top_of_book: Option<TopOfBook>,
+    pub(crate) step_ticks: Option<u32>,
+    pub(crate) levels: Option<usize>,
+    pub(crate) sizes: Option<Vec<Decimal>>,
     pub(crate) _kind: PhantomData<OrderKind>,
 }
 
@@ -68,7 +104,12 @@ impl<OrderKind, K: AuthKind> OrderBuilder<OrderKind, K> {
         self
     }
 
-    /// Sets the nonce for this builder.
+    /// Sets the nonce for this builder. Defaults to 0.
+    ///
+    /// The exchange only settles orders signed with the maker's current nonce, so incrementing it
+    /// on-chain invalidates every order signed with the old one. With the `exchange` feature,
+    /// `exchange::Client::nonce` returns the current nonce, which converts into the `u64` taken
+    /// here, and `exchange::Client::increment_nonce` increments it.
     #[must_use]
     pub fn nonce(mut self, nonce: u64) -> Self {
         self.nonce = Some(nonce);
@@ -81,6 +122,19 @@ impl<OrderKind, K: AuthKind> OrderBuilder<OrderKind, K> {
         self
     }
 
+    /// Sets the expiration of an [`OrderType::GTD`] order so that it stays live for `lifetime`.
+    ///
+    /// The CLOB only honours expirations a minute past its own clock, so the expiration is that
+    /// minute plus `lifetime` after [`Client::corrected_now`], read when this is called. Orders
+    /// rebuilt later therefore get a different expiration, and so a different order ID, even with
+    /// the same [`client_order_id`](Self::client_order_id). Use [`expiration`](Self::expiration)
+    /// to rebuild a GTD order identically.
+    #[must_use]
+    pub fn expires_in(mut self, lifetime: TimeDelta) -> Self {
+        self.expiration = Some(self.client.corrected_now() + GTD_SECURITY_THRESHOLD + lifetime);
+        self
+    }
+
     #[must_use]
     pub fn taker(mut self, taker: Address) -> Self {
         self.taker = Some(taker);
@@ -99,6 +153,84 @@ impl<OrderKind, K: AuthKind> OrderBuilder<OrderKind, K> {
         self.post_only = Some(post_only);
         self
     }
+
+    /// Sets the minimum tick size of the market instead of fetching it from the CLOB. This is
+    /// required for `build_offline`.
+    #[must_use]
+    pub fn tick_size(mut self, tick_size: TickSize) -> Self {
+        self.tick_size = Some(tick_size);
+        self
+    }
+
+    /// Sets the fee rate, in basis points, instead of fetching it from the CLOB. This is required
+    /// for `build_offline`.
+    #[must_use]
+    pub fn fee_rate_bps(mut self, fee_rate_bps: u32) -> Self {
+        self.fee_rate_bps = Some(fee_rate_bps);
+        self
+    }
+
+    /// Sets whether the market is a neg risk market, so that [`Client::sign`] does not have to
+    /// fetch it from the CLOB. This is required for `build_offline`.
+    #[must_use]
+    pub fn neg_risk(mut self, neg_risk: bool) -> Self {
+        self.neg_risk = Some(neg_risk);
+        self
+    }
+
+    /// Sets whether `build` verifies that the order is funded, failing with
+    /// [`InsufficientFunds`](crate::clob::types::InsufficientFunds) instead of leaving the
+    /// rejection to `post_order`. See [`Client::check_funds`]. Disabled by default, and never
+    /// performed by `build_offline`. Checking a buy fetches every open order of the account on
+    /// each `build`.
+    #[must_use]
+    pub fn check_funds(mut self, check_funds: bool) -> Self {
+        self.check_funds = check_funds;
+        self
+    }
+
+    /// Sets whether `build` verifies that the market is open and accepting orders, and that the
+    /// order meets its minimum size, failing with
+    /// [`MarketStateViolation`](crate::clob::types::MarketStateViolation) instead of leaving the
+    /// rejection to `post_order`. See [`Client::check_market`]. Disabled by default, and never
+    /// performed by `build_offline`.
+    #[must_use]
+    pub fn check_market(mut self, check_market: bool) -> Self {
+        self.check_market = check_market;
+        self
+    }
+
+    /// Sets a client order ID, from which the order's salt is derived instead of being generated.
+    /// Building and signing the same order with the same ID then always produces the identical
+    /// signed order, and thus the same order ID, so a post that timed out can be retried with
+    /// [`Client::resubmit_order`] without risking a second order. Market orders are only
+    /// reproducible with an explicit `price`, and GTD orders only with an explicit
+    /// [`expiration`](Self::expiration), since [`expires_in`](Self::expires_in) reads the clock. Each level `n` of a ladder, counted from zero, uses
+    /// the ID `{client_order_id}/{n}`.
+    #[must_use]
+    pub fn client_order_id<S: Into<String>>(mut self, client_order_id: S) -> Self {
+        self.client_order_id = Some(client_order_id.into());
+        self
+    }
+
+    /// Returns the salt derived from the client order ID, if set, or a newly generated one.
+    fn salt(&self) -> u64 {
+        match &self.client_order_id {
+            Some(client_order_id) => client_order_salt(client_order_id),
+            None => (self.salt_generator)(),
+        }
+    }
+
+    /// Returns the explicitly set tick size and fee rate, failing if any of the market parameters
+    /// needed to build and sign without contacting the CLOB is missing.
+    fn offline_parameters(&self) -> Result<(TickSize, u32)> {
+        match (self.tick_size, self.fee_rate_bps, self.neg_risk) {
+            (Some(tick_size), Some(fee_rate_bps), Some(_)) => Ok((tick_size, fee_rate_bps)),
+            _ => Err(Error::validation(
+                "Unable to build Order offline without tick size, fee rate and neg risk",
+            )),
+        }
+    }
 }
 
 impl<K: AuthKind> OrderBuilder<Limit, K> {
@@ -109,19 +241,154 @@ impl<K: AuthKind> OrderBuilder<Limit, K> {
         self
     }
 
-    /// Sets the size for this limit builder. This is a required field.
+    /// Sets the size in shares for this limit builder. Either this or [`amount`](Self::amount) is
+    /// required.
     #[must_use]
     pub fn size(mut self, size: Decimal) -> Self {
         self.size = Some(size);
         self
     }
 
-    /// Validates and transforms this limit builder into a [`SignableOrder`]
+    /// Sets the amount for this limit builder instead of its [`size`](Self::size). An
+    /// [`Amount::usdc`] notional is converted to a size in shares at the limit price, rounded with
+    /// the [`size_rounding`](Self::size_rounding) or down to the lot size if none is set.
+    #[must_use]
+    pub fn amount(mut self, amount: Amount) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    /// Sets how a price with more decimal places than the market's minimum tick size is rounded,
+    /// instead of failing the build.
+    #[must_use]
+    pub fn price_rounding(mut self, rounding: Rounding) -> Self {
+        self.price_rounding = Some(rounding);
+        self
+    }
+
+    /// Sets how a size with more decimal places than the lot size is rounded, instead of failing
+    /// the build.
+    #[must_use]
+    pub fn size_rounding(mut self, rounding: Rounding) -> Self {
+        self.size_rounding = Some(rounding);
+        self
+    }
+
+    /// Checks, before building, whether a [`post_only`](Self::post_only) order would take
+    /// liquidity from the book and whether the order would match our own resting orders, as
+    /// listed by [`Client::orders`]. The policy decides whether such an order is rejected with
+    /// [`CrossingDetected`], repriced to one tick behind the best price it would cross, or built
+    /// after our crossing resting orders are canceled. Not supported by `build_offline`.
+    ///
+    /// With [`CrossingPolicy::CancelResting`], the resting orders are canceled by
+    /// [`build`](Self::build) itself, as its last step once the order and any
+    /// [`check_market`](Self::check_market) and [`check_funds`](Self::check_funds) checks have
+    /// passed. They stay canceled even if the built order is never signed or posted.
+    #[must_use]
+    pub fn crossing_policy(mut self, policy: CrossingPolicy) -> Self {
+        self.crossing_policy = Some(policy);
+        self
+    }
+
+    /// Sets the best bid and ask that the [`crossing_policy`](Self::crossing_policy) check uses
+    /// for post-only orders, such as those of a locally maintained book, instead of fetching the
+    /// order book from the CLOB.
+    #[must_use]
+    pub fn book<B: Into<TopOfBook>>(mut self, book: B) -> Self {
+        self.top_of_book = Some(book.into());
+        self
+    }
+
+    /// Validates and transforms this limit builder into a [`SignableOrder`]. The tick size and
+    /// fee rate are fetched from the CLOB unless they were set on this builder.
+    ///
+    /// The price and size the order was built with, after any rounding, are reported in
+    /// [`SignableOrder::limit_price`] and [`SignableOrder::size`].
     #[cfg_attr(
         feature = "tracing",
         tracing::instrument(skip(self), err(level = "warn"))
     )]
     pub async fn build(self) -> Result<SignableOrder> {
+        let (token_id, side, price) = self.required()?;
+
+        let fee_rate_bps = match self.fee_rate_bps {
+            Some(fee_rate_bps) => fee_rate_bps,
+            None => self.client.fee_rate_bps(token_id).await?.base_fee,
+        };
+        let tick_size = match self.tick_size {
+            Some(tick_size) => tick_size,
+            None => self.client.tick_size(token_id).await?.minimum_tick_size,
+        };
+
+        let crossing = match self.crossing_policy {
+            Some(policy) => Some(
+                self.resolve_crossing(token_id, side, price, tick_size, policy)
+                    .await?,
+            ),
+            None => None,
+        };
+        let price = crossing.as_ref().map_or(price, |crossing| crossing.price);
+
+        let (client, check_market, check_funds) =
+            (self.client.clone(), self.check_market, self.check_funds);
+        let order = self.finish(token_id, side, price, tick_size, fee_rate_bps)?;
+        if check_market {
+            client.check_market(&order).await?;
+        }
+        if check_funds {
+            client.check_funds(&order).await?;
+        }
+
+        // Our crossed orders are only canceled once the order is known to be valid, so that a
+        // failed build leaves them resting
+        if let Some(crossing) = crossing.filter(|crossing| !crossing.cancel.is_empty()) {
+            let ids: Vec<&str> = crossing.cancel.iter().map(String::as_str).collect();
+            let response = client.cancel_orders(&ids).await?;
+            if !response.not_canceled.is_empty() {
+                return Err(Error::with_source(
+                    ErrorKind::Validation,
+                    CrossingDetected {
+                        kind: CrossingKind::SelfTrade,
+                        side,
+                        price,
+                        crossed_price: crossing.crossed_price,
+                        order_ids: response.not_canceled.into_keys().collect(),
+                    },
+                ));
+            }
+        }
+
+        Ok(order)
+    }
+
+    /// Validates and transforms this limit builder into a [`SignableOrder`] without contacting the
+    /// CLOB, using the [`tick_size`](Self::tick_size), [`fee_rate_bps`](Self::fee_rate_bps) and
+    /// [`neg_risk`](Self::neg_risk) set on this builder. The resulting order can be signed with
+    /// [`Client::sign`] without any network I/O either.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of those market parameters is missing, or if the order is invalid
+    /// in the same way as for [`build`](Self::build).
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), err(level = "warn"))
+    )]
+    pub fn build_offline(self) -> Result<SignableOrder> {
+        let (token_id, side, price) = self.required()?;
+        if self.crossing_policy.is_some() {
+            return Err(Error::validation(
+                "Unable to check crossing offline without our open orders",
+            ));
+        }
+        let (tick_size, fee_rate_bps) = self.offline_parameters()?;
+
+        self.finish(token_id, side, price, tick_size, fee_rate_bps)
+    }
+
+    /// Returns the token ID, side and price, which are validated before any market parameter is
+    /// fetched.
+    fn required(&self) -> Result<(U256, Side, Decimal)> {
         let Some(token_id) = self.token_id else {
             return Err(Error::validation(
                 "Unable to build Order due to missing token ID",
@@ -146,16 +413,136 @@ impl<K: AuthKind> OrderBuilder<Limit, K> {
             )));
         }
 
-        let fee_rate = self.client.fee_rate_bps(token_id).await?;
-        let minimum_tick_size = self
+        Ok((token_id, side, price))
+    }
+
+    /// Checks the limit price for post-only crossing against the book and for matching our own
+    /// resting orders, returning the price to build at and the resting orders to cancel under
+    /// `policy`. Nothing is canceled here.
+    async fn resolve_crossing(
+        &self,
+        token_id: U256,
+        side: Side,
+        price: Decimal,
+        tick_size: TickSize,
+        policy: CrossingPolicy,
+    ) -> Result<Crossing> {
+        let minimum_tick_size = tick_size.as_decimal();
+        let price = match self.price_rounding {
+            Some(rounding) => rounding.round_price(price, minimum_tick_size.scale(), side),
+            None => price,
+        };
+        // Whether an opposing order at `level` would be matched by this order
+        let crosses = |level: Decimal| match side {
+            Side::Buy => price >= level,
+            _ => price <= level,
+        };
+        // The opposing price that is crossed first, which is the lowest ask or the highest bid
+        let first = |a: Decimal, b: Decimal| match side {
+            Side::Buy => a.min(b),
+            _ => a.max(b),
+        };
+
+        let book_crossed = if self.post_only == Some(true) {
+            let top = match self.top_of_book {
+                Some(top) => top,
+                None => TopOfBook::from(
+                    &self
+                        .client
+                        .order_book(&OrderBookSummaryRequest {
+                            token_id,
+                            side: None,
+                        })
+                        .await?,
+                ),
+            };
+            match side {
+                Side::Buy => top.best_ask,
+                _ => top.best_bid,
+            }
+            .filter(|&level| crosses(level))
+        } else {
+            None
+        };
+
+        let request = OrdersRequest::builder().asset_id(token_id).build();
+        let resting: Vec<OpenOrderResponse> = self
             .client
-            .tick_size(token_id)
-            .await?
-            .minimum_tick_size
-            .as_decimal();
+            .stream_data(|client, cursor| client.orders(&request, cursor))
+            .try_collect()
+            .await?;
+        let (own_prices, order_ids): (Vec<_>, Vec<_>) = resting
+            .into_iter()
+            .filter(|order| {
+                order.asset_id == token_id && order.side != side && crosses(order.price)
+            })
+            .map(|order| (order.price, order.id))
+            .unzip();
+        let own_crossed = own_prices.into_iter().reduce(first);
+
+        let detected = |kind, crossed_price, order_ids| {
+            Error::with_source(
+                ErrorKind::Validation,
+                CrossingDetected {
+                    kind,
+                    side,
+                    price,
+                    crossed_price,
+                    order_ids,
+                },
+            )
+        };
+
+        let resolved = |price| Crossing {
+            price,
+            crossed_price: price,
+            cancel: Vec::new(),
+        };
+
+        match (policy, book_crossed, own_crossed) {
+            (_, None, None) => Ok(resolved(price)),
+            (CrossingPolicy::Reprice, book_crossed, own_crossed) => {
+                let crossed = book_crossed
+                    .into_iter()
+                    .chain(own_crossed)
+                    .reduce(first)
+                    .unwrap_or(price);
+                Ok(resolved(match side {
+                    Side::Buy => crossed - minimum_tick_size,
+                    _ => crossed + minimum_tick_size,
+                }))
+            }
+            (CrossingPolicy::Reject | CrossingPolicy::CancelResting, Some(book_crossed), _) => {
+                Err(detected(CrossingKind::PostOnly, book_crossed, Vec::new()))
+            }
+            (CrossingPolicy::Reject, None, Some(own_crossed)) => {
+                Err(detected(CrossingKind::SelfTrade, own_crossed, order_ids))
+            }
+            (CrossingPolicy::CancelResting, None, Some(own_crossed)) => Ok(Crossing {
+                price,
+                crossed_price: own_crossed,
+                cancel: order_ids,
+            }),
+        }
+    }
 
+    /// Validates the rest of the order against the market parameters and computes its amounts.
+    fn finish(
+        self,
+        token_id: U256,
+        side: Side,
+        price: Decimal,
+        tick_size: TickSize,
+        fee_rate_bps: u32,
+    ) -> Result<SignableOrder> {
+        let minimum_tick_size = tick_size.as_decimal();
         let decimals = minimum_tick_size.scale();
 
+        let price = match self.price_rounding {
+            Some(rounding) => rounding.round_price(price, decimals, side),
+            None => price,
+        };
+
         if price.scale() > minimum_tick_size.scale() {
             return Err(Error::validation(format!(
                 "Unable to build Order: Price {price} has {} decimal places. Minimum tick size \
@@ -171,10 +558,28 @@ impl<K: AuthKind> OrderBuilder<Limit, K> {
             )));
         }
 
-        let Some(size) = self.size else {
-            return Err(Error::validation(
-                "Unable to build Order due to missing size",
-            ));
+        let size = match (self.size, self.amount) {
+            (Some(size), None) => size,
+            (None, Some(Amount(AmountInner::Shares(shares)))) => shares,
+            (None, Some(Amount(AmountInner::Usdc(usdc)))) => self
+                .size_rounding
+                .unwrap_or(Rounding::Down)
+                .round_size(usdc / price, LOT_SIZE_SCALE),
+            (Some(_), Some(_)) => {
+                return Err(Error::validation(
+                    "Unable to build Order with both a size and an amount",
+                ));
+            }
+            (None, None) => {
+                return Err(Error::validation(
+                    "Unable to build Order due to missing size",
+                ));
+            }
+        };
+
+        let size = match self.size_rounding {
+            Some(rounding) => rounding.round_size(size, LOT_SIZE_SCALE),
+            None => size,
         };
 
         if size.scale() > LOT_SIZE_SCALE {
@@ -193,7 +598,7 @@ impl<K: AuthKind> OrderBuilder<Limit, K> {
         let nonce = self.nonce.unwrap_or(0);
         let expiration = self.expiration.unwrap_or(DateTime::<Utc>::UNIX_EPOCH);
         let taker = self.taker.unwrap_or(Address::ZERO);
-        let order_type = self.order_type.unwrap_or(OrderType::GTC);
+        let order_type = self.order_type.clone().unwrap_or(OrderType::GTC);
         let post_only = Some(self.post_only.unwrap_or(false));
 
         if !matches!(order_type, OrderType::GTD) && expiration > DateTime::<Utc>::UNIX_EPOCH {
@@ -229,7 +634,7 @@ impl<K: AuthKind> OrderBuilder<Limit, K> {
             side => return Err(Error::validation(format!("Invalid side: {side}"))),
         };
 
-        let salt = to_ieee_754_int((self.salt_generator)());
+        let salt = to_ieee_754_int(self.salt());
 
         let order = Order {
             salt: U256::from(salt),
@@ -239,7 +644,7 @@ impl<K: AuthKind> OrderBuilder<Limit, K> {
             makerAmount: U256::from(to_fixed_u128(maker_amount)),
             takerAmount: U256::from(to_fixed_u128(taker_amount)),
             side: side as u8,
-            feeRateBps: U256::from(fee_rate.base_fee),
+            feeRateBps: U256::from(fee_rate_bps),
             nonce: U256::from(nonce),
             signer: self.signer,
             expiration: U256::from(expiration.timestamp().to_u64().ok_or(Error::validation(
@@ -255,6 +660,9 @@ impl<K: AuthKind> OrderBuilder<Limit, K> {
             order,
             order_type,
             post_only,
+            neg_risk: self.neg_risk,
+            limit_price: Some(price),
+            size: Some(size),
         })
     }
 }
@@ -274,21 +682,21 @@ impl<K: AuthKind> OrderBuilder<Market, K> {
         self
     }
 
-    // Attempts to calculate the market price from the top of the book for the particular token.
-    // - Uses an orderbook depth search to find the cutoff price:
-    //   - BUY + USDC: walk asks until notional >= USDC
-    //   - BUY + Shares: walk asks until shares >= N
-    //   - SELL + Shares: walk bids until shares >= N
-    async fn calculate_price(&self, order_type: OrderType) -> Result<Decimal> {
-        let token_id = self
-            .token_id
-            .expect("Token ID was already validated in `build`");
-        let side = self.side.expect("Side was already validated in `build`");
-        let amount = self
-            .amount
-            .as_ref()
-            .expect("Amount was already validated in `build`");
-
+    /// Previews this market order against the current order book without building it, returning
+    /// the expected average and worst prices, amounts, consumed levels and estimated fee. The fee
+    /// rate is fetched from the CLOB unless it was set on this builder.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a required field is missing, there are no opposing orders, or the
+    /// requests fail.
+    pub async fn simulate(&self) -> Result<FillSimulation> {
+        let (token_id, side, amount, _) = self.required()?;
+
+        let fee_rate_bps = match self.fee_rate_bps {
+            Some(fee_rate_bps) => fee_rate_bps,
+            None => self.client.fee_rate_bps(token_id).await?.base_fee,
+        };
         let book = self
             .client
             .order_book(&OrderBookSummaryRequest {
@@ -297,55 +705,155 @@ impl<K: AuthKind> OrderBuilder<Market, K> {
             })
             .await?;
 
+        book.simulate_fill(side, amount, fee_rate_bps)
+    }
+
+    /// Bounds the limit price of this market order to within `max_slippage` of the best opposing
+    /// price or the midpoint. This applies to an explicit [`price`](Self::price) as well as to
+    /// the one calculated from the order book, and the limit price that was used is reported as
+    /// [`SignableOrder::limit_price`]. Not supported by `build_offline`, which has no book to
+    /// measure from.
+    #[must_use]
+    pub fn max_slippage(mut self, max_slippage: MaxSlippage) -> Self {
+        self.max_slippage = Some(max_slippage);
+        self
+    }
+
+    // Attempts to calculate the market price from the top of the book for the particular token.
+    // - Uses an orderbook depth search to find the cutoff price:
+    //   - BUY + USDC: walk asks until notional >= USDC
+    //   - BUY + Shares: walk asks until shares >= N
+    //   - SELL + Shares: walk bids until shares >= N
+    fn calculate_price(
+        book: &OrderBookSummaryResponse,
+        side: Side,
+        amount: Amount,
+        order_type: &OrderType,
+    ) -> Result<Decimal> {
         if !matches!(order_type, OrderType::FAK | OrderType::FOK) {
             return Err(Error::validation(
                 "Cannot set an order type other than FAK/FOK for a market order",
             ));
         }
 
-        let (levels, amount) = match side {
-            Side::Buy => (book.asks, amount.0),
-            Side::Sell => match amount.0 {
-                a @ AmountInner::Shares(_) => (book.bids, a),
-                AmountInner::Usdc(_) => {
-                    return Err(Error::validation(
-                        "Sell Orders must specify their `amount`s in shares",
-                    ));
-                }
-            },
+        // A FAK order that the book cannot fill completely is priced to take all of it
+        let simulation = book.simulate_fill(side, amount, 0)?;
+        if !simulation.filled && matches!(order_type, OrderType::FOK) {
+            return Err(Error::validation(format!(
+                "Insufficient liquidity to fill order for {} at {}",
+                book.asset_id,
+                amount.as_inner()
+            )));
+        }
 
-            side => return Err(Error::validation(format!("Invalid side: {side}"))),
-        };
+        Ok(simulation.worst_price)
+    }
 
-        let first = levels.first().ok_or(Error::validation(format!(
-            "No opposing orders for {token_id} which means there is no market price"
-        )))?;
+    /// Validates and transforms this market builder into a [`SignableOrder`]. The price is
+    /// calculated from the order book, and the tick size and fee rate are fetched from the CLOB,
+    /// unless they were set on this builder.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), err(level = "warn"))
+    )]
+    pub async fn build(self) -> Result<SignableOrder> {
+        let (token_id, side, amount, order_type) = self.required()?;
 
-        let mut sum = Decimal::ZERO;
-        let cutoff_price = levels.iter().rev().find_map(|level| {
-            match amount {
-                AmountInner::Usdc(_) => sum += level.size * level.price,
-                AmountInner::Shares(_) => sum += level.size,
+        let tick_size = match self.tick_size {
+            Some(tick_size) => tick_size,
+            None => self.client.tick_size(token_id).await?.minimum_tick_size,
+        };
+        let price = match (self.price, self.max_slippage) {
+            (Some(price), None) => price,
+            (price, max_slippage) => {
+                let book = self
+                    .client
+                    .order_book(&OrderBookSummaryRequest {
+                        token_id,
+                        side: None,
+                    })
+                    .await?;
+
+                let price = match price {
+                    Some(price) => price,
+                    None => Self::calculate_price(&book, side, amount, &order_type)?,
+                };
+                match max_slippage {
+                    Some(max_slippage) => {
+                        bound_slippage(&book, side, price, tick_size, max_slippage)?
+                    }
+                    None => price,
+                }
             }
-            (sum >= amount.as_inner()).then_some(level.price)
-        });
+        };
+        let fee_rate_bps = match self.fee_rate_bps {
+            Some(fee_rate_bps) => fee_rate_bps,
+            None => self.client.fee_rate_bps(token_id).await?.base_fee,
+        };
 
-        match cutoff_price {
-            Some(price) => Ok(price),
-            None if matches!(order_type, OrderType::FOK) => Err(Error::validation(format!(
-                "Insufficient liquidity to fill order for {token_id} at {}",
-                amount.as_inner()
-            ))),
-            None => Ok(first.price),
+        let (client, check_market, check_funds) =
+            (self.client.clone(), self.check_market, self.check_funds);
+        let order = self.finish(
+            token_id,
+            side,
+            amount,
+            order_type,
+            price,
+            tick_size,
+            fee_rate_bps,
+        )?;
+        if check_market {
+            client.check_market(&order).await?;
+        }
+        if check_funds {
+            client.check_funds(&order).await?;
         }
+
+        Ok(order)
     }
 
-    /// Validates and transforms this market builder into a [`SignableOrder`]
+    /// Validates and transforms this market builder into a [`SignableOrder`] without contacting
+    /// the CLOB, using the [`price`](Self::price), [`tick_size`](Self::tick_size),
+    /// [`fee_rate_bps`](Self::fee_rate_bps) and [`neg_risk`](Self::neg_risk) set on this builder.
+    /// The resulting order can be signed with [`Client::sign`] without any network I/O either.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the price or any of those market parameters is missing, or if the
+    /// order is invalid in the same way as for [`build`](Self::build).
     #[cfg_attr(
         feature = "tracing",
         tracing::instrument(skip(self), err(level = "warn"))
     )]
-    pub async fn build(self) -> Result<SignableOrder> {
+    pub fn build_offline(self) -> Result<SignableOrder> {
+        let (token_id, side, amount, order_type) = self.required()?;
+
+        let Some(price) = self.price else {
+            return Err(Error::validation(
+                "Unable to build market Order offline without a price",
+            ));
+        };
+        if self.max_slippage.is_some() {
+            return Err(Error::validation(
+                "Unable to bound slippage offline without an order book",
+            ));
+        }
+        let (tick_size, fee_rate_bps) = self.offline_parameters()?;
+
+        self.finish(
+            token_id,
+            side,
+            amount,
+            order_type,
+            price,
+            tick_size,
+            fee_rate_bps,
+        )
+    }
+
+    /// Returns the token ID, side, amount and order type, which are validated before the price or
+    /// any market parameter is fetched.
+    fn required(&self) -> Result<(U256, Side, Amount, OrderType)> {
         let Some(token_id) = self.token_id else {
             return Err(Error::validation(
                 "Unable to build Order due to missing token ID",
@@ -362,29 +870,35 @@ impl<K: AuthKind> OrderBuilder<Market, K> {
             .amount
             .ok_or_else(|| Error::validation("Unable to build Order due to missing amount"))?;
 
-        let nonce = self.nonce.unwrap_or(0);
-        let taker = self.taker.unwrap_or(Address::ZERO);
-
         let order_type = self.order_type.clone().unwrap_or(OrderType::FAK);
-        let post_only = self.post_only;
-        if post_only == Some(true) {
+        if self.post_only == Some(true) {
             return Err(Error::validation(
                 "postOnly is only supported for limit orders",
             ));
         }
-        let price = match self.price {
-            Some(price) => price,
-            None => self.calculate_price(order_type.clone()).await?,
-        };
 
-        let minimum_tick_size = self
-            .client
-            .tick_size(token_id)
-            .await?
-            .minimum_tick_size
-            .as_decimal();
-        let fee_rate = self.client.fee_rate_bps(token_id).await?;
+        Ok((token_id, side, amount, order_type))
+    }
 
+    /// Validates the rest of the order against the market parameters and computes its amounts.
+    #[expect(
+        clippy::too_many_arguments,
+        reason = "These are the validated builder fields and fetched market parameters"
+    )]
+    fn finish(
+        self,
+        token_id: U256,
+        side: Side,
+        amount: Amount,
+        order_type: OrderType,
+        price: Decimal,
+        tick_size: TickSize,
+        fee_rate_bps: u32,
+    ) -> Result<SignableOrder> {
+        let nonce = self.nonce.unwrap_or(0);
+        let taker = self.taker.unwrap_or(Address::ZERO);
+
+        let minimum_tick_size = tick_size.as_decimal();
         let decimals = minimum_tick_size.scale();
 
         // Ensure that the market price returned internally is truncated to our tick size
@@ -413,23 +927,23 @@ impl<K: AuthKind> OrderBuilder<Market, K> {
         // `100000000`.
         let raw_amount = amount.as_inner();
 
-        let (taker_amount, maker_amount) = match (side, amount.0) {
+        let (taker_amount, maker_amount, size) = match (side, amount.0) {
             // Spend USDC to buy shares
             (Side::Buy, AmountInner::Usdc(_)) => {
                 let shares = (raw_amount / price).trunc_with_scale(decimals + LOT_SIZE_SCALE);
-                (shares, raw_amount)
+                (shares, raw_amount, shares)
             }
 
             // Buy N shares: use cutoff `price` derived from ask depth
             (Side::Buy, AmountInner::Shares(_)) => {
                 let usdc = (raw_amount * price).trunc_with_scale(decimals + LOT_SIZE_SCALE);
-                (raw_amount, usdc)
+                (raw_amount, usdc, raw_amount)
             }
 
             // Sell N shares for USDC
             (Side::Sell, AmountInner::Shares(_)) => {
                 let usdc = (raw_amount * price).trunc_with_scale(decimals + LOT_SIZE_SCALE);
-                (usdc, raw_amount)
+                (usdc, raw_amount, raw_amount)
             }
 
             (Side::Sell, AmountInner::Usdc(_)) => {
@@ -441,7 +955,7 @@ impl<K: AuthKind> OrderBuilder<Market, K> {
             (side, _) => return Err(Error::validation(format!("Invalid side: {side}"))),
         };
 
-        let salt = to_ieee_754_int((self.salt_generator)());
+        let salt = to_ieee_754_int(self.salt());
 
         let order = Order {
             salt: U256::from(salt),
@@ -451,7 +965,7 @@ impl<K: AuthKind> OrderBuilder<Market, K> {
             makerAmount: U256::from(to_fixed_u128(maker_amount)),
             takerAmount: U256::from(to_fixed_u128(taker_amount)),
             side: side as u8,
-            feeRateBps: U256::from(fee_rate.base_fee),
+            feeRateBps: U256::from(fee_rate_bps),
             nonce: U256::from(nonce),
             signer: self.signer,
             expiration: U256::ZERO,
@@ -465,10 +979,326 @@ impl<K: AuthKind> OrderBuilder<Market, K> {
             order,
             order_type,
             post_only: None,
+            neg_risk: self.neg_risk,
+            limit_price: Some(price),
+            size: Some(size),
         })
     }
 }
 
+impl<K: AuthKind> OrderBuilder<Ladder, K> {
+    /// Sets the price of the first level for this ladder builder. This is a required field.
+    #[must_use]
+    pub fn price(mut self, price: Decimal) -> Self {
+        self.price = Some(price);
+        self
+    }
+
+    /// Sets the distance between consecutive levels, in multiples of the market's minimum tick
+    /// size. Buy ladders step down from the first price and sell ladders step up, so that each
+    /// level is further from the touch. Defaults to 1.
+    #[must_use]
+    pub fn step_ticks(mut self, step_ticks: u32) -> Self {
+        self.step_ticks = Some(step_ticks);
+        self
+    }
+
+    /// Sets the number of levels for this ladder builder. This is required unless the sizes are
+    /// set with [`sizes`](Self::sizes).
+    #[must_use]
+    pub fn levels(mut self, levels: usize) -> Self {
+        self.levels = Some(levels);
+        self
+    }
+
+    /// Sets the same size for every level. Either this or [`sizes`](Self::sizes) is required.
+    #[must_use]
+    pub fn size(mut self, size: Decimal) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the size of each level, starting with the first, for ladders whose size varies with
+    /// the distance from the first price. Either this or [`size`](Self::size) is required.
+    #[must_use]
+    pub fn sizes<I: IntoIterator<Item = Decimal>>(mut self, sizes: I) -> Self {
+        self.sizes = Some(sizes.into_iter().collect());
+        self
+    }
+
+    /// Validates and transforms this ladder builder into one [`SignableOrder`] per level, ready to
+    /// be signed and posted with [`Client::post_orders`]. The tick size and fee rate are fetched
+    /// from the CLOB once, unless they were set on this builder.
+    ///
+    /// With [`check_funds`](Self::check_funds), the levels are checked against the balance and
+    /// allowance together rather than one at a time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a required field is missing, if any level's price or size is invalid
+    /// for the market, or if the requests fail.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), err(level = "warn"))
+    )]
+    pub async fn build(self) -> Result<Vec<SignableOrder>> {
+        let (token_id, side, price, sizes) = self.required()?;
+
+        let fee_rate_bps = match self.fee_rate_bps {
+            Some(fee_rate_bps) => fee_rate_bps,
+            None => self.client.fee_rate_bps(token_id).await?.base_fee,
+        };
+        let tick_size = match self.tick_size {
+            Some(tick_size) => tick_size,
+            None => self.client.tick_size(token_id).await?.minimum_tick_size,
+        };
+
+        let orders = self.finish(token_id, side, price, &sizes, tick_size, fee_rate_bps)?;
+        if self.check_market {
+            self.client.check_market_orders(&orders).await?;
+        }
+        if self.check_funds {
+            self.client.check_total_funds(&orders).await?;
+        }
+
+        Ok(orders)
+    }
+
+    /// Validates and transforms this ladder builder into one [`SignableOrder`] per level without
+    /// contacting the CLOB, using the [`tick_size`](Self::tick_size),
+    /// [`fee_rate_bps`](Self::fee_rate_bps) and [`neg_risk`](Self::neg_risk) set on this builder.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of those market parameters is missing, or if the ladder is invalid
+    /// in the same way as for [`build`](Self::build).
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip(self), err(level = "warn"))
+    )]
+    pub fn build_offline(self) -> Result<Vec<SignableOrder>> {
+        let (token_id, side, price, sizes) = self.required()?;
+        let (tick_size, fee_rate_bps) = self.offline_parameters()?;
+
+        self.finish(token_id, side, price, &sizes, tick_size, fee_rate_bps)
+    }
+
+    /// Returns the token ID, side, first price and the size of each level, which are validated
+    /// before any market parameter is fetched.
+    fn required(&self) -> Result<(U256, Side, Decimal, Vec<Decimal>)> {
+        let Some(token_id) = self.token_id else {
+            return Err(Error::validation(
+                "Unable to build Order due to missing token ID",
+            ));
+        };
+
+        let Some(side) = self.side else {
+            return Err(Error::validation(
+                "Unable to build Order due to missing token side",
+            ));
+        };
+
+        let Some(price) = self.price else {
+            return Err(Error::validation(
+                "Unable to build Order due to missing price",
+            ));
+        };
+
+        let sizes = match (&self.sizes, self.size, self.levels) {
+            (Some(sizes), None, None) => sizes.clone(),
+            (Some(sizes), None, Some(levels)) if sizes.len() == levels => sizes.clone(),
+            (Some(sizes), None, Some(levels)) => {
+                return Err(Error::validation(format!(
+                    "Unable to build ladder with {} sizes for {levels} levels",
+                    sizes.len()
+                )));
+            }
+            (None, Some(size), Some(levels)) => vec![size; levels],
+            (None, Some(_), None) => {
+                return Err(Error::validation(
+                    "Unable to build ladder due to missing levels",
+                ));
+            }
+            (None, None, _) => {
+                return Err(Error::validation(
+                    "Unable to build ladder due to missing size",
+                ));
+            }
+            (Some(_), Some(_), _) => {
+                return Err(Error::validation(
+                    "Unable to build ladder with both a size and sizes",
+                ));
+            }
+        };
+
+        if sizes.is_empty() {
+            return Err(Error::validation(
+                "Unable to build ladder without any levels",
+            ));
+        }
+
+        if self.step_ticks == Some(0) {
+            return Err(Error::validation(
+                "Unable to build ladder with a step of 0 ticks",
+            ));
+        }
+
+        Ok((token_id, side, price, sizes))
+    }
+
+    /// Validates the whole ladder against the market parameters, then builds each level as a limit
+    /// order.
+    fn finish(
+        &self,
+        token_id: U256,
+        side: Side,
+        price: Decimal,
+        sizes: &[Decimal],
+        tick_size: TickSize,
+        fee_rate_bps: u32,
+    ) -> Result<Vec<SignableOrder>> {
+        let minimum_tick_size = tick_size.as_decimal();
+        let step = minimum_tick_size * Decimal::from(self.step_ticks.unwrap_or(1));
+
+        let step = match side {
+            Side::Buy => -step,
+            Side::Sell => step,
+            side => return Err(Error::validation(format!("Invalid side: {side}"))),
+        };
+        // `sizes` is not empty, and the levels in between are within the first and last prices
+        let last = price + step * Decimal::from(sizes.len() - 1);
+        if price.min(last) < minimum_tick_size || price.max(last) > Decimal::ONE - minimum_tick_size
+        {
+            return Err(Error::validation(format!(
+                "Ladder prices from {price} to {last} are too small or too large for the minimum \
+                 tick size {minimum_tick_size}"
+            )));
+        }
+
+        sizes
+            .iter()
+            .zip(0_u32..)
+            .map(|(&size, level)| {
+                let price = price + step * Decimal::from(level);
+                self.level(level, price, size).finish(
+                    token_id,
+                    side,
+                    price,
+                    tick_size,
+                    fee_rate_bps,
+                )
+            })
+            .collect()
+    }
+
+    /// Returns a limit builder for a single level of this ladder, whose client order ID, if any,
+    /// is suffixed with the level so that every level gets its own salt.
+    fn level(&self, level: u32, price: Decimal, size: Decimal) -> OrderBuilder<Limit, K> {
+        OrderBuilder {
+            client: self.client.clone(),
+            signer: self.signer,
+            signature_type: self.signature_type,
+            salt_generator: self.salt_generator,
+            client_order_id: self
+                .client_order_id
+                .as_ref()
+                .map(|client_order_id| format!("{client_order_id}/{level}")),
+            token_id: self.token_id,
+            price: Some(price),
+            size: Some(size),
+            amount: None,
+            side: self.side,
+            nonce: self.nonce,
+            expiration: self.expiration,
+            taker: self.taker,
+            order_type: self.order_type.clone(),
+            post_only: self.post_only,
+            funder: self.funder,
+            tick_size: self.tick_size,
+            fee_rate_bps: self.fee_rate_bps,
+            neg_risk: self.neg_risk,
+            check_funds: false,
+            check_market: false,
+            max_slippage: None,
+            price_rounding: None,
+            size_rounding: None,
+            crossing_policy: None,
+            top_of_book: None,
+            step_ticks: None,
+            levels: None,
+            sizes: None,
+            _kind: PhantomData,
+        }
+    }
+}
+
+/// Checks the market order `price` against `max_slippage` on `book`, returning the limit price to
+/// build at: `price` itself when it is within the bound, or the bound when it is exceeded and
+/// clamping is enabled.
+fn bound_slippage(
+    book: &OrderBookSummaryResponse,
+    side: Side,
+    price: Decimal,
+    tick_size: TickSize,
+    max_slippage: MaxSlippage,
+) -> Result<Decimal> {
+    // Bids and asks are both ordered with the best price last
+    let best_bid = book.bids.last().map(|level| level.price);
+    let best_ask = book.asks.last().map(|level| level.price);
+    let reference_price = match (max_slippage.reference, side) {
+        (SlippageReference::BestPrice, Side::Buy) => best_ask,
+        (SlippageReference::BestPrice, Side::Sell) => best_bid,
+        (SlippageReference::Midpoint, _) => best_bid
+            .zip(best_ask)
+            .map(|(bid, ask)| (bid + ask) / Decimal::TWO),
+        (_, side) => return Err(Error::validation(format!("Invalid side: {side}"))),
+    }
+    .ok_or_else(|| {
+        Error::validation(format!(
+            "Unable to bound slippage for {} without a {}",
+            book.asset_id, max_slippage.reference
+        ))
+    })?;
+
+    let minimum_tick_size = tick_size.as_decimal();
+    let distance = match max_slippage.bound {
+        SlippageBound::Percent(percent) if percent.is_sign_negative() => {
+            return Err(Error::validation(format!(
+                "Unable to bound slippage by negative percentage {percent}"
+            )));
+        }
+        SlippageBound::Percent(percent) => reference_price * percent / Decimal::ONE_HUNDRED,
+        SlippageBound::Ticks(ticks) => minimum_tick_size * Decimal::from(ticks),
+    };
+
+    // Snap the bound onto the tick grid on the side of the reference price, so that the limit
+    // price `finish` truncates to never crosses it
+    let (bound_price, exceeded) = if side == Side::Buy {
+        let bound_price =
+            ((reference_price + distance) / minimum_tick_size).floor() * minimum_tick_size;
+        (bound_price, price > bound_price)
+    } else {
+        let bound_price =
+            ((reference_price - distance) / minimum_tick_size).ceil() * minimum_tick_size;
+        (bound_price, price < bound_price)
+    };
+
+    match (exceeded, max_slippage.clamp) {
+        (false, _) => Ok(price),
+        (true, true) => Ok(bound_price),
+        (true, false) => Err(Error::with_source(
+            ErrorKind::Validation,
+            SlippageExceeded {
+                side,
+                reference: max_slippage.reference,
+                reference_price,
+                bound_price,
+                limit_price: price,
+            },
+        )),
+    }
+}
+
 /// Removes trailing zeros, truncates to [`USDC_DECIMALS`] decimal places, and quanitizes as an
 /// integer.
 fn to_fixed_u128(d: Decimal) -> u128 {
@@ -479,6 +1309,15 @@ fn to_fixed_u128(d: Decimal) -> u128 {
         .expect("The `build` call in `OrderBuilder<S, OrderKind, K>` ensures that only positive values are being multiplied/divided")
 }
 
+/// Derives a salt from the first 8 bytes of the keccak256 hash of `client_order_id`.
+fn client_order_salt(client_order_id: &str) -> u64 {
+    let hash = keccak256(client_order_id.as_bytes());
+    let mut bytes = [0; 8];
+    bytes.copy_from_slice(&hash[..8]);
+
+    u64::from_be_bytes(bytes)
+}
+
 /// Mask the salt to be <= 2^53 - 1, as the backend parses as an IEEE 754.
 fn to_ieee_754_int(salt: u64) -> u64 {
     salt & ((1 << 53) - 1)
@@ -535,4 +1374,10 @@ mod tests {
 
         assert!(masked_salt < (1 << 53));
     }
+
+    #[test]
+    fn client_order_salt_should_be_deterministic() {
+        assert_eq!(client_order_salt("quote-1"), client_order_salt("quote-1"));
+        assert_ne!(client_order_salt("quote-1"), client_order_salt("quote-2"));
+    }
 }
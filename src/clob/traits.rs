@@ -0,0 +1,338 @@
+//! Traits over the CLOB REST surface, for code that should not depend on a concrete backend.
+//!
+//! [`MarketData`] covers the public pricing and orderbook endpoints and is implemented by every
+//! [`Client`], while [`Trading`] covers placing, cancelling and listing the user's orders and
+//! trades and is implemented by authenticated clients. Services written against these traits
+//! can be handed a mock, a recorder or a simulator in place of the real client.
+//!
+//! Every method returns a [`Send`] future, so generic code can spawn the calls onto a
+//! multi-threaded runtime. The inherent methods of [`Client`] take precedence over the trait
+//! methods, so importing these traits does not change existing calls.
+//!
+//! # Example
+//!
+//! ```rust, no_run
+//! use polymarket_client_sdk::Result;
+//! use polymarket_client_sdk::clob::traits::MarketData;
+//! use polymarket_client_sdk::clob::types::request::MidpointRequest;
+//! use polymarket_client_sdk::types::{Decimal, U256};
+//!
+//! async fn midpoint(backend: &impl MarketData, token_id: U256) -> Result<Decimal> {
+//!     let request = MidpointRequest::builder().token_id(token_id).build();
+//!     Ok(backend.midpoint(&request).await?.mid)
+//! }
+//! ```
+
+use std::future::Future;
+
+use crate::auth::Kind;
+use crate::auth::state::{Authenticated, State};
+use crate::clob::Client;
+use crate::clob::types::SignedOrder;
+use crate::clob::types::request::{
+    CancelMarketOrderRequest, LastTradePriceRequest, MidpointRequest, OrderBookSummaryRequest,
+    OrdersRequest, PriceRequest, SpreadRequest, TradesRequest,
+};
+use crate::clob::types::response::{
+    CancelOrdersResponse, FeeRateResponse, LastTradePriceResponse, LastTradesPricesResponse,
+    MarketResponse, MidpointResponse, MidpointsResponse, NegRiskResponse, OpenOrderResponse,
+    OrderBookSummaryResponse, Page, PostOrderResponse, PriceResponse, PricesResponse,
+    SpreadResponse, SpreadsResponse, TickSizeResponse, TradeResponse,
+};
+use crate::types::U256;
+use crate::{Result, Timestamp};
+
+/// Public market data: prices, orderbooks and market metadata.
+///
+/// See the methods of the same name on [`Client`] for the behavior of the REST implementation.
+pub trait MarketData {
+    /// Current server time. See [`Client::server_time`].
+    fn server_time(&self) -> impl Future<Output = Result<Timestamp>> + Send;
+
+    /// Midpoint price of a token. See [`Client::midpoint`].
+    fn midpoint(
+        &self,
+        request: &MidpointRequest,
+    ) -> impl Future<Output = Result<MidpointResponse>> + Send;
+
+    /// Midpoint prices of several tokens. See [`Client::midpoints`].
+    fn midpoints(
+        &self,
+        requests: &[MidpointRequest],
+    ) -> impl Future<Output = Result<MidpointsResponse>> + Send;
+
+    /// Best price on one side of a token's book. See [`Client::price`].
+    fn price(&self, request: &PriceRequest) -> impl Future<Output = Result<PriceResponse>> + Send;
+
+    /// Best prices for several tokens and sides. See [`Client::prices`].
+    fn prices(
+        &self,
+        requests: &[PriceRequest],
+    ) -> impl Future<Output = Result<PricesResponse>> + Send;
+
+    /// Bid-ask spread of a token. See [`Client::spread`].
+    fn spread(
+        &self,
+        request: &SpreadRequest,
+    ) -> impl Future<Output = Result<SpreadResponse>> + Send;
+
+    /// Bid-ask spreads of several tokens. See [`Client::spreads`].
+    fn spreads(
+        &self,
+        requests: &[SpreadRequest],
+    ) -> impl Future<Output = Result<SpreadsResponse>> + Send;
+
+    /// Price of a token's most recent trade. See [`Client::last_trade_price`].
+    fn last_trade_price(
+        &self,
+        request: &LastTradePriceRequest,
+    ) -> impl Future<Output = Result<LastTradePriceResponse>> + Send;
+
+    /// Prices of several tokens' most recent trades. See [`Client::last_trades_prices`].
+    fn last_trades_prices(
+        &self,
+        token_ids: &[LastTradePriceRequest],
+    ) -> impl Future<Output = Result<Vec<LastTradesPricesResponse>>> + Send;
+
+    /// Minimum price increment of a token. See [`Client::tick_size`].
+    fn tick_size(&self, token_id: U256) -> impl Future<Output = Result<TickSizeResponse>> + Send;
+
+    /// Whether a token trades on the negative risk exchange. See [`Client::neg_risk`].
+    fn neg_risk(&self, token_id: U256) -> impl Future<Output = Result<NegRiskResponse>> + Send;
+
+    /// Fee rate of a token, in basis points. See [`Client::fee_rate_bps`].
+    fn fee_rate_bps(&self, token_id: U256) -> impl Future<Output = Result<FeeRateResponse>> + Send;
+
+    /// Orderbook of a token. See [`Client::order_book`].
+    fn order_book(
+        &self,
+        request: &OrderBookSummaryRequest,
+    ) -> impl Future<Output = Result<OrderBookSummaryResponse>> + Send;
+
+    /// Orderbooks of several tokens. See [`Client::order_books`].
+    fn order_books(
+        &self,
+        requests: &[OrderBookSummaryRequest],
+    ) -> impl Future<Output = Result<Vec<OrderBookSummaryResponse>>> + Send;
+
+    /// Details of a single market. See [`Client::market`].
+    fn market(&self, condition_id: &str) -> impl Future<Output = Result<MarketResponse>> + Send;
+
+    /// One page of markets. See [`Client::markets`].
+    fn markets(
+        &self,
+        next_cursor: Option<String>,
+    ) -> impl Future<Output = Result<Page<MarketResponse>>> + Send;
+}
+
+/// Order placement and the user's orders and trades.
+///
+/// See the methods of the same name on [`Client`] for the behavior of the REST implementation.
+pub trait Trading {
+    /// Posts a signed order. See [`Client::post_order`].
+    fn post_order(
+        &self,
+        order: SignedOrder,
+    ) -> impl Future<Output = Result<PostOrderResponse>> + Send;
+
+    /// Posts several signed orders at once. See [`Client::post_orders`].
+    fn post_orders(
+        &self,
+        orders: Vec<SignedOrder>,
+    ) -> impl Future<Output = Result<Vec<PostOrderResponse>>> + Send;
+
+    /// One of the user's orders. See [`Client::order`].
+    fn order(&self, order_id: &str) -> impl Future<Output = Result<OpenOrderResponse>> + Send;
+
+    /// One page of the user's open orders. See [`Client::orders`].
+    fn orders(
+        &self,
+        request: &OrdersRequest,
+        next_cursor: Option<String>,
+    ) -> impl Future<Output = Result<Page<OpenOrderResponse>>> + Send;
+
+    /// Cancels one order. See [`Client::cancel_order`].
+    fn cancel_order(
+        &self,
+        order_id: &str,
+    ) -> impl Future<Output = Result<CancelOrdersResponse>> + Send;
+
+    /// Cancels several orders. See [`Client::cancel_orders`].
+    fn cancel_orders(
+        &self,
+        order_ids: &[&str],
+    ) -> impl Future<Output = Result<CancelOrdersResponse>> + Send;
+
+    /// Cancels all of the user's orders. See [`Client::cancel_all_orders`].
+    fn cancel_all_orders(&self) -> impl Future<Output = Result<CancelOrdersResponse>> + Send;
+
+    /// Cancels the user's orders in a market or asset. See [`Client::cancel_market_orders`].
+    fn cancel_market_orders(
+        &self,
+        request: &CancelMarketOrderRequest,
+    ) -> impl Future<Output = Result<CancelOrdersResponse>> + Send;
+
+    /// One page of the user's trades. See [`Client::trades`].
+    fn trades(
+        &self,
+        request: &TradesRequest,
+        next_cursor: Option<String>,
+    ) -> impl Future<Output = Result<Page<TradeResponse>>> + Send;
+}
+
+impl<S: State + Send + Sync> MarketData for Client<S> {
+    fn server_time(&self) -> impl Future<Output = Result<Timestamp>> + Send {
+        Client::server_time(self)
+    }
+
+    fn midpoint(
+        &self,
+        request: &MidpointRequest,
+    ) -> impl Future<Output = Result<MidpointResponse>> + Send {
+        Client::midpoint(self, request)
+    }
+
+    fn midpoints(
+        &self,
+        requests: &[MidpointRequest],
+    ) -> impl Future<Output = Result<MidpointsResponse>> + Send {
+        Client::midpoints(self, requests)
+    }
+
+    fn price(&self, request: &PriceRequest) -> impl Future<Output = Result<PriceResponse>> + Send {
+        Client::price(self, request)
+    }
+
+    fn prices(
+        &self,
+        requests: &[PriceRequest],
+    ) -> impl Future<Output = Result<PricesResponse>> + Send {
+        Client::prices(self, requests)
+    }
+
+    fn spread(
+        &self,
+        request: &SpreadRequest,
+    ) -> impl Future<Output = Result<SpreadResponse>> + Send {
+        Client::spread(self, request)
+    }
+
+    fn spreads(
+        &self,
+        requests: &[SpreadRequest],
+    ) -> impl Future<Output = Result<SpreadsResponse>> + Send {
+        Client::spreads(self, requests)
+    }
+
+    fn last_trade_price(
+        &self,
+        request: &LastTradePriceRequest,
+    ) -> impl Future<Output = Result<LastTradePriceResponse>> + Send {
+        Client::last_trade_price(self, request)
+    }
+
+    fn last_trades_prices(
+        &self,
+        token_ids: &[LastTradePriceRequest],
+    ) -> impl Future<Output = Result<Vec<LastTradesPricesResponse>>> + Send {
+        Client::last_trades_prices(self, token_ids)
+    }
+
+    fn tick_size(&self, token_id: U256) -> impl Future<Output = Result<TickSizeResponse>> + Send {
+        Client::tick_size(self, token_id)
+    }
+
+    fn neg_risk(&self, token_id: U256) -> impl Future<Output = Result<NegRiskResponse>> + Send {
+        Client::neg_risk(self, token_id)
+    }
+
+    fn fee_rate_bps(&self, token_id: U256) -> impl Future<Output = Result<FeeRateResponse>> + Send {
+        Client::fee_rate_bps(self, token_id)
+    }
+
+    fn order_book(
+        &self,
+        request: &OrderBookSummaryRequest,
+    ) -> impl Future<Output = Result<OrderBookSummaryResponse>> + Send {
+        Client::order_book(self, request)
+    }
+
+    fn order_books(
+        &self,
+        requests: &[OrderBookSummaryRequest],
+    ) -> impl Future<Output = Result<Vec<OrderBookSummaryResponse>>> + Send {
+        Client::order_books(self, requests)
+    }
+
+    fn market(&self, condition_id: &str) -> impl Future<Output = Result<MarketResponse>> + Send {
+        Client::market(self, condition_id)
+    }
+
+    fn markets(
+        &self,
+        next_cursor: Option<String>,
+    ) -> impl Future<Output = Result<Page<MarketResponse>>> + Send {
+        Client::markets(self, next_cursor)
+    }
+}
+
+impl<K: Kind> Trading for Client<Authenticated<K>> {
+    fn post_order(
+        &self,
+        order: SignedOrder,
+    ) -> impl Future<Output = Result<PostOrderResponse>> + Send {
+        Client::post_order(self, order)
+    }
+
+    fn post_orders(
+        &self,
+        orders: Vec<SignedOrder>,
+    ) -> impl Future<Output = Result<Vec<PostOrderResponse>>> + Send {
+        Client::post_orders(self, orders)
+    }
+
+    fn order(&self, order_id: &str) -> impl Future<Output = Result<OpenOrderResponse>> + Send {
+        Client::order(self, order_id)
+    }
+
+    fn orders(
+        &self,
+        request: &OrdersRequest,
+        next_cursor: Option<String>,
+    ) -> impl Future<Output = Result<Page<OpenOrderResponse>>> + Send {
+        Client::orders(self, request, next_cursor)
+    }
+
+    fn cancel_order(
+        &self,
+        order_id: &str,
+    ) -> impl Future<Output = Result<CancelOrdersResponse>> + Send {
+        Client::cancel_order(self, order_id)
+    }
+
+    fn cancel_orders(
+        &self,
+        order_ids: &[&str],
+    ) -> impl Future<Output = Result<CancelOrdersResponse>> + Send {
+        Client::cancel_orders(self, order_ids)
+    }
+
+    fn cancel_all_orders(&self) -> impl Future<Output = Result<CancelOrdersResponse>> + Send {
+        Client::cancel_all_orders(self)
+    }
+
+    fn cancel_market_orders(
+        &self,
+        request: &CancelMarketOrderRequest,
+    ) -> impl Future<Output = Result<CancelOrdersResponse>> + Send {
+        Client::cancel_market_orders(self, request)
+    }
+
+    fn trades(
+        &self,
+        request: &TradesRequest,
+        next_cursor: Option<String>,
+    ) -> impl Future<Output = Result<Page<TradeResponse>>> + Send {
+        Client::trades(self, request, next_cursor)
+    }
+}
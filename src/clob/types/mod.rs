@@ -1,8 +1,17 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::error::Error as StdError;
 use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
 
 use alloy::core::sol;
-use alloy::primitives::{Signature, U256};
+use alloy::primitives::{ChainId, Signature, U256};
+use alloy::sol_types::{Eip712Domain, SolStruct as _};
 use bon::Builder;
+use chrono::{DateTime, TimeDelta, Utc};
+use rust_decimal::RoundingStrategy;
 use rust_decimal_macros::dec;
 use serde::ser::{Error as _, SerializeStruct as _};
 use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
@@ -13,12 +22,16 @@ use strum_macros::Display;
 use crate::Result;
 use crate::auth::ApiKey;
 use crate::clob::order_builder::{LOT_SIZE_SCALE, USDC_DECIMALS};
-use crate::error::Error;
-use crate::types::Decimal;
+use crate::error::{Error, Kind};
+use crate::types::{Address, B256, Decimal};
+use crate::{contract_config, derive_proxy_wallet, derive_safe_wallet};
 
 pub mod request;
 pub mod response;
 
+const ORDER_NAME: Option<Cow<'static, str>> = Some(Cow::Borrowed("Polymarket CTF Exchange"));
+const VERSION: Option<Cow<'static, str>> = Some(Cow::Borrowed("1"));
+
 // Re-export RFQ types for convenient access
 #[cfg(feature = "rfq")]
 pub use request::{
@@ -73,6 +86,21 @@ pub enum Side {
     Unknown = 255,
 }
 
+impl TryFrom<u8> for SignatureType {
+    type Error = Error;
+
+    fn try_from(value: u8) -> std::result::Result<Self, Self::Error> {
+        match value {
+            0 => Ok(SignatureType::Eoa),
+            1 => Ok(SignatureType::Proxy),
+            2 => Ok(SignatureType::GnosisSafe),
+            other => Err(Error::validation(format!(
+                "Unable to create SignatureType from {other}"
+            ))),
+        }
+    }
+}
+
 impl TryFrom<u8> for Side {
     type Error = Error;
 
@@ -218,6 +246,96 @@ impl Amount {
     }
 }
 
+/// How far the limit price of a market order may be from a reference price on the order book,
+/// as set with [`OrderBuilder::max_slippage`](crate::clob::order_builder::OrderBuilder::max_slippage).
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Builder, PartialEq)]
+pub struct MaxSlippage {
+    /// The maximum distance from the reference price, away from the touch.
+    pub bound: SlippageBound,
+    #[builder(default)]
+    pub reference: SlippageReference,
+    /// Whether a limit price beyond the bound is clamped to it, instead of failing the build with
+    /// [`SlippageExceeded`].
+    #[builder(default)]
+    pub clamp: bool,
+}
+
+/// The maximum distance of a [`MaxSlippage`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SlippageBound {
+    /// A percentage of the reference price, e.g. `dec!(2)` for 2%.
+    Percent(Decimal),
+    /// A number of the market's minimum ticks.
+    Ticks(u32),
+}
+
+/// The price a [`MaxSlippage`] is measured from.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, Display, PartialEq, Eq)]
+pub enum SlippageReference {
+    /// The best opposing price, which is the best ask for a buy and the best bid for a sell.
+    #[default]
+    #[strum(serialize = "best opposing price")]
+    BestPrice,
+    /// The midpoint between the best bid and the best ask.
+    #[strum(serialize = "midpoint")]
+    Midpoint,
+}
+
+/// How a limit order's price is rounded to the market's minimum tick size, or its size to the lot
+/// size, as set with
+/// [`OrderBuilder::price_rounding`](crate::clob::order_builder::OrderBuilder::price_rounding) and
+/// [`OrderBuilder::size_rounding`](crate::clob::order_builder::OrderBuilder::size_rounding).
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq)]
+pub enum Rounding {
+    /// Rounds towards zero.
+    #[strum(serialize = "down")]
+    Down,
+    /// Rounds away from zero.
+    #[strum(serialize = "up")]
+    Up,
+    /// Rounds to the nearest tick or lot, with midpoints rounded away from zero.
+    #[strum(serialize = "nearest")]
+    Nearest,
+    /// Rounds so that the order is never more aggressive than requested: buy prices down, sell
+    /// prices up, and sizes down.
+    #[strum(serialize = "passive")]
+    Passive,
+    /// Rounds so that the order is never less aggressive than requested: buy prices up, sell
+    /// prices down, and sizes up.
+    #[strum(serialize = "aggressive")]
+    Aggressive,
+}
+
+impl Rounding {
+    /// Rounds a price to `scale` decimal places for an order on `side`.
+    pub(crate) fn round_price(self, price: Decimal, scale: u32, side: Side) -> Decimal {
+        let strategy = match (self, side) {
+            (Self::Down, _) | (Self::Passive, Side::Buy) | (Self::Aggressive, Side::Sell) => {
+                RoundingStrategy::ToZero
+            }
+            (Self::Up | Self::Passive | Self::Aggressive, _) => RoundingStrategy::AwayFromZero,
+            (Self::Nearest, _) => RoundingStrategy::MidpointAwayFromZero,
+        };
+
+        price.round_dp_with_strategy(scale, strategy)
+    }
+
+    /// Rounds a size to `scale` decimal places.
+    pub(crate) fn round_size(self, size: Decimal, scale: u32) -> Decimal {
+        let strategy = match self {
+            Self::Down | Self::Passive => RoundingStrategy::ToZero,
+            Self::Up | Self::Aggressive => RoundingStrategy::AwayFromZero,
+            Self::Nearest => RoundingStrategy::MidpointAwayFromZero,
+        };
+
+        size.round_dp_with_strategy(scale, strategy)
+    }
+}
+
 #[non_exhaustive]
 #[derive(
     Clone,
@@ -422,6 +540,197 @@ impl<'de> Deserialize<'de> for TickSize {
     }
 }
 
+impl Serialize for TickSize {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Serialize::serialize(&self.as_decimal(), serializer)
+    }
+}
+
+/// A cached market metadata value along with the time it was stored.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CacheEntry<T> {
+    pub value: T,
+    pub cached_at: DateTime<Utc>,
+}
+
+impl<T> CacheEntry<T> {
+    /// Creates an entry for `value` stored at the current time.
+    #[must_use]
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            cached_at: Utc::now(),
+        }
+    }
+
+    /// Whether this entry is still valid under `ttl`. Entries never expire without a `ttl`.
+    #[must_use]
+    pub fn is_fresh(&self, ttl: Option<Duration>) -> bool {
+        ttl.is_none_or(|ttl| {
+            (Utc::now() - self.cached_at)
+                .to_std()
+                .map_or(true, |age| age < ttl)
+        })
+    }
+}
+
+/// The offset of the CLOB's clock from the local clock, as measured by
+/// [`Client::sync_clock`](crate::clob::Client::sync_clock).
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClockOffset {
+    /// How far the server clock is ahead of the local clock, negative when it is behind.
+    pub offset: TimeDelta,
+    /// The round-trip time of the `/time` request the offset was measured with.
+    pub round_trip: TimeDelta,
+    /// The change in offset since the previous measurement, if any.
+    pub drift: Option<TimeDelta>,
+    /// When the offset was measured, by the local clock.
+    pub measured_at: DateTime<Utc>,
+    /// Whether the offset exceeds the configured clock skew threshold.
+    pub skewed: bool,
+}
+
+/// Whether a market can currently take orders, and the smallest order it takes. Cached per token
+/// ID by [`Client::market_state`](crate::clob::Client::market_state).
+#[expect(
+    clippy::struct_excessive_bools,
+    reason = "These mirror the flags of the market API"
+)]
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Builder, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MarketState {
+    /// Whether the market is live, as opposed to not yet launched or archived.
+    pub active: bool,
+    /// Whether the market has closed, after which it takes no more orders.
+    pub closed: bool,
+    /// Whether the market trades on the CLOB order book at all.
+    pub enable_order_book: bool,
+    /// Whether the CLOB is currently taking orders for the market, which it stops doing for
+    /// a while before resolution.
+    pub accepting_orders: bool,
+    /// The minimum order size, in shares.
+    pub minimum_order_size: Decimal,
+}
+
+impl MarketState {
+    /// Checks that an order of `size` shares of `token_id` is allowed by this state.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first [`MarketStateViolation`] found, checking the market's flags before the
+    /// order's size.
+    pub fn check(
+        &self,
+        token_id: U256,
+        size: Decimal,
+    ) -> std::result::Result<(), MarketStateViolation> {
+        if self.closed {
+            Err(MarketStateViolation::Closed { token_id })
+        } else if !self.active {
+            Err(MarketStateViolation::Inactive { token_id })
+        } else if !self.enable_order_book {
+            Err(MarketStateViolation::OrderBookDisabled { token_id })
+        } else if !self.accepting_orders {
+            Err(MarketStateViolation::NotAcceptingOrders { token_id })
+        } else if size < self.minimum_order_size {
+            Err(MarketStateViolation::BelowMinimumSize {
+                token_id,
+                size,
+                minimum_order_size: self.minimum_order_size,
+            })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl From<&response::MarketResponse> for MarketState {
+    fn from(market: &response::MarketResponse) -> Self {
+        Self {
+            active: market.active,
+            closed: market.closed,
+            enable_order_book: market.enable_order_book,
+            accepting_orders: market.accepting_orders,
+            minimum_order_size: market.minimum_order_size,
+        }
+    }
+}
+
+/// Flags missing from the Gamma market are taken as `false`, and a missing `order_min_size` as
+/// zero.
+#[cfg(feature = "gamma")]
+impl From<&crate::gamma::types::response::Market> for MarketState {
+    fn from(market: &crate::gamma::types::response::Market) -> Self {
+        Self {
+            active: market.active.unwrap_or_default(),
+            closed: market.closed.unwrap_or_default(),
+            enable_order_book: market.enable_order_book.unwrap_or_default(),
+            accepting_orders: market.accepting_orders.unwrap_or_default(),
+            minimum_order_size: market.order_min_size.unwrap_or_default(),
+        }
+    }
+}
+
+/// A point-in-time copy of the client's tick size, neg risk, fee rate and market state caches.
+///
+/// Snapshots are serializable so that they can be persisted across restarts and loaded back
+/// with [`Client::import_caches`](crate::clob::Client::import_caches), skipping the per-token
+/// requests on startup.
+#[non_exhaustive]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CacheSnapshot {
+    pub tick_sizes: HashMap<U256, CacheEntry<TickSize>>,
+    pub neg_risk: HashMap<U256, CacheEntry<bool>>,
+    pub fee_rate_bps: HashMap<U256, CacheEntry<u32>>,
+    /// Defaults to empty, so that snapshots taken before market states were cached still load.
+    #[serde(default)]
+    pub market_states: HashMap<U256, CacheEntry<MarketState>>,
+}
+
+impl CacheSnapshot {
+    /// Serializes this snapshot to JSON bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        Ok(serde_json::to_vec(self)?)
+    }
+
+    /// Deserializes a snapshot previously produced by [`CacheSnapshot::to_bytes`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `bytes` is not a valid snapshot.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        Ok(serde_json::from_slice(bytes)?)
+    }
+
+    /// Writes this snapshot to the file at `path`, replacing it if it exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails or the file cannot be written.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        fs::write(path, self.to_bytes()?).map_err(|e| Error::with_source(Kind::Internal, e))
+    }
+
+    /// Reads a snapshot from the file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or does not contain a valid snapshot.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let bytes = fs::read(path).map_err(|e| Error::with_source(Kind::Internal, e))?;
+        Self::from_bytes(&bytes)
+    }
+}
+
 sol! {
     /// Alloy solidity type representing an order in the context of the Polymarket exchange
     ///
@@ -471,6 +780,20 @@ pub struct SignableOrder {
     pub order_type: OrderType,
     #[serde(rename = "postOnly", skip_serializing_if = "Option::is_none")]
     pub post_only: Option<bool>,
+    /// Whether the order's market is a neg risk market. When set, [`Client::sign`] uses it
+    /// instead of fetching it from the CLOB.
+    ///
+    /// [`Client::sign`]: crate::clob::Client::sign
+    #[serde(skip)]
+    pub neg_risk: Option<bool>,
+    /// The limit price the order was built at, after truncation to the tick size and any
+    /// [`MaxSlippage`] clamping. Set by the order builders and never sent to the CLOB.
+    #[serde(skip)]
+    pub limit_price: Option<Decimal>,
+    /// The size in shares the order was built with, after any [`Rounding`] and conversion from a
+    /// USDC amount. Set by the order builders and never sent to the CLOB.
+    #[serde(skip)]
+    pub size: Option<Decimal>,
 }
 
 #[non_exhaustive]
@@ -481,6 +804,9 @@ pub struct SignedOrder {
     pub order_type: OrderType,
     pub owner: ApiKey,
     pub post_only: Option<bool>,
+    /// Whether the order was signed for the neg risk exchange, as resolved by
+    /// [`Client::sign`](crate::clob::Client::sign). Never sent to the CLOB.
+    pub neg_risk: Option<bool>,
 }
 
 /// Helper struct for serializing Order with signature injected.
@@ -554,6 +880,601 @@ impl Serialize for SignedOrder {
     }
 }
 
+impl Order {
+    /// Computes the order hash used by the exchange on `chain_id` to identify this order. It is the
+    /// EIP-712 hash of the order under the exchange domain, which is both what gets signed and the
+    /// order ID returned by the CLOB once the order is posted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no exchange contract configured for `chain_id` and `neg_risk`.
+    pub fn hash(&self, chain_id: ChainId, neg_risk: bool) -> Result<B256> {
+        Ok(self.eip712_signing_hash(&exchange_domain(chain_id, neg_risk)?))
+    }
+
+    /// Returns the side of this order, along with the limit price and size in shares implied by
+    /// its maker and taker amounts.
+    #[cfg(feature = "ws")]
+    pub(crate) fn terms(&self) -> Result<(Side, Decimal, Decimal)> {
+        let side = Side::try_from(self.side)?;
+        let maker_amount = fixed_to_decimal(self.makerAmount)?;
+        let taker_amount = fixed_to_decimal(self.takerAmount)?;
+
+        // Buys give up USDC for shares, and sells give up shares for USDC
+        let (size, notional) = match side {
+            Side::Buy => (taker_amount, maker_amount),
+            _ => (maker_amount, taker_amount),
+        };
+        let price = notional.checked_div(size).unwrap_or_default().normalize();
+
+        Ok((side, price, size))
+    }
+
+    /// Returns the amount this order gives up when filled, in USDC for buys and in shares for
+    /// sells.
+    pub(crate) fn maker_amount(&self) -> Result<Decimal> {
+        fixed_to_decimal(self.makerAmount)
+    }
+
+    /// Returns the size of this order in shares, which is what it takes when buying and what it
+    /// gives up when selling.
+    pub(crate) fn size(&self) -> Result<Decimal> {
+        match Side::try_from(self.side)? {
+            Side::Buy => fixed_to_decimal(self.takerAmount),
+            _ => fixed_to_decimal(self.makerAmount),
+        }
+    }
+}
+
+/// Converts an on-chain amount, quantized to `USDC_DECIMALS`, into a [`Decimal`].
+fn fixed_to_decimal(amount: U256) -> Result<Decimal> {
+    i128::try_from(amount)
+        .ok()
+        .and_then(|fixed| Decimal::try_from_i128_with_scale(fixed, USDC_DECIMALS).ok())
+        .ok_or_else(|| Error::validation(format!("Order amount {amount} is too large")))
+}
+
+impl SignedOrder {
+    /// Recomputes the EIP-712 hash that was signed for this order, under the exchange domain for
+    /// `chain_id` and `neg_risk`. See [`Order::hash`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no exchange contract configured for `chain_id` and `neg_risk`.
+    pub fn signing_hash(&self, chain_id: ChainId, neg_risk: bool) -> Result<B256> {
+        self.order.hash(chain_id, neg_risk)
+    }
+
+    /// Returns the ID the CLOB will assign to this order, formatted the same way as
+    /// [`PostOrderResponse::order_id`](response::PostOrderResponse::order_id) and the `id` of
+    /// user channel order messages, so pending orders can be tracked before they are posted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no exchange contract configured for `chain_id` and `neg_risk`.
+    pub fn order_id(&self, chain_id: ChainId, neg_risk: bool) -> Result<String> {
+        Ok(self.signing_hash(chain_id, neg_risk)?.to_string())
+    }
+
+    /// Recovers the address that produced `signature` for this order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no exchange contract configured for `chain_id` and `neg_risk`,
+    /// or if no address can be recovered from `signature`.
+    pub fn recover_signer(&self, chain_id: ChainId, neg_risk: bool) -> Result<Address> {
+        let hash = self.signing_hash(chain_id, neg_risk)?;
+
+        self.signature
+            .recover_address_from_prehash(&hash)
+            .map_err(|e| Error::with_source(Kind::Validation, e))
+    }
+
+    /// Checks that this order was signed by `order.signer` for the exchange on `chain_id`, and
+    /// that `order.maker` is the wallet `order.signer` controls under `order.signatureType`:
+    /// the signer itself for [`SignatureType::Eoa`], or the proxy or Gnosis Safe wallet derived
+    /// from it otherwise.
+    ///
+    /// This does not contact the CLOB, so it can be used to audit orders signed elsewhere before
+    /// they are posted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InvalidSignature`] error describing the first check that failed, or an error
+    /// if there is no exchange contract configured for `chain_id` and `neg_risk`.
+    pub fn verify(&self, chain_id: ChainId, neg_risk: bool) -> Result<()> {
+        let Order {
+            maker,
+            signer,
+            signatureType,
+            ..
+        } = self.order;
+
+        let recovered = self.recover_signer(chain_id, neg_risk)?;
+        if recovered != signer {
+            return Err(InvalidSignature::SignerMismatch { signer, recovered }.into());
+        }
+
+        let Ok(signature_type) = SignatureType::try_from(signatureType) else {
+            return Err(InvalidSignature::UnknownSignatureType(signatureType).into());
+        };
+        let expected = match signature_type {
+            SignatureType::Eoa => Some(signer),
+            SignatureType::Proxy => derive_proxy_wallet(signer, chain_id),
+            SignatureType::GnosisSafe => derive_safe_wallet(signer, chain_id),
+        };
+
+        match expected {
+            Some(expected) if expected == maker => Ok(()),
+            _ => Err(InvalidSignature::MakerMismatch {
+                maker,
+                expected,
+                signature_type,
+            }
+            .into()),
+        }
+    }
+}
+
+/// Builds the EIP-712 domain of the exchange contract that settles orders on `chain_id`.
+pub(crate) fn exchange_domain(chain_id: ChainId, neg_risk: bool) -> Result<Eip712Domain> {
+    let exchange = contract_config(chain_id, neg_risk)
+        .ok_or(Error::missing_contract_config(chain_id, neg_risk))?
+        .exchange;
+
+    Ok(Eip712Domain {
+        name: ORDER_NAME,
+        version: VERSION,
+        chain_id: Some(U256::from(chain_id)),
+        verifying_contract: Some(exchange),
+        ..Eip712Domain::default()
+    })
+}
+
+/// Reason a [`SignedOrder`] failed [`verify`](SignedOrder::verify).
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InvalidSignature {
+    /// The signature was produced by `recovered` rather than by the order's `signer`.
+    SignerMismatch { signer: Address, recovered: Address },
+    /// The order's `signatureType` is not a known [`SignatureType`].
+    UnknownSignatureType(u8),
+    /// The order's `maker` is not the wallet that its `signer` controls under `signature_type`.
+    /// `expected` is `None` if that wallet cannot be derived on the chain.
+    MakerMismatch {
+        maker: Address,
+        expected: Option<Address>,
+        signature_type: SignatureType,
+    },
+}
+
+impl fmt::Display for InvalidSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SignerMismatch { signer, recovered } => {
+                write!(
+                    f,
+                    "order signer {signer} does not match recovered {recovered}"
+                )
+            }
+            Self::UnknownSignatureType(value) => write!(f, "unknown signature type {value}"),
+            Self::MakerMismatch {
+                maker,
+                expected: Some(expected),
+                signature_type,
+            } => write!(
+                f,
+                "order maker {maker} does not match {expected} for signature type {signature_type}"
+            ),
+            Self::MakerMismatch {
+                maker,
+                expected: None,
+                signature_type,
+            } => write!(
+                f,
+                "order maker {maker} cannot be derived for signature type {signature_type}"
+            ),
+        }
+    }
+}
+
+impl StdError for InvalidSignature {}
+
+impl From<InvalidSignature> for Error {
+    fn from(err: InvalidSignature) -> Self {
+        Error::with_source(Kind::Validation, err)
+    }
+}
+
+/// How [`Client::replace_order`](crate::clob::Client::replace_order) orders the cancellation of
+/// the original order and the posting of its replacement.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ReplaceStrategy {
+    /// Cancel the original, then post the replacement. The two are never live at the same time,
+    /// but nothing is quoted in between.
+    #[default]
+    CancelFirst,
+    /// Post the replacement, then cancel the original. There is no gap in quotes, but both are
+    /// briefly live and could both be matched.
+    PostFirst,
+}
+
+/// Error returned by [`Client::replace_order`](crate::clob::Client::replace_order) once the
+/// replacement order is signed, describing which half of the replacement did not go through and
+/// what is live as a result.
+#[non_exhaustive]
+#[derive(Debug)]
+pub enum ReplaceFailed {
+    /// The original order was not cancelled. With [`ReplaceStrategy::CancelFirst`] nothing was
+    /// posted, and with [`ReplaceStrategy::PostFirst`] the `replacement` is live alongside the
+    /// original.
+    NotCanceled {
+        /// ID of the original order.
+        order_id: String,
+        /// Why the cancellation did not go through.
+        reason: String,
+        /// The replacement, if it was posted before the cancellation was attempted.
+        replacement: Option<response::PostOrderResponse>,
+        /// The error the cancellation request failed with, if it did not get a response.
+        source: Option<Error>,
+    },
+    /// The replacement was not posted. With [`ReplaceStrategy::CancelFirst`] the original was
+    /// already `canceled`, so neither order is live, and with [`ReplaceStrategy::PostFirst`] the
+    /// original is untouched.
+    NotPosted {
+        /// ID of the original order.
+        order_id: String,
+        /// Whether the original order was cancelled before the replacement was posted.
+        canceled: bool,
+        /// Why the replacement was not accepted.
+        reason: String,
+        /// The error the post request failed with, if it did not get a response.
+        source: Option<Error>,
+    },
+}
+
+impl fmt::Display for ReplaceFailed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotCanceled {
+                order_id,
+                reason,
+                replacement: None,
+                ..
+            } => write!(f, "order {order_id} was not canceled or replaced: {reason}"),
+            Self::NotCanceled {
+                order_id,
+                reason,
+                replacement: Some(replacement),
+                ..
+            } => write!(
+                f,
+                "order {order_id} was not canceled, but its replacement {} is live: {reason}",
+                replacement.order_id
+            ),
+            Self::NotPosted {
+                order_id,
+                canceled: true,
+                reason,
+                ..
+            } => write!(
+                f,
+                "order {order_id} was canceled, but its replacement was not posted: {reason}"
+            ),
+            Self::NotPosted {
+                order_id,
+                canceled: false,
+                reason,
+                ..
+            } => write!(
+                f,
+                "replacement for order {order_id} was not posted, the original is still live: \
+                 {reason}"
+            ),
+        }
+    }
+}
+
+impl StdError for ReplaceFailed {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Self::NotCanceled { source, .. } | Self::NotPosted { source, .. } => source
+                .as_ref()
+                .map(|source| source as &(dyn StdError + 'static)),
+        }
+    }
+}
+
+/// Error returned when an order's maker amount is not covered by the available balance or
+/// allowance, as checked by [`Client::check_funds`](crate::clob::Client::check_funds).
+///
+/// Amounts are in USDC for [`AssetType::Collateral`] and in shares for
+/// [`AssetType::Conditional`].
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq)]
+pub struct InsufficientFunds {
+    pub asset_type: AssetType,
+    /// The conditional token being sold, if any.
+    pub token_id: Option<U256>,
+    /// The maker amount of the order being checked.
+    pub required: Decimal,
+    /// The amount already committed to open orders.
+    pub reserved: Decimal,
+    pub balance: Decimal,
+    /// The allowance granted to the exchange contract that settles the order.
+    pub allowance: Decimal,
+}
+
+impl InsufficientFunds {
+    /// The amount by which `required` plus `reserved` exceeds the lesser of the balance and the
+    /// allowance.
+    #[must_use]
+    pub fn shortfall(&self) -> Decimal {
+        self.required + self.reserved - self.balance.min(self.allowance)
+    }
+}
+
+impl fmt::Display for InsufficientFunds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let limit = if self.allowance < self.balance {
+            "allowance"
+        } else {
+            "balance"
+        };
+
+        write!(
+            f,
+            "insufficient {} {limit}: short by {} (order requires {}, open orders reserve {}, \
+             balance is {}, allowance is {})",
+            self.asset_type,
+            self.shortfall(),
+            self.required,
+            self.reserved,
+            self.balance,
+            self.allowance
+        )
+    }
+}
+
+impl StdError for InsufficientFunds {}
+
+impl From<InsufficientFunds> for Error {
+    fn from(err: InsufficientFunds) -> Self {
+        Error::with_source(Kind::Validation, err)
+    }
+}
+
+/// Error returned when the limit price of a market order is further from the reference price
+/// than its [`MaxSlippage`] allows.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SlippageExceeded {
+    pub side: Side,
+    pub reference: SlippageReference,
+    pub reference_price: Decimal,
+    /// The worst limit price allowed by the bound, snapped to the market's tick size.
+    pub bound_price: Decimal,
+    /// The limit price the order would have been built at.
+    pub limit_price: Decimal,
+}
+
+impl fmt::Display for SlippageExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} limit price {} is beyond the slippage bound {} from the {} {}",
+            self.side, self.limit_price, self.bound_price, self.reference, self.reference_price
+        )
+    }
+}
+
+impl StdError for SlippageExceeded {}
+
+/// What a limit order builder does when its order would cross, as set with
+/// [`OrderBuilder::crossing_policy`](crate::clob::order_builder::OrderBuilder::crossing_policy).
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq)]
+pub enum CrossingPolicy {
+    /// Fails the build with [`CrossingDetected`].
+    #[strum(serialize = "reject")]
+    Reject,
+    /// Moves the price to one tick behind the best price it would cross.
+    #[strum(serialize = "reprice")]
+    Reprice,
+    /// Cancels our own resting orders that the order would match. The cancellation is sent at the
+    /// end of the build, once the order has passed every check, so those orders are gone even if
+    /// the built order is never posted. A post-only order that would take liquidity from the book is still rejected, since
+    /// the orders it crosses may not be ours.
+    #[strum(serialize = "cancel resting")]
+    CancelResting,
+}
+
+/// How an order would cross, as reported by [`CrossingDetected`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq)]
+pub enum CrossingKind {
+    /// A post-only order would take liquidity from the book.
+    #[strum(serialize = "post-only crossing")]
+    PostOnly,
+    /// The order would match our own resting orders.
+    #[strum(serialize = "self-trade")]
+    SelfTrade,
+}
+
+/// The best bid and ask a limit order is checked against for post-only crossing. Built from an
+/// [`OrderBookSummaryResponse`](response::OrderBookSummaryResponse) or, with the `ws` feature, a
+/// locally maintained `LocalOrderBook`.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, Builder, PartialEq, Eq)]
+pub struct TopOfBook {
+    /// The highest bid price, or `None` if there are no bids.
+    pub best_bid: Option<Decimal>,
+    /// The lowest ask price, or `None` if there are no asks.
+    pub best_ask: Option<Decimal>,
+}
+
+impl From<&response::OrderBookSummaryResponse> for TopOfBook {
+    fn from(book: &response::OrderBookSummaryResponse) -> Self {
+        // Bids and asks are both ordered with the best price last
+        Self {
+            best_bid: book.bids.last().map(|level| level.price),
+            best_ask: book.asks.last().map(|level| level.price),
+        }
+    }
+}
+
+/// Error returned when a limit order would cross and its [`CrossingPolicy`] is
+/// [`CrossingPolicy::Reject`], or the crossing cannot be resolved by the policy.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CrossingDetected {
+    /// Whether the order would cross the book or our own resting orders.
+    pub kind: CrossingKind,
+    /// The side of the order being built.
+    pub side: Side,
+    /// The limit price of the order being built.
+    pub price: Decimal,
+    /// The best opposing price that would be crossed, on the book or among our resting orders.
+    pub crossed_price: Decimal,
+    /// The IDs of our resting orders that would be matched, for a [`CrossingKind::SelfTrade`].
+    pub order_ids: Vec<String>,
+}
+
+impl fmt::Display for CrossingDetected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} at {} would cross {}",
+            self.kind, self.side, self.price, self.crossed_price
+        )?;
+        if !self.order_ids.is_empty() {
+            write!(f, " (resting orders {})", self.order_ids.join(", "))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl StdError for CrossingDetected {}
+
+/// Error returned when an order is not allowed by the [`MarketState`] of its token, as checked by
+/// [`Client::check_market`](crate::clob::Client::check_market).
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MarketStateViolation {
+    /// The market is closed.
+    Closed { token_id: U256 },
+    /// The market is not active.
+    Inactive { token_id: U256 },
+    /// The market does not have an order book.
+    OrderBookDisabled { token_id: U256 },
+    /// The market is not accepting orders.
+    NotAcceptingOrders { token_id: U256 },
+    /// The order's size in shares is below the market's minimum order size.
+    BelowMinimumSize {
+        token_id: U256,
+        size: Decimal,
+        minimum_order_size: Decimal,
+    },
+}
+
+impl fmt::Display for MarketStateViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Closed { token_id } => write!(f, "market for token {token_id} is closed"),
+            Self::Inactive { token_id } => write!(f, "market for token {token_id} is not active"),
+            Self::OrderBookDisabled { token_id } => {
+                write!(f, "market for token {token_id} has no order book")
+            }
+            Self::NotAcceptingOrders { token_id } => {
+                write!(f, "market for token {token_id} is not accepting orders")
+            }
+            Self::BelowMinimumSize {
+                token_id,
+                size,
+                minimum_order_size,
+            } => write!(
+                f,
+                "size {size} is below the minimum order size {minimum_order_size} for token {token_id}"
+            ),
+        }
+    }
+}
+
+impl StdError for MarketStateViolation {}
+
+/// Why the CLOB rejected an order, parsed from the error message or code of a
+/// [`PostOrderResponse`](response::PostOrderResponse) or of a failed request.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RejectionReason {
+    /// The balance or allowance does not cover the order.
+    InsufficientBalance,
+    /// The price is not a multiple of the market's minimum tick size.
+    InvalidTickSize,
+    /// The size is below the market's minimum order size.
+    BelowMinimumSize,
+    /// The market is not ready for, or no longer accepting, orders.
+    MarketNotAccepting,
+    /// A post-only order would have crossed the book.
+    PostOnlyCrossing,
+    /// The same order was already placed.
+    Duplicate,
+    /// A FOK order could not be filled in full.
+    FokNotFilled,
+    /// Any other rejection, with the raw error text.
+    Other(String),
+}
+
+impl RejectionReason {
+    // Matched in order against the lowercased error text, which can be either a human readable
+    // message or an error code such as `INVALID_ORDER_MIN_TICK_SIZE`
+    const PATTERNS: &[(&str, RejectionReason)] = &[
+        ("not enough balance", Self::InsufficientBalance),
+        ("not_enough_balance", Self::InsufficientBalance),
+        ("insufficient balance", Self::InsufficientBalance),
+        ("tick size", Self::InvalidTickSize),
+        ("tick_size", Self::InvalidTickSize),
+        ("lower than the minimum", Self::BelowMinimumSize),
+        ("min_size", Self::BelowMinimumSize),
+        ("minimum size", Self::BelowMinimumSize),
+        ("not accepting orders", Self::MarketNotAccepting),
+        ("not yet ready", Self::MarketNotAccepting),
+        ("market_not_ready", Self::MarketNotAccepting),
+        ("market is closed", Self::MarketNotAccepting),
+        ("crosses book", Self::PostOnlyCrossing),
+        ("crosses the book", Self::PostOnlyCrossing),
+        ("duplicated", Self::Duplicate),
+        ("fok_order_not_filled", Self::FokNotFilled),
+        ("fok orders are fully filled or killed", Self::FokNotFilled),
+    ];
+
+    /// Parses a CLOB error message or code, falling back to [`RejectionReason::Other`] with the
+    /// raw text if it is not a known rejection.
+    #[must_use]
+    pub fn parse(message: &str) -> Self {
+        let lowercase = message.to_lowercase();
+
+        Self::PATTERNS
+            .iter()
+            .find(|(pattern, _)| lowercase.contains(pattern))
+            .map_or_else(
+                || Self::Other(message.to_owned()),
+                |(_, reason)| reason.clone(),
+            )
+    }
+
+    /// Returns why an order was rejected, if `error` is the [`Status`](crate::error::Status)
+    /// error of a failed CLOB request.
+    #[must_use]
+    pub fn from_error(error: &Error) -> Option<Self> {
+        error
+            .downcast_ref::<crate::error::Status>()
+            .map(|status| Self::parse(&status.message))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use serde_json::to_value;
@@ -561,6 +1482,75 @@ mod tests {
     use super::*;
     use crate::error::Validation;
 
+    #[cfg(feature = "gamma")]
+    #[test]
+    fn market_state_should_convert_from_gamma_market() {
+        let market = crate::gamma::types::response::Market::builder()
+            .id("1".to_owned())
+            .active(true)
+            .closed(false)
+            .enable_order_book(true)
+            .accepting_orders(true)
+            .order_min_size(Decimal::from(5))
+            .build();
+
+        let expected = MarketState::builder()
+            .active(true)
+            .closed(false)
+            .enable_order_book(true)
+            .accepting_orders(true)
+            .minimum_order_size(Decimal::from(5))
+            .build();
+        assert_eq!(MarketState::from(&market), expected);
+
+        let unknown = crate::gamma::types::response::Market::builder()
+            .id("1".to_owned())
+            .build();
+        assert!(!MarketState::from(&unknown).accepting_orders);
+    }
+
+    #[test]
+    fn rejection_reason_should_parse_messages_and_codes() {
+        let cases = [
+            (
+                "not enough balance / allowance",
+                RejectionReason::InsufficientBalance,
+            ),
+            (
+                "INVALID_ORDER_MIN_TICK_SIZE",
+                RejectionReason::InvalidTickSize,
+            ),
+            (
+                "order 0x1 is invalid. Size (1) lower than the minimum: 5",
+                RejectionReason::BelowMinimumSize,
+            ),
+            (
+                "the market is not yet ready to process new orders",
+                RejectionReason::MarketNotAccepting,
+            ),
+            (
+                "invalid post-only order: order crosses book",
+                RejectionReason::PostOnlyCrossing,
+            ),
+            (
+                "order 0x1 is invalid. Duplicated.",
+                RejectionReason::Duplicate,
+            ),
+            (
+                "order couldn't be fully filled. FOK orders are fully filled or killed.",
+                RejectionReason::FokNotFilled,
+            ),
+            (
+                "could not insert order",
+                RejectionReason::Other("could not insert order".to_owned()),
+            ),
+        ];
+
+        for (message, expected) in cases {
+            assert_eq!(RejectionReason::parse(message), expected, "{message}");
+        }
+    }
+
     #[test]
     fn tick_size_decimals_should_succeed() {
         assert_eq!(TickSize::Tenth.as_decimal().scale(), 1);
@@ -716,6 +1706,7 @@ mod tests {
             order_type: OrderType::GTC,
             owner: ApiKey::nil(),
             post_only: None,
+            neg_risk: None,
         };
 
         let value = to_value(&signed_order).expect("serialize SignedOrder");
@@ -725,4 +1716,180 @@ mod tests {
 
         assert!(!object.contains_key("postOnly"));
     }
+
+    mod verify {
+        use alloy::signers::SignerSync as _;
+        use alloy::signers::local::PrivateKeySigner;
+
+        use super::*;
+        use crate::POLYGON;
+
+        const PRIVATE_KEY: &str =
+            "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+
+        fn sign(order: Order, neg_risk: bool) -> SignedOrder {
+            let signer: PrivateKeySigner = PRIVATE_KEY.parse().unwrap();
+            let hash = order.hash(POLYGON, neg_risk).unwrap();
+
+            SignedOrder {
+                order,
+                signature: signer.sign_hash_sync(&hash).unwrap(),
+                order_type: OrderType::GTC,
+                owner: ApiKey::nil(),
+                post_only: None,
+                neg_risk: Some(neg_risk),
+            }
+        }
+
+        fn order(signature_type: SignatureType) -> Order {
+            let signer: PrivateKeySigner = PRIVATE_KEY.parse().unwrap();
+            let maker = match signature_type {
+                SignatureType::Eoa => signer.address(),
+                SignatureType::Proxy => derive_proxy_wallet(signer.address(), POLYGON).unwrap(),
+                SignatureType::GnosisSafe => derive_safe_wallet(signer.address(), POLYGON).unwrap(),
+            };
+
+            Order {
+                salt: U256::from(1),
+                maker,
+                signer: signer.address(),
+                tokenId: U256::from(1234),
+                makerAmount: U256::from(50_000_000),
+                takerAmount: U256::from(100_000_000),
+                signatureType: signature_type as u8,
+                ..Order::default()
+            }
+        }
+
+        #[test]
+        fn verify_should_succeed_for_every_signature_type() {
+            for signature_type in [
+                SignatureType::Eoa,
+                SignatureType::Proxy,
+                SignatureType::GnosisSafe,
+            ] {
+                let signed = sign(order(signature_type), false);
+
+                signed.verify(POLYGON, false).unwrap();
+                assert_eq!(
+                    signed.recover_signer(POLYGON, false).unwrap(),
+                    signed.order.signer
+                );
+            }
+
+            sign(order(SignatureType::Eoa), true)
+                .verify(POLYGON, true)
+                .unwrap();
+        }
+
+        /// The Amoy order from the reference `py-order-utils` test suite, with the hash and
+        /// signature Polymarket's own signer produces for it.
+        #[test]
+        fn order_id_should_match_reference_vector() {
+            let signer: PrivateKeySigner = PRIVATE_KEY.parse().unwrap();
+            let signed = SignedOrder {
+                order: Order {
+                    salt: U256::from(479_249_096_354_u64),
+                    maker: signer.address(),
+                    signer: signer.address(),
+                    tokenId: U256::from(1234),
+                    makerAmount: U256::from(100_000_000),
+                    takerAmount: U256::from(50_000_000),
+                    feeRateBps: U256::from(100),
+                    side: Side::Buy as u8,
+                    signatureType: SignatureType::Eoa as u8,
+                    ..Order::default()
+                },
+                signature: "0x302cd9abd0b5fcaa202a344437ec0b6660da984e24ae9ad915a592a90facf5a51bb8a873cd8d270f070217fea1986531d5eec66f1162a81f66e026db653bf7ce1c"
+                    .parse()
+                    .unwrap(),
+                order_type: OrderType::GTC,
+                owner: ApiKey::nil(),
+                post_only: None,
+                neg_risk: Some(false),
+            };
+
+            assert_eq!(
+                signed.order_id(crate::AMOY, false).unwrap(),
+                "0x02ca1d1aa31103804173ad1acd70066cb6c1258a4be6dada055111f9a7ea4e55"
+            );
+            signed.verify(crate::AMOY, false).unwrap();
+        }
+
+        #[test]
+        fn order_id_should_depend_on_chain_and_exchange() {
+            let signed = sign(order(SignatureType::Eoa), false);
+            let hash = signed.order.hash(POLYGON, false).unwrap();
+
+            assert_eq!(signed.order_id(POLYGON, false).unwrap().len(), 66);
+            assert_ne!(
+                hash,
+                signed.order.hash(POLYGON, true).unwrap(),
+                "neg risk orders are hashed under a different exchange"
+            );
+            assert_ne!(
+                hash,
+                signed.order.hash(crate::AMOY, false).unwrap(),
+                "orders are hashed per chain"
+            );
+        }
+
+        #[test]
+        fn verify_with_wrong_domain_should_fail() {
+            let signed = sign(order(SignatureType::Eoa), false);
+
+            let err = signed.verify(POLYGON, true).unwrap_err();
+
+            assert!(matches!(
+                err.downcast_ref::<InvalidSignature>(),
+                Some(InvalidSignature::SignerMismatch { .. })
+            ));
+        }
+
+        #[test]
+        fn verify_with_tampered_order_should_fail() {
+            let mut signed = sign(order(SignatureType::Eoa), false);
+            signed.order.makerAmount = U256::from(1);
+
+            let err = signed.verify(POLYGON, false).unwrap_err();
+
+            assert_eq!(err.kind(), Kind::Validation);
+            assert!(matches!(
+                err.downcast_ref::<InvalidSignature>(),
+                Some(InvalidSignature::SignerMismatch { .. })
+            ));
+        }
+
+        #[test]
+        fn verify_with_wrong_maker_should_fail() {
+            let mut order = order(SignatureType::Proxy);
+            order.maker = Address::repeat_byte(1);
+            let signed = sign(order, false);
+
+            let err = signed.verify(POLYGON, false).unwrap_err();
+
+            assert_eq!(
+                err.downcast_ref::<InvalidSignature>(),
+                Some(&InvalidSignature::MakerMismatch {
+                    maker: Address::repeat_byte(1),
+                    expected: derive_proxy_wallet(signed.order.signer, POLYGON),
+                    signature_type: SignatureType::Proxy,
+                })
+            );
+        }
+
+        #[test]
+        fn verify_with_unknown_signature_type_should_fail() {
+            let mut order = order(SignatureType::Eoa);
+            order.signatureType = 7;
+            let signed = sign(order, false);
+
+            let err = signed.verify(POLYGON, false).unwrap_err();
+
+            assert_eq!(
+                err.downcast_ref::<InvalidSignature>(),
+                Some(&InvalidSignature::UnknownSignatureType(7))
+            );
+        }
+    }
 }
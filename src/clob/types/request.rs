@@ -12,12 +12,12 @@ use serde_with::{
 #[cfg(feature = "rfq")]
 use {
     crate::clob::types::{RfqSortBy, RfqSortDir, RfqState},
-    crate::{Timestamp, auth::ApiKey, types::Decimal},
+    crate::{Timestamp, auth::ApiKey},
 };
 
-use crate::clob::types::{AssetType, Side, SignatureType, TimeRange};
+use crate::clob::types::{AssetType, ReplaceStrategy, Side, SignatureType, TimeRange};
 use crate::types::U256;
-use crate::types::{Address, B256};
+use crate::types::{Address, B256, Decimal};
 
 #[serde_as]
 #[non_exhaustive]
@@ -99,6 +99,26 @@ pub struct CancelMarketOrderRequest {
     pub asset_id: Option<U256>,
 }
 
+/// Replacement of a resting order with one at a new price and/or size, for
+/// [`Client::replace_order`](crate::clob::Client::replace_order).
+#[non_exhaustive]
+#[derive(Debug, Clone, Builder)]
+#[builder(on(String, into))]
+pub struct ReplaceOrderRequest {
+    /// ID of the order to replace.
+    pub order_id: String,
+    /// Price of the replacement. Defaults to the original order's price.
+    pub price: Option<Decimal>,
+    /// Size of the replacement, in shares. Defaults to the original order's unmatched size.
+    pub size: Option<Decimal>,
+    /// Whether the replacement is post-only. The CLOB does not report whether the original
+    /// order was, so set this when amending a post-only quote, or the replacement may take
+    /// liquidity.
+    pub post_only: Option<bool>,
+    #[builder(default)]
+    pub strategy: ReplaceStrategy,
+}
+
 #[non_exhaustive]
 #[serde_as]
 #[derive(Debug, Default, Clone, Builder, Serialize)]
@@ -4,6 +4,7 @@
 )]
 
 use std::collections::HashMap;
+use std::sync::Arc;
 
 use bon::Builder;
 use chrono::{DateTime, NaiveDate, Utc};
@@ -12,12 +13,17 @@ use serde_with::{
     DefaultOnError, DefaultOnNull, NoneAsEmptyString, TimestampMilliSeconds, TimestampSeconds,
     TryFromInto, serde_as,
 };
+use sha1::Sha1;
 use sha2::{Digest as _, Sha256};
 use uuid::Uuid;
 
 use crate::Result;
 use crate::auth::ApiKey;
-use crate::clob::types::{OrderStatusType, OrderType, Side, TickSize, TradeStatusType, TraderSide};
+use crate::clob::types::{
+    Amount, AmountInner, OrderStatusType, OrderType, RejectionReason, Side, TickSize,
+    TradeStatusType, TraderSide,
+};
+use crate::error::Error;
 use crate::serde_helpers::StringFromAny;
 use crate::types::{Address, B256, Decimal, U256};
 
@@ -147,6 +153,147 @@ impl OrderBookSummaryResponse {
 
         Ok(format!("{result:x}"))
     }
+
+    /// Computes the `hash` the CLOB sends with this summary, the SHA-1 of its compact JSON with
+    /// an empty `hash`, laid out as py-clob-client's `generate_orderbook_summary_hash` does.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the summary cannot be serialized.
+    pub fn summary_hash(&self) -> Result<String> {
+        let summary = HashedSummary {
+            market: self.market,
+            asset_id: self.asset_id.to_string(),
+            timestamp: self.timestamp.timestamp_millis().to_string(),
+            bids: &self.bids,
+            asks: &self.asks,
+            min_order_size: self.min_order_size,
+            neg_risk: self.neg_risk,
+            tick_size: self.tick_size.as_decimal(),
+            hash: "",
+        };
+        let json = serde_json::to_string(&summary)?;
+
+        Ok(format!("{:x}", Sha1::digest(json.as_bytes())))
+    }
+
+    /// Simulates a market order on `side` for `amount` against this book, consuming the opposing
+    /// levels best price first. `fee_rate_bps` is used to estimate the fee the fill would incur.
+    ///
+    /// If the book cannot fill the whole amount, the simulation covers all of the opposing
+    /// liquidity and [`FillSimulation::filled`] is `false`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are no opposing orders, or if `amount` is in USDC for a sell.
+    pub fn simulate_fill(
+        &self,
+        side: Side,
+        amount: Amount,
+        fee_rate_bps: u32,
+    ) -> Result<FillSimulation> {
+        // Bids and asks are both ordered with the best price last
+        let levels = match (side, amount.0) {
+            (Side::Buy, _) => &self.asks,
+            (Side::Sell, AmountInner::Shares(_)) => &self.bids,
+            (Side::Sell, AmountInner::Usdc(_)) => {
+                return Err(Error::validation(
+                    "Sell Orders must specify their `amount`s in shares",
+                ));
+            }
+            (side, _) => return Err(Error::validation(format!("Invalid side: {side}"))),
+        };
+
+        if levels.is_empty() {
+            return Err(Error::validation(format!(
+                "No opposing orders for {} which means there is no market price",
+                self.asset_id
+            )));
+        }
+
+        let fee_rate = Decimal::from(fee_rate_bps) / Decimal::from(10_000);
+        let mut remaining = amount.as_inner();
+        let mut simulation = FillSimulation {
+            average_price: Decimal::ZERO,
+            worst_price: Decimal::ZERO,
+            shares: Decimal::ZERO,
+            usdc: Decimal::ZERO,
+            fee: Decimal::ZERO,
+            levels: Vec::new(),
+            filled: false,
+        };
+
+        for level in levels.iter().rev() {
+            let shares = match amount.0 {
+                AmountInner::Usdc(_) if level.size * level.price > remaining => {
+                    remaining / level.price
+                }
+                AmountInner::Usdc(_) => level.size,
+                AmountInner::Shares(_) => level.size.min(remaining),
+            };
+            let usdc = shares * level.price;
+
+            remaining -= match amount.0 {
+                AmountInner::Usdc(_) => usdc,
+                AmountInner::Shares(_) => shares,
+            };
+            simulation.shares += shares;
+            simulation.usdc += usdc;
+            simulation.fee += fee_rate * level.price.min(Decimal::ONE - level.price) * shares;
+            simulation.worst_price = level.price;
+            simulation.levels.push(OrderSummary {
+                price: level.price,
+                size: shares,
+            });
+
+            if remaining <= Decimal::ZERO {
+                simulation.filled = true;
+                break;
+            }
+        }
+
+        simulation.average_price = simulation
+            .usdc
+            .checked_div(simulation.shares)
+            .unwrap_or_default();
+
+        Ok(simulation)
+    }
+}
+
+/// The expected outcome of a market order walking an [`OrderBookSummaryResponse`], as returned by
+/// [`OrderBookSummaryResponse::simulate_fill`].
+#[non_exhaustive]
+#[derive(Clone, Debug, Builder, PartialEq)]
+pub struct FillSimulation {
+    /// The volume-weighted average price of the fill.
+    pub average_price: Decimal,
+    /// The price of the last level consumed, which is the limit price needed to fill this much.
+    pub worst_price: Decimal,
+    /// The shares received by a buy, or given up by a sell.
+    pub shares: Decimal,
+    /// The USDC spent by a buy, or received by a sell.
+    pub usdc: Decimal,
+    /// The estimated fee in USDC, charged at the fee rate scaled by `min(price, 1 - price)`.
+    pub fee: Decimal,
+    /// The portion of each level consumed, best price first.
+    pub levels: Vec<OrderSummary>,
+    /// Whether the book had enough liquidity for the whole amount.
+    pub filled: bool,
+}
+
+/// The fields of an [`OrderBookSummaryResponse`] in the order the CLOB hashes them.
+#[derive(Serialize)]
+struct HashedSummary<'summary> {
+    market: B256,
+    asset_id: String,
+    timestamp: String,
+    bids: &'summary [OrderSummary],
+    asks: &'summary [OrderSummary],
+    min_order_size: Decimal,
+    neg_risk: bool,
+    tick_size: Decimal,
+    hash: &'static str,
 }
 
 #[non_exhaustive]
@@ -305,6 +452,18 @@ pub struct PostOrderResponse {
     pub trade_ids: Vec<String>,
 }
 
+impl PostOrderResponse {
+    /// Returns why the order was rejected, parsed from [`error_msg`](Self::error_msg), or `None`
+    /// if there is no error message.
+    #[must_use]
+    pub fn rejection_reason(&self) -> Option<RejectionReason> {
+        self.error_msg
+            .as_deref()
+            .filter(|message| !message.is_empty())
+            .map(RejectionReason::parse)
+    }
+}
+
 pub fn empty_string_as_zero<'de, D>(deserializer: D) -> std::result::Result<Decimal, D::Error>
 where
     D: Deserializer<'de>,
@@ -361,6 +520,54 @@ pub struct CancelOrdersResponse {
     pub not_canceled: HashMap<String, String>,
 }
 
+/// The outcome for one input of a chunked batch request, paired with that input. See
+/// [`Client::post_orders_chunked`](crate::clob::Client::post_orders_chunked) and
+/// [`Client::cancel_orders_chunked`](crate::clob::Client::cancel_orders_chunked).
+///
+/// The error is that of the request for the input's chunk, and is shared by every input in it.
+#[non_exhaustive]
+#[derive(Debug)]
+pub struct BatchResult<I, T> {
+    /// The input this result is for, in the order the inputs were given
+    pub input: I,
+    /// The CLOB's response for `input`, or the error of the request that carried it
+    pub result: std::result::Result<T, Arc<Error>>,
+}
+
+/// Whether an order was canceled by a chunked cancellation, as reported by the CLOB.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CancelStatus {
+    /// The order was listed as `canceled`.
+    Canceled,
+    /// The order was listed as `not_canceled`.
+    NotCanceled {
+        /// Why the CLOB did not cancel the order, e.g. because it was already filled or canceled
+        reason: String,
+    },
+}
+
+/// Outcome of a successful [`Client::resubmit_order`](crate::clob::Client::resubmit_order).
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq)]
+pub enum Resubmission {
+    /// The CLOB already knows the order, whether it is still open, matched or canceled, so it was
+    /// not posted again.
+    Existing(OpenOrderResponse),
+    /// The CLOB did not know the order, and it has been posted.
+    Posted(PostOrderResponse),
+}
+
+/// Outcome of a successful [`Client::replace_order`](crate::clob::Client::replace_order).
+#[non_exhaustive]
+#[derive(Debug, Builder, PartialEq)]
+pub struct ReplaceOrderResponse {
+    /// Cancellation of the original order.
+    pub canceled: CancelOrdersResponse,
+    /// Posting of the replacement order.
+    pub posted: PostOrderResponse,
+}
+
 #[non_exhaustive]
 #[serde_as]
 #[derive(Debug, Clone, Deserialize, Builder, PartialEq)]
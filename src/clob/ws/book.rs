@@ -0,0 +1,701 @@
+//! Locally maintained orderbooks built from the market channel.
+//!
+//! The market channel sends a full [`BookUpdate`] snapshot when an asset is first subscribed
+//! (and after trades), followed by incremental [`PriceChange`] level updates. [`LocalOrderBook`]
+//! merges the two into a single sorted view, and [`OrderBookManager`] keeps one book per asset
+//! with a [`watch`] feed for consumers that only care about the latest state.
+
+use std::collections::BTreeMap;
+use std::error::Error as StdError;
+use std::fmt;
+
+use dashmap::DashMap;
+use dashmap::mapref::one::Ref;
+use tokio::sync::watch;
+
+use super::subscription::StaleReason;
+use super::types::response::{BookUpdate, OrderBookLevel, PriceChange, WsMessage};
+use crate::Result;
+use crate::clob::types::response::OrderBookSummaryResponse;
+use crate::clob::types::{Side, TopOfBook};
+use crate::error::{Error, Kind};
+use crate::types::{B256, Decimal, U256};
+
+/// Orderbook for a single asset, kept in sync from a snapshot plus level updates.
+///
+/// Levels are keyed by price, so bids and asks are always sorted and a level with a size of zero
+/// is removed rather than stored.
+///
+/// The server's `hash` covers fields that the market channel does not carry, such as the tick
+/// size and minimum order size, so it is only verified for books loaded over REST with
+/// [`from_summary`](Self::from_summary). Books kept in sync from the feed are instead checked
+/// against the best bid and ask that the server reports with each price change.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalOrderBook {
+    asset_id: U256,
+    market: B256,
+    timestamp: i64,
+    hash: Option<String>,
+    bids: BTreeMap<Decimal, Decimal>,
+    asks: BTreeMap<Decimal, Decimal>,
+}
+
+impl LocalOrderBook {
+    /// Creates a book from a full orderbook snapshot.
+    #[must_use]
+    pub fn from_snapshot(snapshot: &BookUpdate) -> Self {
+        let mut book = Self {
+            asset_id: snapshot.asset_id,
+            market: snapshot.market,
+            timestamp: snapshot.timestamp,
+            hash: None,
+            bids: BTreeMap::new(),
+            asks: BTreeMap::new(),
+        };
+        book.apply_snapshot(snapshot);
+        book
+    }
+
+    /// Creates a book from an [`order_book`] response, verifying its `hash`.
+    ///
+    /// Summaries without a `hash` are accepted as is.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`OutOfSync`] error if the summary does not hash to the `hash` the server sent
+    /// with it, meaning the response was altered or decoded incorrectly.
+    ///
+    /// [`order_book`]: crate::clob::Client::order_book
+    pub fn from_summary(summary: &OrderBookSummaryResponse) -> Result<Self> {
+        if let Some(expected) = &summary.hash {
+            let actual = summary.summary_hash()?;
+            if *expected != actual {
+                return Err(OutOfSync {
+                    asset_id: summary.asset_id,
+                    divergence: Divergence::Hash {
+                        expected: expected.clone(),
+                        actual,
+                    },
+                }
+                .into());
+            }
+        }
+
+        Ok(Self::from_snapshot(&summary.clone().into()))
+    }
+
+    /// Replaces every level in the book with the contents of `snapshot`.
+    ///
+    /// Snapshots for other assets are ignored.
+    pub fn apply_snapshot(&mut self, snapshot: &BookUpdate) {
+        if snapshot.asset_id != self.asset_id {
+            return;
+        }
+
+        self.market = snapshot.market;
+        self.timestamp = snapshot.timestamp;
+        self.hash.clone_from(&snapshot.hash);
+        self.bids = levels(&snapshot.bids);
+        self.asks = levels(&snapshot.asks);
+    }
+
+    /// Applies the entries of `change` that belong to this book's asset.
+    ///
+    /// Returns `true` if any entry touched this book. When the server reports the best bid or ask
+    /// alongside an entry, the resulting top of book is checked against it. The entry's `hash` is
+    /// recorded as the book's [`hash`](Self::hash) without being verified. Changes older than the
+    /// book itself, such as those buffered while a snapshot was being fetched, are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`OutOfSync`] error if the local best bid or ask no longer matches the one
+    /// reported by the server, meaning an update was missed and the book must be rebuilt from a
+    /// fresh snapshot.
+    pub fn apply_price_change(&mut self, change: &PriceChange) -> Result<bool> {
+        let mut touched = false;
+
+        if change.timestamp < self.timestamp {
+            return Ok(false);
+        }
+
+        for entry in change
+            .price_changes
+            .iter()
+            .filter(|entry| entry.asset_id == self.asset_id)
+        {
+            touched = true;
+
+            if let Some(size) = entry.size {
+                let side = match entry.side {
+                    Side::Buy => &mut self.bids,
+                    Side::Sell => &mut self.asks,
+                    side => {
+                        return Err(Error::validation(format!(
+                            "unable to apply price change for {}: unknown side {side}",
+                            self.asset_id
+                        )));
+                    }
+                };
+
+                if size.is_zero() {
+                    side.remove(&entry.price);
+                } else {
+                    side.insert(entry.price, size);
+                }
+            }
+
+            self.verify(Side::Buy, entry.best_bid, self.best_bid(), Decimal::ZERO)?;
+            self.verify(Side::Sell, entry.best_ask, self.best_ask(), Decimal::ONE)?;
+
+            if entry.hash.is_some() {
+                self.hash.clone_from(&entry.hash);
+            }
+        }
+
+        if touched {
+            self.timestamp = change.timestamp;
+        }
+
+        Ok(touched)
+    }
+
+    /// The asset this book tracks.
+    #[must_use]
+    pub const fn asset_id(&self) -> U256 {
+        self.asset_id
+    }
+
+    /// The market condition ID of the asset.
+    #[must_use]
+    pub const fn market(&self) -> B256 {
+        self.market
+    }
+
+    /// Unix timestamp in milliseconds of the last applied update.
+    #[must_use]
+    pub const fn timestamp(&self) -> i64 {
+        self.timestamp
+    }
+
+    /// The server's hash for the last applied update, if one was sent.
+    ///
+    /// The server computes this over the full book state, including fields not carried on the
+    /// WebSocket feed, so it is only verified when the book is loaded with
+    /// [`from_summary`](Self::from_summary). Hashes from the feed are tracked for comparison
+    /// against the `hash` of [`order_book`] responses.
+    ///
+    /// [`order_book`]: crate::clob::Client::order_book
+    #[must_use]
+    pub fn hash(&self) -> Option<&str> {
+        self.hash.as_deref()
+    }
+
+    /// Bid levels, best (highest) price first.
+    pub fn bids(&self) -> impl Iterator<Item = OrderBookLevel> + '_ {
+        self.bids
+            .iter()
+            .rev()
+            .map(|(price, size)| level(*price, *size))
+    }
+
+    /// Ask levels, best (lowest) price first.
+    pub fn asks(&self) -> impl Iterator<Item = OrderBookLevel> + '_ {
+        self.asks.iter().map(|(price, size)| level(*price, *size))
+    }
+
+    /// The highest bid, if any.
+    #[must_use]
+    pub fn best_bid(&self) -> Option<OrderBookLevel> {
+        self.bids().next()
+    }
+
+    /// The lowest ask, if any.
+    #[must_use]
+    pub fn best_ask(&self) -> Option<OrderBookLevel> {
+        self.asks().next()
+    }
+
+    /// The midpoint between the best bid and ask, if both sides have liquidity.
+    #[must_use]
+    pub fn midpoint(&self) -> Option<Decimal> {
+        let bid = self.best_bid()?;
+        let ask = self.best_ask()?;
+        Some((bid.price + ask.price) / Decimal::TWO)
+    }
+
+    /// The difference between the best ask and bid, if both sides have liquidity.
+    #[must_use]
+    pub fn spread(&self) -> Option<Decimal> {
+        let bid = self.best_bid()?;
+        let ask = self.best_ask()?;
+        Some(ask.price - bid.price)
+    }
+
+    /// Number of price levels on the bid and ask side respectively.
+    #[must_use]
+    pub fn depth(&self) -> (usize, usize) {
+        (self.bids.len(), self.asks.len())
+    }
+
+    /// Returns `true` if neither side has any levels.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.bids.is_empty() && self.asks.is_empty()
+    }
+
+    /// Removes up to `size` of the liquidity an order on `side` limited to `price` would trade
+    /// against, best price first, and returns the amount taken from each level.
+    pub(crate) fn take(
+        &mut self,
+        side: Side,
+        price: Decimal,
+        size: Decimal,
+    ) -> Vec<OrderBookLevel> {
+        let mut taken = Vec::new();
+        let mut remaining = size;
+
+        while remaining > Decimal::ZERO {
+            let best = match side {
+                Side::Buy => self
+                    .asks
+                    .first_entry()
+                    .filter(|entry| *entry.key() <= price),
+                Side::Sell => self.bids.last_entry().filter(|entry| *entry.key() >= price),
+                Side::Unknown => None,
+            };
+            let Some(mut entry) = best else {
+                break;
+            };
+
+            let filled = remaining.min(*entry.get());
+            remaining -= filled;
+            taken.push(level(*entry.key(), filled));
+
+            if filled == *entry.get() {
+                entry.remove();
+            } else {
+                *entry.get_mut() -= filled;
+            }
+        }
+
+        taken
+    }
+
+    fn verify(
+        &self,
+        side: Side,
+        reported: Option<Decimal>,
+        local: Option<OrderBookLevel>,
+        empty: Decimal,
+    ) -> Result<()> {
+        let Some(reported) = reported else {
+            return Ok(());
+        };
+
+        match local {
+            // The server reports an empty side with a price outside of the valid range
+            None if reported == empty => Ok(()),
+            Some(level) if level.price == reported => Ok(()),
+            local => Err(OutOfSync {
+                asset_id: self.asset_id,
+                divergence: Divergence::BestPrice {
+                    side,
+                    expected: reported,
+                    actual: local.map(|level| level.price),
+                },
+            }
+            .into()),
+        }
+    }
+}
+
+impl From<&LocalOrderBook> for TopOfBook {
+    fn from(book: &LocalOrderBook) -> Self {
+        TopOfBook::builder()
+            .maybe_best_bid(book.best_bid().map(|level| level.price))
+            .maybe_best_ask(book.best_ask().map(|level| level.price))
+            .build()
+    }
+}
+
+/// Error indicating that a [`LocalOrderBook`] no longer matches the server.
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct OutOfSync {
+    /// The asset whose book diverged
+    pub asset_id: U256,
+    /// How the book diverged
+    pub divergence: Divergence,
+}
+
+/// The way in which a [`LocalOrderBook`] diverged from the server.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Divergence {
+    /// The best price on one side differs from the one the server reported with a price change.
+    BestPrice {
+        /// The side of the book that diverged
+        side: Side,
+        /// Best price on that side according to the server
+        expected: Decimal,
+        /// Best price on that side in the local book, or `None` if the side is empty
+        actual: Option<Decimal>,
+    },
+    /// An orderbook summary does not hash to the `hash` the server sent with it.
+    Hash {
+        /// Hash sent by the server
+        expected: String,
+        /// Hash computed from the summary
+        actual: String,
+    },
+}
+
+impl fmt::Display for OutOfSync {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "orderbook for {} is out of sync: ", self.asset_id)?;
+        match &self.divergence {
+            Divergence::BestPrice {
+                side,
+                expected,
+                actual,
+            } => {
+                let side = if *side == Side::Buy { "bid" } else { "ask" };
+                write!(f, "server best {side} is {expected}, local best {side} is ")?;
+                match actual {
+                    Some(price) => write!(f, "{price}"),
+                    None => write!(f, "empty"),
+                }
+            }
+            Divergence::Hash { expected, actual } => {
+                write!(f, "server hash is {expected}, computed hash is {actual}")
+            }
+        }
+    }
+}
+
+impl StdError for OutOfSync {}
+
+impl From<OutOfSync> for Error {
+    fn from(err: OutOfSync) -> Self {
+        Error::with_source(Kind::WebSocket, err)
+    }
+}
+
+/// Item yielded by a locally maintained orderbook subscription that resynchronizes on gaps.
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub enum BookEvent {
+    /// Books for these assets may have missed updates and must not be relied on until a fresh
+    /// [`BookEvent::Updated`] arrives for them.
+    Stale {
+        /// Assets whose books are stale
+        asset_ids: Vec<U256>,
+        /// Why the books are stale
+        reason: StaleReason,
+    },
+    /// The latest state of a book.
+    Updated(LocalOrderBook),
+}
+
+/// Keeps a [`LocalOrderBook`] per asset and publishes every change on a [`watch`] channel.
+///
+/// Feed it every message from a market subscription with [`apply`](Self::apply). Price changes
+/// for an asset are ignored until its first snapshot arrives, and a book that falls out of sync is
+/// discarded until the next snapshot.
+#[derive(Debug, Default)]
+pub struct OrderBookManager {
+    books: DashMap<U256, watch::Sender<Option<LocalOrderBook>>>,
+}
+
+impl OrderBookManager {
+    /// Creates an empty manager.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Applies a market channel message to the books it concerns.
+    ///
+    /// Returns the updated book for every asset the message touched, or an error for each book
+    /// that fell out of sync and was discarded. Messages other than snapshots and price changes
+    /// are ignored.
+    #[must_use]
+    pub fn apply(&self, message: &WsMessage) -> Vec<Result<LocalOrderBook>> {
+        match message {
+            WsMessage::Book(snapshot) => vec![Ok(self.replace(snapshot))],
+            WsMessage::PriceChange(change) => self.apply_price_change(change),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Replaces the book for the snapshot's asset.
+    pub fn apply_snapshot(&self, snapshot: &BookUpdate) {
+        self.replace(snapshot);
+    }
+
+    /// Replaces the book for the summary's asset with one loaded over REST.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`OutOfSync`] error if the summary fails the hash check of
+    /// [`LocalOrderBook::from_summary`], in which case the current book is discarded.
+    pub fn apply_summary(&self, summary: &OrderBookSummaryResponse) -> Result<LocalOrderBook> {
+        let sender = self.sender(summary.asset_id);
+        match LocalOrderBook::from_summary(summary) {
+            Ok(book) => {
+                sender.send_replace(Some(book.clone()));
+                Ok(book)
+            }
+            Err(e) => {
+                sender.send_if_modified(|current| current.take().is_some());
+                Err(e)
+            }
+        }
+    }
+
+    /// Applies a price change to every book it touches.
+    ///
+    /// See [`apply`](Self::apply) for the returned values.
+    #[must_use]
+    pub fn apply_price_change(&self, change: &PriceChange) -> Vec<Result<LocalOrderBook>> {
+        let mut asset_ids: Vec<U256> = change
+            .price_changes
+            .iter()
+            .map(|entry| entry.asset_id)
+            .collect();
+        asset_ids.sort_unstable();
+        asset_ids.dedup();
+
+        asset_ids
+            .into_iter()
+            .filter_map(|asset_id| {
+                let sender = self.books.get(&asset_id)?;
+                let mut outcome = None;
+
+                sender.send_if_modified(|current| {
+                    let Some(book) = current else {
+                        return false;
+                    };
+
+                    match book.apply_price_change(change) {
+                        Ok(touched) => {
+                            if touched {
+                                outcome = Some(Ok(book.clone()));
+                            }
+                            touched
+                        }
+                        Err(e) => {
+                            *current = None;
+                            outcome = Some(Err(e));
+                            true
+                        }
+                    }
+                });
+
+                outcome
+            })
+            .collect()
+    }
+
+    /// Returns a copy of the current book for `asset_id`, if a snapshot has been received.
+    #[must_use]
+    pub fn book(&self, asset_id: U256) -> Option<LocalOrderBook> {
+        self.books.get(&asset_id)?.borrow().clone()
+    }
+
+    /// Returns a receiver that observes every change to the book for `asset_id`.
+    ///
+    /// The value is `None` until the first snapshot arrives and after the book is discarded for
+    /// falling out of sync.
+    #[must_use]
+    pub fn watch(&self, asset_id: U256) -> watch::Receiver<Option<LocalOrderBook>> {
+        self.sender(asset_id).subscribe()
+    }
+
+    /// Discards the book for `asset_id` until its next snapshot, without closing its channel.
+    pub fn invalidate(&self, asset_id: U256) {
+        if let Some(sender) = self.books.get(&asset_id) {
+            sender.send_if_modified(|current| current.take().is_some());
+        }
+    }
+
+    /// Stops tracking `asset_id`. Existing receivers observe the channel closing.
+    pub fn remove(&self, asset_id: U256) {
+        self.books.remove(&asset_id);
+    }
+
+    fn replace(&self, snapshot: &BookUpdate) -> LocalOrderBook {
+        let book = LocalOrderBook::from_snapshot(snapshot);
+        self.sender(snapshot.asset_id)
+            .send_replace(Some(book.clone()));
+        book
+    }
+
+    fn sender(&self, asset_id: U256) -> Ref<'_, U256, watch::Sender<Option<LocalOrderBook>>> {
+        self.books
+            .entry(asset_id)
+            .or_insert_with(|| watch::Sender::new(None))
+            .downgrade()
+    }
+}
+
+fn levels(levels: &[OrderBookLevel]) -> BTreeMap<Decimal, Decimal> {
+    levels
+        .iter()
+        .filter(|level| !level.size.is_zero())
+        .map(|level| (level.price, level.size))
+        .collect()
+}
+
+fn level(price: Decimal, size: Decimal) -> OrderBookLevel {
+    OrderBookLevel::builder().price(price).size(size).build()
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::clob::ws::types::response::PriceChangeBatchEntry;
+    use crate::types::b256;
+
+    const MARKET: B256 = b256!("0000000000000000000000000000000000000000000000000000000000000001");
+
+    fn snapshot() -> BookUpdate {
+        BookUpdate::builder()
+            .asset_id(U256::from(1))
+            .market(MARKET)
+            .timestamp(1)
+            .bids(vec![
+                level(dec!(0.48), dec!(30)),
+                level(dec!(0.49), dec!(20)),
+            ])
+            .asks(vec![
+                level(dec!(0.52), dec!(25)),
+                level(dec!(0.51), dec!(10)),
+            ])
+            .hash("snapshot".to_owned())
+            .build()
+    }
+
+    fn change(entries: Vec<PriceChangeBatchEntry>) -> PriceChange {
+        PriceChange::builder()
+            .market(MARKET)
+            .timestamp(2)
+            .price_changes(entries)
+            .build()
+    }
+
+    fn entry(side: Side, price: Decimal, size: Decimal) -> PriceChangeBatchEntry {
+        PriceChangeBatchEntry::builder()
+            .asset_id(U256::from(1))
+            .price(price)
+            .size(size)
+            .side(side)
+            .build()
+    }
+
+    #[test]
+    fn snapshot_should_sort_levels() {
+        let book = LocalOrderBook::from_snapshot(&snapshot());
+
+        let bids: Vec<_> = book.bids().map(|level| level.price).collect();
+        let asks: Vec<_> = book.asks().map(|level| level.price).collect();
+
+        assert_eq!(bids, vec![dec!(0.49), dec!(0.48)]);
+        assert_eq!(asks, vec![dec!(0.51), dec!(0.52)]);
+        assert_eq!(book.midpoint(), Some(dec!(0.50)));
+        assert_eq!(book.spread(), Some(dec!(0.02)));
+        assert_eq!(book.depth(), (2, 2));
+        assert_eq!(book.hash(), Some("snapshot"));
+    }
+
+    #[test]
+    fn price_change_should_insert_update_and_remove_levels() {
+        let mut book = LocalOrderBook::from_snapshot(&snapshot());
+
+        let touched = book
+            .apply_price_change(&change(vec![
+                entry(Side::Buy, dec!(0.50), dec!(5)),
+                entry(Side::Sell, dec!(0.51), dec!(0)),
+                entry(Side::Sell, dec!(0.52), dec!(40)),
+            ]))
+            .unwrap();
+
+        assert!(touched, "entries for the book's asset should be applied");
+        assert_eq!(book.best_bid().unwrap().price, dec!(0.50));
+        assert_eq!(book.best_ask().unwrap().price, dec!(0.52));
+        assert_eq!(book.best_ask().unwrap().size, dec!(40));
+        assert_eq!(book.depth(), (3, 1));
+        assert_eq!(book.timestamp(), 2);
+    }
+
+    #[test]
+    fn price_change_for_other_asset_should_be_ignored() {
+        let mut book = LocalOrderBook::from_snapshot(&snapshot());
+        let mut other = entry(Side::Buy, dec!(0.50), dec!(5));
+        other.asset_id = U256::from(2);
+
+        let touched = book.apply_price_change(&change(vec![other])).unwrap();
+
+        assert!(!touched, "entries for other assets should not be applied");
+        assert_eq!(book, LocalOrderBook::from_snapshot(&snapshot()));
+    }
+
+    #[test]
+    fn price_change_should_fail_when_top_of_book_diverges() {
+        let mut book = LocalOrderBook::from_snapshot(&snapshot());
+        let mut diverged = entry(Side::Buy, dec!(0.47), dec!(5));
+        diverged.best_bid = Some(dec!(0.50));
+
+        let err = book
+            .apply_price_change(&change(vec![diverged]))
+            .unwrap_err();
+
+        assert!(
+            err.to_string().contains("out of sync"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn manager_should_ignore_deltas_before_snapshot_and_drop_desynced_books() {
+        let manager = OrderBookManager::new();
+        let mut rx = manager.watch(U256::from(1));
+
+        let updates = manager.apply(&WsMessage::PriceChange(change(vec![entry(
+            Side::Buy,
+            dec!(0.50),
+            dec!(5),
+        )])));
+        assert!(updates.is_empty(), "deltas before a snapshot are ignored");
+        assert!(rx.borrow().is_none(), "no book before snapshot");
+
+        manager.apply_snapshot(&snapshot());
+        assert!(rx.has_changed().unwrap(), "snapshot should notify watchers");
+        assert_eq!(
+            rx.borrow_and_update()
+                .as_ref()
+                .unwrap()
+                .best_bid()
+                .unwrap()
+                .price,
+            dec!(0.49)
+        );
+
+        let mut diverged = entry(Side::Sell, dec!(0.60), dec!(5));
+        diverged.best_ask = Some(dec!(0.55));
+        let updates = manager.apply(&WsMessage::PriceChange(change(vec![diverged])));
+
+        assert!(updates[0].is_err(), "desynced book should be reported");
+        assert!(
+            manager.book(U256::from(1)).is_none(),
+            "desynced book is dropped"
+        );
+        assert!(
+            rx.has_changed().unwrap(),
+            "dropping a book should notify watchers"
+        );
+    }
+}
@@ -1,13 +1,14 @@
 use std::sync::Arc;
 
-use async_stream::try_stream;
+use async_stream::{stream, try_stream};
 use dashmap::mapref::one::{Ref, RefMut};
 use dashmap::{DashMap, Entry};
 use futures::Stream;
 use futures::StreamExt as _;
 
+use super::book::{BookEvent, LocalOrderBook, OrderBookManager, OutOfSync};
 use super::interest::InterestTracker;
-use super::subscription::{ChannelType, SubscriptionManager};
+use super::subscription::{ChannelType, MarketEvent, StaleReason, SubscriptionManager, UserEvent};
 use super::types::response::{
     BestBidAsk, BookUpdate, LastTradePrice, MarketResolved, MidpointUpdate, NewMarket,
     OrderMessage, PriceChange, TickSizeChange, TradeMessage, WsMessage,
@@ -15,6 +16,9 @@ use super::types::response::{
 use crate::Result;
 use crate::auth::state::{Authenticated, State, Unauthenticated};
 use crate::auth::{Credentials, Kind as AuthKind, Normal};
+use crate::clob::Client as RestClient;
+use crate::clob::types::TickSize;
+use crate::clob::types::request::OrderBookSummaryRequest;
 use crate::error::Error;
 use crate::types::{Address, B256, Decimal, U256};
 use crate::ws::ConnectionManager;
@@ -292,6 +296,212 @@ impl<S: State> Client<S> {
         })
     }
 
+    /// Subscribes to locally maintained orderbooks for specified assets.
+    ///
+    /// Merges the snapshots from [`subscribe_orderbook`](Self::subscribe_orderbook) with the
+    /// level updates from [`subscribe_prices`](Self::subscribe_prices) and yields the full book
+    /// for an asset every time it changes. If a book falls out of sync with the server, an error
+    /// is yielded and the asset is skipped until its next snapshot.
+    ///
+    /// Use an [`OrderBookManager`] directly to share books between tasks through `watch`
+    /// channels.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset_ids` - List of asset/token IDs to monitor
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the subscription cannot be created or the WebSocket
+    /// connection is not established.
+    pub fn subscribe_local_orderbook(
+        &self,
+        asset_ids: Vec<U256>,
+    ) -> Result<impl Stream<Item = Result<LocalOrderBook>> + use<S>> {
+        let resources = self.inner.get_or_create_channel(ChannelType::Market)?;
+        let stream = resources.subscriptions.subscribe_market(asset_ids)?;
+        let manager = OrderBookManager::new();
+
+        Ok(stream.flat_map(move |msg_result| {
+            let updates = match msg_result {
+                Ok(msg) => manager.apply(&msg),
+                Err(e) => vec![Err(e)],
+            };
+            futures::stream::iter(updates)
+        }))
+    }
+
+    /// Subscribes to locally maintained orderbooks that resynchronize over REST after a gap.
+    ///
+    /// Behaves like [`subscribe_local_orderbook`](Self::subscribe_local_orderbook), but when
+    /// messages may have been missed (the stream lagged behind the connection, the connection was
+    /// re-established, or a book fell out of sync with the server) it yields
+    /// [`BookEvent::Stale`] for the affected assets, fetches fresh snapshots with
+    /// [`order_books`](crate::clob::Client::order_books), yields them as
+    /// [`BookEvent::Updated`], and then resumes applying level updates.
+    ///
+    /// Each fetched snapshot is checked against its `hash` as in
+    /// [`LocalOrderBook::from_summary`]. If the snapshot request fails, or a snapshot fails that
+    /// check, the error is yielded and the affected books stay discarded until the server sends
+    /// its next snapshot.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset_ids` - List of asset/token IDs to monitor
+    /// * `rest` - CLOB client used to fetch snapshots
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the subscription cannot be created or the WebSocket
+    /// connection is not established.
+    pub fn subscribe_synced_orderbook<R: State>(
+        &self,
+        asset_ids: Vec<U256>,
+        rest: RestClient<R>,
+    ) -> Result<impl Stream<Item = Result<BookEvent>> + use<S, R>> {
+        let resources = self.inner.get_or_create_channel(ChannelType::Market)?;
+        let events = resources
+            .subscriptions
+            .subscribe_market_events(asset_ids.clone(), false)?;
+        let manager = OrderBookManager::new();
+
+        Ok(stream! {
+            for await event in events {
+                let (stale, reason) = match event {
+                    Ok(MarketEvent::Message(msg)) => {
+                        let mut out_of_sync = Vec::new();
+                        for update in manager.apply(&msg) {
+                            match update {
+                                Ok(book) => yield Ok(BookEvent::Updated(book)),
+                                Err(e) => match e.downcast_ref::<OutOfSync>() {
+                                    Some(err) => out_of_sync.push(err.asset_id),
+                                    None => yield Err(e),
+                                },
+                            }
+                        }
+
+                        if out_of_sync.is_empty() {
+                            continue;
+                        }
+                        (out_of_sync, StaleReason::OutOfSync)
+                    }
+                    Ok(MarketEvent::Stale(reason)) => (asset_ids.clone(), reason),
+                    Err(e) => {
+                        yield Err(e);
+                        continue;
+                    }
+                };
+
+                for asset_id in &stale {
+                    manager.invalidate(*asset_id);
+                }
+                yield Ok(BookEvent::Stale {
+                    asset_ids: stale.clone(),
+                    reason,
+                });
+
+                let requests: Vec<_> = stale
+                    .into_iter()
+                    .map(|token_id| OrderBookSummaryRequest::builder().token_id(token_id).build())
+                    .collect();
+                match rest.order_books(&requests).await {
+                    Ok(summaries) => {
+                        for summary in summaries {
+                            yield manager.apply_summary(&summary).map(BookEvent::Updated);
+                        }
+                    }
+                    Err(e) => yield Err(e),
+                }
+            }
+        })
+    }
+
+    /// Keeps the tick size cache of a CLOB client in sync with tick size changes.
+    ///
+    /// Every [`TickSizeChange`] for the given assets is written to `rest`'s cache with
+    /// [`set_tick_size`](crate::clob::Client::set_tick_size) before it is yielded, so orders
+    /// built by `rest` or any of its clones are validated against the new tick size, and the
+    /// yielded changes let strategies react, such as by requoting. The cache is only updated
+    /// while the stream is polled.
+    ///
+    /// When changes may have been missed because the stream lagged or the connection was
+    /// re-established, the cached tick sizes of the assets are dropped so that the next order
+    /// fetches them again. A change to an unknown tick size is yielded as an error and leaves the
+    /// cache untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset_ids` - List of asset/token IDs to monitor
+    /// * `rest` - CLOB client whose cache is kept in sync
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the subscription cannot be created or the WebSocket
+    /// connection is not established.
+    pub fn subscribe_synced_tick_sizes<R: State>(
+        &self,
+        asset_ids: Vec<U256>,
+        rest: RestClient<R>,
+    ) -> Result<impl Stream<Item = Result<TickSizeChange>> + use<S, R>> {
+        let resources = self.inner.get_or_create_channel(ChannelType::Market)?;
+        let events = resources
+            .subscriptions
+            .subscribe_market_events(asset_ids.clone(), false)?;
+
+        Ok(stream! {
+            for await event in events {
+                match event {
+                    Ok(MarketEvent::Message(WsMessage::TickSizeChange(change))) => {
+                        match TickSize::try_from(change.new_tick_size) {
+                            Ok(tick_size) => {
+                                rest.set_tick_size(change.asset_id, tick_size);
+                                yield Ok(change);
+                            }
+                            Err(e) => yield Err(e),
+                        }
+                    }
+                    Ok(MarketEvent::Message(_)) => {}
+                    Ok(MarketEvent::Stale(reason)) => {
+                        #[cfg(not(feature = "tracing"))]
+                        let _ = reason;
+                        #[cfg(feature = "tracing")]
+                        tracing::debug!(?reason, "Tick sizes may be stale, dropping cached values");
+
+                        for asset_id in &asset_ids {
+                            rest.invalidate_tick_size(*asset_id);
+                        }
+                    }
+                    Err(e) => yield Err(e),
+                }
+            }
+        })
+    }
+
+    /// Subscribes to raw market channel messages along with notifications of gaps in the feed.
+    ///
+    /// Yields every market message for the given assets as [`MarketEvent::Message`], and
+    /// [`MarketEvent::Stale`] whenever messages may have been missed because the stream lagged
+    /// behind the connection or the connection was re-established. Any state built from earlier
+    /// messages should be refreshed when a stale event arrives.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset_ids` - List of asset/token IDs to monitor
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the subscription cannot be created or the WebSocket
+    /// connection is not established.
+    pub fn subscribe_market_events(
+        &self,
+        asset_ids: Vec<U256>,
+    ) -> Result<impl Stream<Item = Result<MarketEvent>> + use<S>> {
+        let resources = self.inner.get_or_create_channel(ChannelType::Market)?;
+        resources
+            .subscriptions
+            .subscribe_market_events(asset_ids, false)
+    }
+
     /// Subscribe to best bid/ask updates with custom features enabled.
     ///
     /// Requires `custom_features_enabled` flag on the server side.
@@ -455,6 +665,33 @@ impl<K: AuthKind> Client<Authenticated<K>> {
             .subscribe_user(markets, &self.inner.state.credentials)
     }
 
+    /// Subscribes to user channel messages along with notifications of gaps in the feed.
+    ///
+    /// Yields every user message for the given markets as [`UserEvent::Message`], and
+    /// [`UserEvent::Stale`] whenever order or trade updates may have been missed because the
+    /// stream lagged behind the connection or the connection was re-established. Order state
+    /// built from earlier messages should be refreshed over REST when a stale event arrives, as
+    /// [`OrderManager::run`](super::OrderManager::run) does.
+    ///
+    /// # Arguments
+    ///
+    /// * `markets` - List of market condition IDs to monitor
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the subscription cannot be created, the WebSocket
+    /// connection is not established, or authentication fails.
+    pub fn subscribe_user_channel(
+        &self,
+        markets: Vec<B256>,
+    ) -> Result<impl Stream<Item = Result<UserEvent>> + use<K>> {
+        let resources = self.inner.get_or_create_channel(ChannelType::User)?;
+
+        resources
+            .subscriptions
+            .subscribe_user_events(markets, &self.inner.state.credentials)
+    }
+
     /// Subscribes to real-time order status updates for the authenticated user.
     ///
     /// Returns a stream of order events including order placement, fills, partial fills,
@@ -3,14 +3,22 @@
     reason = "Re-exported names intentionally match their modules for API clarity"
 )]
 
+pub mod book;
 pub mod client;
 pub mod interest;
+pub mod orders;
+pub mod paper;
 pub mod subscription;
 pub mod types;
 
 // Re-export commonly used types
+pub use book::{BookEvent, Divergence, LocalOrderBook, OrderBookManager, OutOfSync};
 pub use client::Client;
-pub use subscription::{ChannelType, SubscriptionInfo, SubscriptionTarget};
+pub use orders::{OrderEvent, OrderManager, TrackedOrder};
+pub use paper::PaperExchange;
+pub use subscription::{
+    ChannelType, MarketEvent, StaleReason, SubscriptionInfo, SubscriptionTarget, UserEvent,
+};
 pub use types::request::SubscriptionRequest;
 pub use types::response::{
     BestBidAsk, BookUpdate, EventMessage, LastTradePrice, MakerOrder, MarketResolved,
@@ -0,0 +1,533 @@
+//! Lifecycle tracking for the authenticated user's orders.
+//!
+//! [`OrderManager`] posts and cancels orders through the CLOB REST API, then follows them through
+//! the user channel's [`OrderMessage`]s and [`TradeMessage`]s, keeping one [`TrackedOrder`] per
+//! order ID. Whenever the user channel may have dropped updates, it falls back to polling the
+//! open orders over REST, so the tracked orders remain authoritative across disconnects.
+
+use std::collections::{HashMap, HashSet};
+use std::pin::pin;
+use std::sync::Arc;
+
+use async_stream::stream;
+use dashmap::{DashMap, Entry};
+use futures::{Stream, StreamExt as _, TryStreamExt as _, stream};
+use tokio::sync::broadcast;
+use tokio::sync::broadcast::error::RecvError;
+
+use super::client::Client as WsClient;
+use super::subscription::UserEvent;
+use super::types::response::{
+    OrderMessage, OrderMessageType, TradeMessage, TradeMessageStatus, WsMessage,
+};
+use crate::Result;
+use crate::auth::state::Authenticated;
+use crate::auth::{Kind as AuthKind, Normal};
+use crate::clob::Client as RestClient;
+use crate::clob::types::request::OrdersRequest;
+use crate::clob::types::response::{CancelOrdersResponse, OpenOrderResponse, PostOrderResponse};
+use crate::clob::types::{OrderStatusType, Side, SignedOrder};
+use crate::types::{B256, Decimal, U256};
+
+/// Number of lifecycle events buffered for each [`OrderManager::events`] subscriber.
+const EVENT_CAPACITY: usize = 1024;
+
+/// The maximum number of closed orders [`OrderManager::reconcile`] fetches at once
+const RECONCILE_CONCURRENCY: usize = 16;
+
+/// Latest known state of one of the user's orders.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackedOrder {
+    /// Order ID, as returned by the CLOB.
+    pub id: String,
+    /// Market condition ID, once reported by the CLOB.
+    pub market: Option<B256>,
+    /// Token ID of the outcome the order trades.
+    pub asset_id: U256,
+    /// Whether the order buys or sells `asset_id`.
+    pub side: Side,
+    /// Limit price of the order.
+    pub price: Decimal,
+    /// Size of the order, in shares.
+    pub original_size: Decimal,
+    /// Size matched so far, in shares.
+    pub size_matched: Decimal,
+    /// Latest status reported over REST or the user channel.
+    pub status: OrderStatusType,
+    /// Latest status of every trade this order took part in, keyed by trade ID.
+    pub trades: HashMap<String, TradeMessageStatus>,
+}
+
+impl TrackedOrder {
+    /// Size that has not been matched yet, in shares.
+    #[must_use]
+    pub fn remaining_size(&self) -> Decimal {
+        (self.original_size - self.size_matched).max(Decimal::ZERO)
+    }
+
+    /// Returns `true` while the order can still be matched.
+    #[must_use]
+    pub fn is_open(&self) -> bool {
+        matches!(
+            self.status,
+            OrderStatusType::Live | OrderStatusType::Delayed
+        )
+    }
+
+    /// Creates the order described by `order` before it is posted, with a placeholder ID and
+    /// status until the CLOB responds.
+    fn unposted(order: &SignedOrder) -> Result<Self> {
+        let (side, price, size) = order.order.terms()?;
+
+        Ok(Self {
+            id: String::new(),
+            market: None,
+            asset_id: order.order.tokenId,
+            side,
+            price,
+            original_size: size,
+            size_matched: Decimal::ZERO,
+            status: OrderStatusType::Live,
+            trades: HashMap::new(),
+        })
+    }
+
+    /// Completes an [`unposted`](Self::unposted) order with the CLOB's `response`.
+    fn posted(self, response: &PostOrderResponse) -> Self {
+        let size_matched = match self.side {
+            Side::Buy => response.taking_amount,
+            _ => response.making_amount,
+        };
+
+        Self {
+            id: response.order_id.clone(),
+            size_matched,
+            status: response.status.clone(),
+            ..self
+        }
+    }
+
+    /// Creates the order described by `message`, filling the fields it leaves out from
+    /// `current`.
+    fn from_message(message: &OrderMessage, current: Option<&TrackedOrder>) -> Self {
+        let original_size = message
+            .original_size
+            .or(current.map(|order| order.original_size))
+            .unwrap_or_default();
+        let size_matched = message
+            .size_matched
+            .or(current.map(|order| order.size_matched))
+            .unwrap_or_default();
+
+        let status = match (&message.status, &message.msg_type) {
+            (Some(status), _) => status.clone(),
+            (None, Some(OrderMessageType::Cancellation)) => OrderStatusType::Canceled,
+            _ if !original_size.is_zero() && size_matched >= original_size => {
+                OrderStatusType::Matched
+            }
+            _ => current.map_or(OrderStatusType::Live, |order| order.status.clone()),
+        };
+
+        Self {
+            id: message.id.clone(),
+            market: Some(message.market),
+            asset_id: message.asset_id,
+            side: message.side,
+            price: message.price,
+            original_size,
+            size_matched,
+            status,
+            trades: HashMap::new(),
+        }
+    }
+}
+
+impl From<&OpenOrderResponse> for TrackedOrder {
+    fn from(order: &OpenOrderResponse) -> Self {
+        Self {
+            id: order.id.clone(),
+            market: Some(order.market),
+            asset_id: order.asset_id,
+            side: order.side,
+            price: order.price,
+            original_size: order.original_size,
+            size_matched: order.size_matched,
+            status: order.status.clone(),
+            trades: HashMap::new(),
+        }
+    }
+}
+
+/// Change to one of the user's orders, published by [`OrderManager::events`].
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+#[expect(
+    clippy::large_enum_variant,
+    reason = "Trade events carry the full message, as received from the user channel"
+)]
+pub enum OrderEvent {
+    /// An order was seen for the first time, either when posting it or from the CLOB.
+    Placed(TrackedOrder),
+    /// An order was matched further or otherwise changed status.
+    Updated(TrackedOrder),
+    /// An order was cancelled.
+    Canceled(TrackedOrder),
+    /// A trade involving tracked orders was matched, or its settlement progressed.
+    Trade {
+        /// IDs of the tracked orders that took part in the trade.
+        order_ids: Vec<String>,
+        trade: TradeMessage,
+    },
+}
+
+/// Tracks the lifecycle of the user's orders across REST placement and user channel updates.
+///
+/// Orders posted through the manager, reported on the user channel, or found open over REST are
+/// all tracked, and every change is published to [`events`](Self::events) subscribers.
+/// [`run`](Self::run) keeps the orders in sync with the user channel. Clones share the same
+/// tracked orders.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # async fn example(
+/// #     rest: polymarket_client_sdk::clob::Client<
+/// #         polymarket_client_sdk::auth::state::Authenticated<polymarket_client_sdk::auth::Normal>,
+/// #     >,
+/// #     ws: polymarket_client_sdk::clob::ws::Client<
+/// #         polymarket_client_sdk::auth::state::Authenticated<polymarket_client_sdk::auth::Normal>,
+/// #     >,
+/// #     markets: Vec<polymarket_client_sdk::types::B256>,
+/// # ) -> anyhow::Result<()> {
+/// use futures::StreamExt as _;
+/// use polymarket_client_sdk::clob::ws::{OrderEvent, OrderManager};
+///
+/// let manager = OrderManager::new(rest);
+/// let mut events = Box::pin(manager.events());
+///
+/// let tracker = manager.clone();
+/// tokio::spawn(async move { tracker.run(&ws, markets).await });
+///
+/// while let Some(event) = events.next().await {
+///     if let OrderEvent::Updated(order) = event {
+///         println!("{} has {} left", order.id, order.remaining_size());
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct OrderManager<K: AuthKind = Normal> {
+    rest: RestClient<Authenticated<K>>,
+    orders: Arc<DashMap<String, TrackedOrder>>,
+    events: broadcast::Sender<OrderEvent>,
+}
+
+impl<K: AuthKind> OrderManager<K> {
+    /// Creates a manager that places and reconciles orders through `rest`.
+    #[must_use]
+    pub fn new(rest: RestClient<Authenticated<K>>) -> Self {
+        Self {
+            rest,
+            orders: Arc::new(DashMap::new()),
+            events: broadcast::channel(EVENT_CAPACITY).0,
+        }
+    }
+
+    /// Subscribes to every change to the tracked orders from now on.
+    ///
+    /// A subscriber that falls more than 1024 events behind skips the events it missed; the
+    /// tracked orders themselves are always up to date.
+    pub fn events(&self) -> impl Stream<Item = OrderEvent> + use<K> {
+        let mut rx = self.events.subscribe();
+
+        stream! {
+            loop {
+                match rx.recv().await {
+                    Ok(event) => yield event,
+                    Err(RecvError::Lagged(n)) => {
+                        #[cfg(not(feature = "tracing"))]
+                        let _ = n;
+                        #[cfg(feature = "tracing")]
+                        tracing::warn!("Order event subscriber lagged, missed {n} events");
+                    }
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        }
+    }
+
+    /// Returns the latest state of the order with `order_id`, if it is tracked.
+    #[must_use]
+    pub fn order(&self, order_id: &str) -> Option<TrackedOrder> {
+        self.orders.get(order_id).map(|order| order.clone())
+    }
+
+    /// Returns every tracked order that can still be matched.
+    #[must_use]
+    pub fn open_orders(&self) -> Vec<TrackedOrder> {
+        self.orders
+            .iter()
+            .filter(|order| order.is_open())
+            .map(|order| order.clone())
+            .collect()
+    }
+
+    /// Stops tracking the order with `order_id`, returning its last known state.
+    ///
+    /// Closed orders are kept so that the settlement of their trades can still be followed, so
+    /// long-running processes should remove them once they are no longer of interest.
+    #[must_use]
+    pub fn remove(&self, order_id: &str) -> Option<TrackedOrder> {
+        self.orders.remove(order_id).map(|(_, order)| order)
+    }
+
+    /// Posts `order` and tracks it if the CLOB accepts it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order cannot be posted.
+    pub async fn post_order(&self, order: SignedOrder) -> Result<PostOrderResponse> {
+        let unposted = TrackedOrder::unposted(&order)?;
+        let response = self.rest.post_order(order).await?;
+        self.track_posted(unposted, &response);
+
+        Ok(response)
+    }
+
+    /// Posts `orders` in a single request and tracks each one the CLOB accepts.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the orders cannot be posted.
+    pub async fn post_orders(&self, orders: Vec<SignedOrder>) -> Result<Vec<PostOrderResponse>> {
+        let unposted = orders
+            .iter()
+            .map(TrackedOrder::unposted)
+            .collect::<Result<Vec<_>>>()?;
+        let responses = self.rest.post_orders(orders).await?;
+        for (order, response) in unposted.into_iter().zip(&responses) {
+            self.track_posted(order, response);
+        }
+
+        Ok(responses)
+    }
+
+    /// Cancels the order with `order_id`, marking it as cancelled once the CLOB confirms.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cancellation request fails.
+    pub async fn cancel_order(&self, order_id: &str) -> Result<CancelOrdersResponse> {
+        let response = self.rest.cancel_order(order_id).await?;
+        self.track_canceled(&response);
+
+        Ok(response)
+    }
+
+    /// Cancels the orders with `order_ids`, marking each one the CLOB confirms as cancelled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cancellation request fails.
+    pub async fn cancel_orders(&self, order_ids: &[&str]) -> Result<CancelOrdersResponse> {
+        let response = self.rest.cancel_orders(order_ids).await?;
+        self.track_canceled(&response);
+
+        Ok(response)
+    }
+
+    /// Cancels all of the user's open orders, marking each one the CLOB confirms as cancelled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cancellation request fails.
+    pub async fn cancel_all_orders(&self) -> Result<CancelOrdersResponse> {
+        let response = self.rest.cancel_all_orders().await?;
+        self.track_canceled(&response);
+
+        Ok(response)
+    }
+
+    /// Applies a user channel message to the tracked orders. Other messages are ignored.
+    pub fn apply(&self, message: &WsMessage) {
+        match message {
+            WsMessage::Order(order) => {
+                self.update(&order.id, |current| {
+                    Some(TrackedOrder::from_message(order, current))
+                });
+            }
+            WsMessage::Trade(trade) => self.apply_trade(trade),
+            _ => {}
+        }
+    }
+
+    /// Refreshes the tracked orders over REST.
+    ///
+    /// Every open order is fetched with [`orders`](RestClient::orders), and each tracked order
+    /// that is no longer open is fetched with [`order`](RestClient::order) to learn how it was
+    /// closed, several at a time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the requests fail. Orders refreshed before the failure keep
+    /// their new state.
+    pub async fn reconcile(&self) -> Result<()> {
+        let request = OrdersRequest::default();
+        let open: Vec<OpenOrderResponse> = self
+            .rest
+            .stream_data(|client, cursor| client.orders(&request, cursor))
+            .try_collect()
+            .await?;
+
+        let open_ids: HashSet<&str> = open.iter().map(|order| order.id.as_str()).collect();
+        let closed: Vec<String> = self
+            .orders
+            .iter()
+            .filter(|order| order.is_open() && !open_ids.contains(order.id.as_str()))
+            .map(|order| order.id.clone())
+            .collect();
+
+        for order in &open {
+            self.update(&order.id, |_| Some(order.into()));
+        }
+
+        stream::iter(closed.into_iter().map(Ok))
+            .try_for_each_concurrent(RECONCILE_CONCURRENCY, |order_id| async move {
+                let order = self.rest.order(&order_id).await?;
+                self.update(&order_id, |_| Some((&order).into()));
+                Ok(())
+            })
+            .await
+    }
+
+    /// Keeps the tracked orders in sync with the user channel for `markets` until the
+    /// subscription ends.
+    ///
+    /// The orders are first reconciled over REST, since they may have changed before the
+    /// subscription started, and again whenever the user channel reports that updates may have
+    /// been missed. An empty `markets` follows every market.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the subscription cannot be created or a reconciliation fails. The
+    /// tracked orders are kept, so calling `run` again resumes where it stopped.
+    pub async fn run(&self, ws: &WsClient<Authenticated<K>>, markets: Vec<B256>) -> Result<()> {
+        let mut events = pin!(ws.subscribe_user_channel(markets)?);
+
+        self.reconcile().await?;
+
+        while let Some(event) = events.next().await {
+            match event? {
+                UserEvent::Message(message) => self.apply(&message),
+                UserEvent::Stale(reason) => {
+                    #[cfg(not(feature = "tracing"))]
+                    let _ = reason;
+                    #[cfg(feature = "tracing")]
+                    tracing::debug!(?reason, "User channel is stale, reconciling orders");
+
+                    self.reconcile().await?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn track_posted(&self, order: TrackedOrder, response: &PostOrderResponse) {
+        if !response.success || response.order_id.is_empty() {
+            return;
+        }
+
+        self.update(&response.order_id, |_| Some(order.posted(response)));
+    }
+
+    fn track_canceled(&self, response: &CancelOrdersResponse) {
+        for order_id in &response.canceled {
+            self.update(order_id, |current| {
+                current.map(|order| TrackedOrder {
+                    status: OrderStatusType::Canceled,
+                    ..order.clone()
+                })
+            });
+        }
+    }
+
+    fn apply_trade(&self, trade: &TradeMessage) {
+        let order_ids: Vec<String> = trade
+            .taker_order_id
+            .iter()
+            .chain(trade.maker_orders.iter().map(|maker| &maker.order_id))
+            .filter(|order_id| {
+                self.orders.get_mut(*order_id).is_some_and(|mut order| {
+                    order.trades.insert(trade.id.clone(), trade.status.clone());
+                    true
+                })
+            })
+            .cloned()
+            .collect();
+
+        if !order_ids.is_empty() {
+            // Nobody listening is fine, the tracked orders remain the source of truth
+            _ = self.events.send(OrderEvent::Trade {
+                order_ids,
+                trade: trade.clone(),
+            });
+        }
+    }
+
+    /// Replaces the order with `order_id` by the result of `next`, which receives the current
+    /// state, if any, and publishes the change.
+    fn update<F>(&self, order_id: &str, next: F)
+    where
+        F: FnOnce(Option<&TrackedOrder>) -> Option<TrackedOrder>,
+    {
+        let event = match self.orders.entry(order_id.to_owned()) {
+            Entry::Vacant(entry) => {
+                let Some(order) = next(None) else {
+                    return;
+                };
+                entry.insert(order.clone());
+                OrderEvent::Placed(order)
+            }
+            Entry::Occupied(mut entry) => {
+                let current = entry.get();
+                let Some(mut order) = next(Some(current)) else {
+                    return;
+                };
+
+                // REST responses and user channel messages can arrive in any order, so a stale
+                // update must never shrink a fill or reopen a closed order
+                order.size_matched = order.size_matched.max(current.size_matched);
+                if !current.is_open() && order.is_open() {
+                    order.status = current.status.clone();
+                }
+                order.market = order.market.or(current.market);
+                for (trade_id, status) in &current.trades {
+                    order
+                        .trades
+                        .entry(trade_id.clone())
+                        .or_insert_with(|| status.clone());
+                }
+
+                if order == *current {
+                    return;
+                }
+
+                let canceled = order.status == OrderStatusType::Canceled
+                    && current.status != OrderStatusType::Canceled;
+                entry.insert(order.clone());
+
+                if canceled {
+                    OrderEvent::Canceled(order)
+                } else {
+                    OrderEvent::Updated(order)
+                }
+            }
+        };
+
+        // Nobody listening is fine, the tracked orders remain the source of truth
+        _ = self.events.send(event);
+    }
+}
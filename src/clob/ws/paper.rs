@@ -0,0 +1,944 @@
+//! Simulated order execution for paper trading.
+//!
+//! [`PaperExchange`] accepts the same [`SignedOrder`]s as the CLOB and answers with the same
+//! [`PostOrderResponse`]s and [`CancelOrdersResponse`]s, but never sends anything. Orders are
+//! matched against the liquidity of the [`BookUpdate`]s it is fed, whether they come from a live
+//! market subscription or a recording, and every fill is reported as the [`OrderMessage`]s and
+//! [`TradeMessage`]s the user channel would have sent, so strategy code runs unchanged.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use alloy::primitives::ChainId;
+use async_stream::stream;
+use chrono::{DateTime, Utc};
+use futures::Stream;
+use reqwest::{Method, StatusCode};
+use tokio::sync::broadcast;
+use tokio::sync::broadcast::error::RecvError;
+use uuid::Uuid;
+
+use super::book::LocalOrderBook;
+use super::types::response::{
+    BookUpdate, MakerOrder, OrderBookLevel, OrderMessage, OrderMessageType, TradeMessage,
+    TradeMessageStatus, TradeMessageType, WsMessage,
+};
+use crate::Result;
+use crate::auth::ApiKey;
+use crate::clob::traits::Trading;
+use crate::clob::types::request::{CancelMarketOrderRequest, OrdersRequest, TradesRequest};
+use crate::clob::types::response::{
+    CancelOrdersResponse, MakerOrder as TradeMakerOrder, OpenOrderResponse, Page,
+    PostOrderResponse, TradeResponse,
+};
+use crate::clob::types::{
+    OrderStatusType, OrderType, Side, SignedOrder, TradeStatusType, TraderSide,
+};
+use crate::error::Error;
+use crate::types::{Address, B256, Decimal, U256};
+
+/// Number of simulated user channel messages buffered for each [`PaperExchange::events`]
+/// subscriber.
+const EVENT_CAPACITY: usize = 1024;
+
+const TERMINAL_CURSOR: &str = "LTE="; // base64("-1")
+
+/// In-memory exchange that fills orders against market data instead of posting them.
+///
+/// Incoming orders trade immediately against the current book for their asset, best price first,
+/// taking liquidity at each level's price. Whatever is left of a GTC or GTD order then rests, and
+/// is filled at its own price by later snapshots whose opposite side crosses it, with resting
+/// orders served by price, then by arrival. Liquidity taken by a simulated order stays out of the
+/// book until the next snapshot for that asset replaces it. Simulated orders never trade with
+/// each other.
+///
+/// Order IDs are order hashes under the exchange domain the CLOB would use for the configured
+/// chain: the neg risk exchange for assets marked with [`set_neg_risk`](Self::set_neg_risk), or
+/// otherwise whichever exchange the order's signature was made for. Timestamps come from the
+/// market data, so replaying a recording produces the same messages every time. Clones share the
+/// same simulated state.
+///
+/// Alongside its inherent methods, the exchange implements [`Trading`], so strategies written
+/// against that trait can be pointed at it instead of a [`Client`](crate::clob::Client).
+#[derive(Clone, Debug)]
+pub struct PaperExchange {
+    chain_id: ChainId,
+    state: Arc<Mutex<State>>,
+    events: broadcast::Sender<WsMessage>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    books: HashMap<U256, LocalOrderBook>,
+    /// Whether each asset trades on the neg risk exchange, where set explicitly.
+    neg_risk: HashMap<U256, bool>,
+    orders: HashMap<String, RestingOrder>,
+    /// Accepted orders that no longer rest on the book, with their final status.
+    closed: HashMap<String, RestingOrder>,
+    /// Every simulated trade, oldest first.
+    fills: Vec<TradeResponse>,
+    /// Arrival counter for time priority among resting orders.
+    sequence: u64,
+    /// Counter from which trade IDs are derived.
+    trades: u128,
+}
+
+/// A simulated order resting on the book.
+#[derive(Debug, Clone)]
+struct RestingOrder {
+    id: String,
+    market: B256,
+    asset_id: U256,
+    side: Side,
+    price: Decimal,
+    original_size: Decimal,
+    size_matched: Decimal,
+    owner: ApiKey,
+    maker_address: Address,
+    fee_rate_bps: Decimal,
+    order_type: OrderType,
+    status: OrderStatusType,
+    /// Unix timestamp in seconds at which the order was posted.
+    created_at: i64,
+    /// Unix timestamp in seconds after which a GTD order is cancelled.
+    expiration: Option<i64>,
+    sequence: u64,
+    trade_ids: Vec<String>,
+}
+
+impl RestingOrder {
+    fn remaining_size(&self) -> Decimal {
+        self.original_size - self.size_matched
+    }
+
+    fn response(&self) -> OpenOrderResponse {
+        let timestamp = |seconds| DateTime::from_timestamp(seconds, 0).unwrap_or_default();
+
+        OpenOrderResponse::builder()
+            .id(self.id.clone())
+            .status(self.status.clone())
+            .owner(self.owner)
+            .maker_address(self.maker_address)
+            .market(self.market)
+            .asset_id(self.asset_id)
+            .side(self.side)
+            .original_size(self.original_size)
+            .size_matched(self.size_matched)
+            .price(self.price)
+            .associate_trades(self.trade_ids.clone())
+            .outcome(String::new())
+            .created_at(timestamp(self.created_at))
+            .expiration(
+                self.expiration
+                    .map_or(DateTime::<Utc>::UNIX_EPOCH, timestamp),
+            )
+            .order_type(self.order_type.clone())
+            .build()
+    }
+
+    fn message(
+        &self,
+        msg_type: OrderMessageType,
+        status: OrderStatusType,
+        timestamp: i64,
+    ) -> WsMessage {
+        WsMessage::Order(
+            OrderMessage::builder()
+                .id(self.id.clone())
+                .market(self.market)
+                .asset_id(self.asset_id)
+                .side(self.side)
+                .price(self.price)
+                .msg_type(msg_type)
+                .owner(self.owner)
+                .order_owner(self.owner)
+                .original_size(self.original_size)
+                .size_matched(self.size_matched)
+                .timestamp(timestamp)
+                .status(status)
+                .build(),
+        )
+    }
+}
+
+impl State {
+    fn next_trade_id(&mut self) -> String {
+        self.trades += 1;
+        Uuid::from_u128(self.trades).to_string()
+    }
+
+    /// Records `order` trading `fill` as `trader_side`, and queues the user channel message.
+    fn record_trade(
+        &mut self,
+        order: &mut RestingOrder,
+        fill: &OrderBookLevel,
+        trader_side: TraderSide,
+        timestamp: i64,
+        messages: &mut Vec<WsMessage>,
+    ) -> String {
+        let trade_id = self.next_trade_id();
+        order.size_matched += fill.size;
+        order.trade_ids.push(trade_id.clone());
+
+        messages.push(trade(
+            trade_id.clone(),
+            order,
+            fill,
+            trader_side.clone(),
+            timestamp,
+        ));
+        self.fills.push(trade_response(
+            trade_id.clone(),
+            order,
+            fill,
+            trader_side,
+            timestamp,
+        ));
+        trade_id
+    }
+
+    /// Moves the resting order with `order_id` to the closed orders with `status`.
+    fn close(&mut self, order_id: &str, status: OrderStatusType) -> Option<&RestingOrder> {
+        let mut order = self.orders.remove(order_id)?;
+        order.status = status;
+        let order_id = order.id.clone();
+        Some(self.closed.entry(order_id).insert_entry(order).into_mut())
+    }
+
+    /// Fills resting orders for `asset_id` against its book, in price-time priority.
+    fn match_resting(&mut self, asset_id: U256, messages: &mut Vec<WsMessage>) {
+        let Some(book) = self.books.get(&asset_id) else {
+            return;
+        };
+        let timestamp = book.timestamp() / 1000;
+
+        let mut queue: Vec<(Side, Decimal, u64, String)> = self
+            .orders
+            .values()
+            .filter(|order| order.asset_id == asset_id)
+            .map(|order| {
+                // Negating bid prices lets both sides sort best price first
+                let priority = match order.side {
+                    Side::Buy => -order.price,
+                    _ => order.price,
+                };
+                (order.side, priority, order.sequence, order.id.clone())
+            })
+            .collect();
+        queue.sort();
+
+        for (_, _, _, order_id) in queue {
+            let Some(book) = self.books.get_mut(&asset_id) else {
+                return;
+            };
+            let Some(order) = self.orders.get(&order_id) else {
+                continue;
+            };
+
+            let size: Decimal = book
+                .take(order.side, order.price, order.remaining_size())
+                .iter()
+                .map(|level| level.size)
+                .sum();
+            if size.is_zero() {
+                continue;
+            }
+
+            let Some(mut order) = self.orders.remove(&order_id) else {
+                continue;
+            };
+            let fill = OrderBookLevel::builder()
+                .price(order.price)
+                .size(size)
+                .build();
+            self.record_trade(&mut order, &fill, TraderSide::Maker, timestamp, messages);
+
+            let filled = order.remaining_size().is_zero();
+            let status = if filled {
+                OrderStatusType::Matched
+            } else {
+                OrderStatusType::Live
+            };
+            messages.push(order.message(OrderMessageType::Update, status, timestamp));
+
+            self.orders.insert(order_id.clone(), order);
+            if filled {
+                self.close(&order_id, OrderStatusType::Matched);
+            }
+        }
+    }
+
+    /// Cancels GTD orders that expired by `timestamp`, in seconds.
+    fn expire(&mut self, timestamp: i64, messages: &mut Vec<WsMessage>) {
+        let expired: Vec<String> = self
+            .orders
+            .values()
+            .filter(|order| {
+                order
+                    .expiration
+                    .is_some_and(|expiration| expiration <= timestamp)
+            })
+            .map(|order| order.id.clone())
+            .collect();
+
+        for order_id in expired {
+            if let Some(order) = self.close(&order_id, OrderStatusType::Canceled) {
+                messages.push(order.message(
+                    OrderMessageType::Cancellation,
+                    OrderStatusType::Canceled,
+                    timestamp,
+                ));
+            }
+        }
+    }
+}
+
+#[expect(
+    clippy::same_name_method,
+    reason = "The trading trait mirrors these methods so strategies can swap in the REST client"
+)]
+impl PaperExchange {
+    /// Creates an exchange with no books or orders, identifying orders as the CLOB on `chain_id`
+    /// would.
+    #[must_use]
+    pub fn new(chain_id: ChainId) -> Self {
+        Self {
+            chain_id,
+            state: Arc::default(),
+            events: broadcast::channel(EVENT_CAPACITY).0,
+        }
+    }
+
+    /// Subscribes to the simulated user channel from now on.
+    ///
+    /// Only [`WsMessage::Order`] and [`WsMessage::Trade`] messages are sent. A subscriber that
+    /// falls more than 1024 messages behind skips the messages it missed.
+    pub fn events(&self) -> impl Stream<Item = WsMessage> + use<> {
+        let mut rx = self.events.subscribe();
+
+        stream! {
+            loop {
+                match rx.recv().await {
+                    Ok(message) => yield message,
+                    Err(RecvError::Lagged(n)) => {
+                        #[cfg(not(feature = "tracing"))]
+                        let _ = n;
+                        #[cfg(feature = "tracing")]
+                        tracing::warn!("Paper exchange subscriber lagged, missed {n} messages");
+                    }
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        }
+    }
+
+    /// Applies a market channel message. Only [`WsMessage::Book`] snapshots are used, since
+    /// simulated fills make the local book diverge from the server's price changes.
+    pub fn apply(&self, message: &WsMessage) {
+        if let WsMessage::Book(snapshot) = message {
+            self.apply_snapshot(snapshot);
+        }
+    }
+
+    /// Replaces the book for the snapshot's asset, then cancels expired GTD orders and fills
+    /// resting orders that the new book crosses.
+    pub fn apply_snapshot(&self, snapshot: &BookUpdate) {
+        let mut messages = Vec::new();
+        {
+            let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+            state
+                .books
+                .insert(snapshot.asset_id, LocalOrderBook::from_snapshot(snapshot));
+            state.expire(snapshot.timestamp / 1000, &mut messages);
+            state.match_resting(snapshot.asset_id, &mut messages);
+        }
+
+        self.send(messages);
+    }
+
+    /// Returns the current simulated book for `asset_id`, without the liquidity simulated orders
+    /// have taken since its last snapshot.
+    #[must_use]
+    pub fn book(&self, asset_id: U256) -> Option<LocalOrderBook> {
+        let state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        state.books.get(&asset_id).cloned()
+    }
+
+    /// Simulates posting `order`.
+    ///
+    /// Orders the CLOB would reject, such as those for an asset with no book yet, a FOK order
+    /// that cannot be filled in full, or a post-only order that would cross the book, are
+    /// answered with an unsuccessful response carrying the reason in
+    /// [`error_msg`](PostOrderResponse::error_msg).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order's side or amounts are invalid, or there is no exchange
+    /// contract configured for the chain.
+    #[expect(
+        clippy::unused_async,
+        reason = "Matches the REST client so that strategies can swap one for the other"
+    )]
+    pub async fn post_order(&self, order: SignedOrder) -> Result<PostOrderResponse> {
+        let mut messages = Vec::new();
+        let response = {
+            let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+            self.post(&mut state, &order, &mut messages)?
+        };
+
+        self.send(messages);
+        Ok(response)
+    }
+
+    /// Simulates posting each of `orders` in turn.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any order's side or amounts are invalid, or there is no exchange
+    /// contract configured for the chain. Orders before the invalid one remain posted.
+    #[expect(
+        clippy::unused_async,
+        reason = "Matches the REST client so that strategies can swap one for the other"
+    )]
+    pub async fn post_orders(&self, orders: Vec<SignedOrder>) -> Result<Vec<PostOrderResponse>> {
+        let mut messages = Vec::new();
+        let responses = {
+            let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+            orders
+                .iter()
+                .map(|order| self.post(&mut state, order, &mut messages))
+                .collect()
+        };
+
+        self.send(messages);
+        responses
+    }
+
+    /// Cancels the resting order with `order_id`.
+    ///
+    /// # Errors
+    ///
+    /// This never fails, and only returns a [`Result`] to match the REST client.
+    pub async fn cancel_order(&self, order_id: &str) -> Result<CancelOrdersResponse> {
+        self.cancel_orders(&[order_id]).await
+    }
+
+    /// Cancels the resting orders with `order_ids`.
+    ///
+    /// # Errors
+    ///
+    /// This never fails, and only returns a [`Result`] to match the REST client.
+    #[expect(
+        clippy::unused_async,
+        reason = "Matches the REST client so that strategies can swap one for the other"
+    )]
+    pub async fn cancel_orders(&self, order_ids: &[&str]) -> Result<CancelOrdersResponse> {
+        let mut messages = Vec::new();
+        let mut response = CancelOrdersResponse::default();
+        {
+            let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+            for order_id in order_ids {
+                let Some(order) = state.close(order_id, OrderStatusType::Canceled).cloned() else {
+                    response.not_canceled.insert(
+                        (*order_id).to_owned(),
+                        "order can't be found - already canceled or matched".to_owned(),
+                    );
+                    continue;
+                };
+
+                let timestamp = state
+                    .books
+                    .get(&order.asset_id)
+                    .map_or(0, |book| book.timestamp() / 1000);
+                messages.push(order.message(
+                    OrderMessageType::Cancellation,
+                    OrderStatusType::Canceled,
+                    timestamp,
+                ));
+                response.canceled.push(order.id);
+            }
+        }
+
+        self.send(messages);
+        Ok(response)
+    }
+
+    /// Cancels every resting order.
+    ///
+    /// # Errors
+    ///
+    /// This never fails, and only returns a [`Result`] to match the REST client.
+    pub async fn cancel_all_orders(&self) -> Result<CancelOrdersResponse> {
+        let order_ids: Vec<String> = {
+            let state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+            state.orders.keys().cloned().collect()
+        };
+        let order_ids: Vec<&str> = order_ids.iter().map(String::as_str).collect();
+
+        self.cancel_orders(&order_ids).await
+    }
+
+    /// Cancels the resting orders in `request`'s market and asset.
+    ///
+    /// # Errors
+    ///
+    /// This never fails, and only returns a [`Result`] to match the REST client.
+    pub async fn cancel_market_orders(
+        &self,
+        request: &CancelMarketOrderRequest,
+    ) -> Result<CancelOrdersResponse> {
+        let order_ids: Vec<String> = {
+            let state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+            state
+                .orders
+                .values()
+                .filter(|order| request.market.is_none_or(|market| market == order.market))
+                .filter(|order| {
+                    request
+                        .asset_id
+                        .is_none_or(|asset_id| asset_id == order.asset_id)
+                })
+                .map(|order| order.id.clone())
+                .collect()
+        };
+        let order_ids: Vec<&str> = order_ids.iter().map(String::as_str).collect();
+
+        self.cancel_orders(&order_ids).await
+    }
+
+    /// Marks whether `asset_id` trades on the neg risk exchange, which determines the domain its
+    /// order IDs are hashed under. Without this, the exchange is inferred from each order's
+    /// signature, which fails for orders that were not signed.
+    pub fn set_neg_risk(&self, asset_id: U256, neg_risk: bool) {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        state.neg_risk.insert(asset_id, neg_risk);
+    }
+
+    /// Returns the simulated order with `order_id`, whether it still rests on the book or not.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StatusCode::NOT_FOUND`] status error if no such order was accepted.
+    #[expect(
+        clippy::unused_async,
+        reason = "Matches the REST client so that strategies can swap one for the other"
+    )]
+    pub async fn order(&self, order_id: &str) -> Result<OpenOrderResponse> {
+        let state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        state
+            .orders
+            .get(order_id)
+            .or_else(|| state.closed.get(order_id))
+            .map(RestingOrder::response)
+            .ok_or_else(|| {
+                Error::status(
+                    StatusCode::NOT_FOUND,
+                    Method::GET,
+                    format!("data/order/{order_id}"),
+                    format!("order {order_id} not found"),
+                )
+            })
+    }
+
+    /// Returns the resting orders matching `request`, oldest first, as a single page.
+    ///
+    /// # Errors
+    ///
+    /// This never fails, and only returns a [`Result`] to match the REST client.
+    #[expect(
+        clippy::unused_async,
+        reason = "Matches the REST client so that strategies can swap one for the other"
+    )]
+    pub async fn orders(
+        &self,
+        request: &OrdersRequest,
+        _next_cursor: Option<String>,
+    ) -> Result<Page<OpenOrderResponse>> {
+        let state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut orders: Vec<&RestingOrder> = state
+            .orders
+            .values()
+            .filter(|order| {
+                request
+                    .order_id
+                    .as_ref()
+                    .is_none_or(|order_id| *order_id == order.id)
+            })
+            .filter(|order| request.market.is_none_or(|market| market == order.market))
+            .filter(|order| {
+                request
+                    .asset_id
+                    .is_none_or(|asset_id| asset_id == order.asset_id)
+            })
+            .collect();
+        orders.sort_by_key(|order| order.sequence);
+
+        Ok(page(
+            orders.into_iter().map(RestingOrder::response).collect(),
+        ))
+    }
+
+    /// Returns the simulated trades matching `request`, oldest first, as a single page.
+    ///
+    /// # Errors
+    ///
+    /// This never fails, and only returns a [`Result`] to match the REST client.
+    #[expect(
+        clippy::unused_async,
+        reason = "Matches the REST client so that strategies can swap one for the other"
+    )]
+    pub async fn trades(
+        &self,
+        request: &TradesRequest,
+        _next_cursor: Option<String>,
+    ) -> Result<Page<TradeResponse>> {
+        let state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        let trades = state
+            .fills
+            .iter()
+            .filter(|trade| request.id.as_ref().is_none_or(|id| *id == trade.id))
+            .filter(|trade| {
+                [request.maker_address, request.taker_address]
+                    .into_iter()
+                    .flatten()
+                    .all(|address| address == trade.maker_address)
+            })
+            .filter(|trade| request.market.is_none_or(|market| market == trade.market))
+            .filter(|trade| {
+                request
+                    .asset_id
+                    .is_none_or(|asset_id| asset_id == trade.asset_id)
+            })
+            .filter(|trade| {
+                let match_time = trade.match_time.timestamp();
+                request.before.is_none_or(|before| match_time < before)
+                    && request.after.is_none_or(|after| match_time > after)
+            })
+            .cloned()
+            .collect();
+
+        Ok(page(trades))
+    }
+
+    fn post(
+        &self,
+        state: &mut State,
+        order: &SignedOrder,
+        messages: &mut Vec<WsMessage>,
+    ) -> Result<PostOrderResponse> {
+        let (side, price, size) = order.order.terms()?;
+        let asset_id = order.order.tokenId;
+        let neg_risk = state
+            .neg_risk
+            .get(&asset_id)
+            .copied()
+            .unwrap_or_else(|| self.signed_for_neg_risk(order));
+        let order_id = order.order_id(self.chain_id, neg_risk)?;
+
+        let reject = |reason: String| {
+            PostOrderResponse::builder()
+                .error_msg(reason)
+                .making_amount(Decimal::ZERO)
+                .taking_amount(Decimal::ZERO)
+                .order_id(order_id.clone())
+                .status(OrderStatusType::Unmatched)
+                .success(false)
+                .build()
+        };
+
+        if state.orders.contains_key(&order_id) {
+            return Ok(reject(format!("order {order_id} is already live")));
+        }
+        let Some(book) = state.books.get_mut(&asset_id) else {
+            return Ok(reject(format!("the orderbook {asset_id} does not exist")));
+        };
+        let market = book.market();
+        let timestamp = book.timestamp() / 1000;
+
+        if order.post_only == Some(true) {
+            let best = match side {
+                Side::Buy => book.best_ask().filter(|ask| ask.price <= price),
+                _ => book.best_bid().filter(|bid| bid.price >= price),
+            };
+            if best.is_some() {
+                return Ok(reject(
+                    "invalid post-only order: order crosses book".to_owned(),
+                ));
+            }
+        }
+
+        let fills = if order.order_type == OrderType::FOK {
+            // Only take liquidity if all of it is there
+            let mut trial = book.clone();
+            let fills = trial.take(side, price, size);
+            if fills.iter().map(|fill| fill.size).sum::<Decimal>() < size {
+                return Ok(reject(
+                    "order couldn't be fully filled. FOK orders are fully filled or killed."
+                        .to_owned(),
+                ));
+            }
+            *book = trial;
+            fills
+        } else {
+            book.take(side, price, size)
+        };
+
+        if order.order_type == OrderType::FAK && fills.is_empty() {
+            return Ok(reject(
+                "no orders found to match with FAK order. FAK orders are partially filled or \
+                 killed if no match is found."
+                    .to_owned(),
+            ));
+        }
+
+        state.sequence += 1;
+        let mut resting = RestingOrder {
+            id: order_id.clone(),
+            market,
+            asset_id,
+            side,
+            price,
+            original_size: size,
+            size_matched: Decimal::ZERO,
+            owner: order.owner,
+            maker_address: order.order.maker,
+            fee_rate_bps: u64::try_from(order.order.feeRateBps).map_or(Decimal::MAX, Decimal::from),
+            order_type: order.order_type.clone(),
+            status: OrderStatusType::Live,
+            created_at: timestamp,
+            expiration: (order.order_type == OrderType::GTD)
+                .then(|| i64::try_from(order.order.expiration).unwrap_or(i64::MAX)),
+            sequence: state.sequence,
+            trade_ids: Vec::with_capacity(fills.len()),
+        };
+
+        let mut notional = Decimal::ZERO;
+        for fill in &fills {
+            notional += fill.price * fill.size;
+            state.record_trade(&mut resting, fill, TraderSide::Taker, timestamp, messages);
+        }
+        let trade_ids = resting.trade_ids.clone();
+
+        let rests = !resting.remaining_size().is_zero()
+            && matches!(order.order_type, OrderType::GTC | OrderType::GTD);
+        let status = if rests {
+            OrderStatusType::Live
+        } else {
+            OrderStatusType::Matched
+        };
+
+        let (making_amount, taking_amount) = match side {
+            Side::Buy => (notional, resting.size_matched),
+            _ => (resting.size_matched, notional),
+        };
+
+        if rests {
+            messages.push(resting.message(
+                OrderMessageType::Placement,
+                OrderStatusType::Live,
+                timestamp,
+            ));
+            state.orders.insert(order_id.clone(), resting);
+        } else {
+            resting.status = OrderStatusType::Matched;
+            state.closed.insert(order_id.clone(), resting);
+        }
+
+        Ok(PostOrderResponse::builder()
+            .making_amount(making_amount)
+            .taking_amount(taking_amount)
+            .order_id(order_id)
+            .status(status)
+            .success(true)
+            .trade_ids(trade_ids)
+            .build())
+    }
+
+    /// Whether `order` was signed for the neg risk exchange, judging by which exchange's domain
+    /// its signature recovers the signer under.
+    fn signed_for_neg_risk(&self, order: &SignedOrder) -> bool {
+        order
+            .recover_signer(self.chain_id, true)
+            .is_ok_and(|signer| signer == order.order.signer)
+    }
+
+    fn send(&self, messages: Vec<WsMessage>) {
+        for message in messages {
+            // Nobody listening is fine, the simulation carries on regardless
+            _ = self.events.send(message);
+        }
+    }
+}
+
+impl Trading for PaperExchange {
+    fn post_order(
+        &self,
+        order: SignedOrder,
+    ) -> impl Future<Output = Result<PostOrderResponse>> + Send {
+        PaperExchange::post_order(self, order)
+    }
+
+    fn post_orders(
+        &self,
+        orders: Vec<SignedOrder>,
+    ) -> impl Future<Output = Result<Vec<PostOrderResponse>>> + Send {
+        PaperExchange::post_orders(self, orders)
+    }
+
+    fn order(&self, order_id: &str) -> impl Future<Output = Result<OpenOrderResponse>> + Send {
+        PaperExchange::order(self, order_id)
+    }
+
+    fn orders(
+        &self,
+        request: &OrdersRequest,
+        next_cursor: Option<String>,
+    ) -> impl Future<Output = Result<Page<OpenOrderResponse>>> + Send {
+        PaperExchange::orders(self, request, next_cursor)
+    }
+
+    fn cancel_order(
+        &self,
+        order_id: &str,
+    ) -> impl Future<Output = Result<CancelOrdersResponse>> + Send {
+        PaperExchange::cancel_order(self, order_id)
+    }
+
+    fn cancel_orders(
+        &self,
+        order_ids: &[&str],
+    ) -> impl Future<Output = Result<CancelOrdersResponse>> + Send {
+        PaperExchange::cancel_orders(self, order_ids)
+    }
+
+    fn cancel_all_orders(&self) -> impl Future<Output = Result<CancelOrdersResponse>> + Send {
+        PaperExchange::cancel_all_orders(self)
+    }
+
+    fn cancel_market_orders(
+        &self,
+        request: &CancelMarketOrderRequest,
+    ) -> impl Future<Output = Result<CancelOrdersResponse>> + Send {
+        PaperExchange::cancel_market_orders(self, request)
+    }
+
+    fn trades(
+        &self,
+        request: &TradesRequest,
+        next_cursor: Option<String>,
+    ) -> impl Future<Output = Result<Page<TradeResponse>>> + Send {
+        PaperExchange::trades(self, request, next_cursor)
+    }
+}
+
+/// Wraps `data` as the only page of a listing.
+fn page<T>(data: Vec<T>) -> Page<T> {
+    let count = data.len() as u64;
+
+    Page::builder()
+        .data(data)
+        .next_cursor(TERMINAL_CURSOR)
+        .limit(count)
+        .count(count)
+        .build()
+}
+
+/// Builds the user channel message for `order` trading `fill` as `trader_side`.
+fn trade(
+    trade_id: String,
+    order: &RestingOrder,
+    fill: &OrderBookLevel,
+    trader_side: TraderSide,
+    timestamp: i64,
+) -> WsMessage {
+    // The other side of every simulated trade is anonymous market liquidity
+    let (taker_order_id, maker_orders) = match trader_side {
+        TraderSide::Maker => (
+            None,
+            vec![
+                MakerOrder::builder()
+                    .asset_id(order.asset_id)
+                    .matched_amount(fill.size)
+                    .order_id(order.id.clone())
+                    .outcome(String::new())
+                    .owner(order.owner)
+                    .price(order.price)
+                    .build(),
+            ],
+        ),
+        _ => (Some(order.id.clone()), Vec::new()),
+    };
+
+    WsMessage::Trade(
+        TradeMessage::builder()
+            .id(trade_id)
+            .market(order.market)
+            .asset_id(order.asset_id)
+            .side(order.side)
+            .size(fill.size)
+            .price(fill.price)
+            .status(TradeMessageStatus::Matched)
+            .msg_type(TradeMessageType::Trade)
+            .last_update(timestamp)
+            .matchtime(timestamp)
+            .timestamp(timestamp)
+            .owner(order.owner)
+            .trade_owner(order.owner)
+            .maybe_taker_order_id(taker_order_id)
+            .maker_orders(maker_orders)
+            .trader_side(trader_side)
+            .build(),
+    )
+}
+
+/// Builds the REST trade record for `order` trading `fill` as `trader_side`.
+fn trade_response(
+    trade_id: String,
+    order: &RestingOrder,
+    fill: &OrderBookLevel,
+    trader_side: TraderSide,
+    timestamp: i64,
+) -> TradeResponse {
+    let time = DateTime::from_timestamp(timestamp, 0).unwrap_or_default();
+    let (taker_order_id, maker_orders) = match trader_side {
+        TraderSide::Maker => (
+            String::new(),
+            vec![
+                TradeMakerOrder::builder()
+                    .order_id(order.id.clone())
+                    .owner(order.owner)
+                    .maker_address(order.maker_address)
+                    .matched_amount(fill.size)
+                    .price(order.price)
+                    .fee_rate_bps(order.fee_rate_bps)
+                    .asset_id(order.asset_id)
+                    .outcome(String::new())
+                    .side(order.side)
+                    .build(),
+            ],
+        ),
+        _ => (order.id.clone(), Vec::new()),
+    };
+
+    TradeResponse::builder()
+        .id(trade_id)
+        .taker_order_id(taker_order_id)
+        .market(order.market)
+        .asset_id(order.asset_id)
+        .side(order.side)
+        .size(fill.size)
+        .fee_rate_bps(order.fee_rate_bps)
+        .price(fill.price)
+        .status(TradeStatusType::Matched)
+        .match_time(time)
+        .last_update(time)
+        .outcome(String::new())
+        .bucket_index(0)
+        .owner(order.owner)
+        .maker_address(order.maker_address)
+        .maker_orders(maker_orders)
+        .transaction_hash(B256::ZERO)
+        .trader_side(trader_side)
+        .build()
+}
@@ -8,10 +8,11 @@ use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, PoisonError, RwLock};
 use std::time::Instant;
 
-use async_stream::try_stream;
+use async_stream::stream;
 use dashmap::{DashMap, Entry};
-use futures::Stream;
-use tokio::sync::broadcast::error::RecvError;
+use futures::{Stream, StreamExt as _, future};
+use tokio::sync::broadcast::{self, error::RecvError};
+use tokio::sync::watch;
 
 use super::interest::{InterestTracker, MessageInterest};
 use super::types::request::SubscriptionRequest;
@@ -62,6 +63,46 @@ impl SubscriptionInfo {
     }
 }
 
+/// Item yielded by [`SubscriptionManager::subscribe_market_events`].
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+#[expect(
+    clippy::large_enum_variant,
+    reason = "Nearly every event is a message, so boxing would only add an allocation per message"
+)]
+pub enum MarketEvent {
+    /// A message from the market channel.
+    Message(WsMessage),
+    /// Messages may have been missed, so state built from earlier messages is out of date.
+    Stale(StaleReason),
+}
+
+/// Item yielded by [`SubscriptionManager::subscribe_user_events`].
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+#[expect(
+    clippy::large_enum_variant,
+    reason = "Nearly every event is a message, so boxing would only add an allocation per message"
+)]
+pub enum UserEvent {
+    /// A message from the user channel.
+    Message(WsMessage),
+    /// Messages may have been missed, so state built from earlier messages is out of date.
+    Stale(StaleReason),
+}
+
+/// Why data from a subscription is considered stale.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StaleReason {
+    /// The subscriber fell behind and this many messages were dropped.
+    Lagged(u64),
+    /// The connection dropped and was re-established.
+    Reconnected,
+    /// A locally maintained orderbook no longer matched the server.
+    OutOfSync,
+}
+
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ChannelType {
@@ -214,6 +255,37 @@ impl SubscriptionManager {
         asset_ids: Vec<U256>,
         custom_features: bool,
     ) -> Result<impl Stream<Item = Result<WsMessage>> + use<>> {
+        let events = self.subscribe_market_events(asset_ids, custom_features)?;
+
+        Ok(events.filter_map(|event| async move {
+            match event {
+                Ok(MarketEvent::Message(msg)) => Some(Ok(msg)),
+                Ok(MarketEvent::Stale(reason)) => {
+                    #[cfg(not(feature = "tracing"))]
+                    let _ = reason;
+                    #[cfg(feature = "tracing")]
+                    if let StaleReason::Lagged(n) = reason {
+                        tracing::warn!("Subscription lagged, missed {n} messages — continuing");
+                    }
+                    None
+                }
+                Err(e) => Some(Err(e)),
+            }
+        }))
+    }
+
+    /// Subscribe to public market data channel, including notifications of gaps in the feed.
+    ///
+    /// Alongside every message for `asset_ids`, the stream yields [`MarketEvent::Stale`] when the
+    /// subscriber falls behind the connection's buffer or the connection is re-established, since
+    /// messages may have been missed in both cases.
+    ///
+    /// This will fail if `asset_ids` is empty.
+    pub fn subscribe_market_events(
+        &self,
+        asset_ids: Vec<U256>,
+        custom_features: bool,
+    ) -> Result<impl Stream<Item = Result<MarketEvent>> + use<>> {
         if asset_ids.is_empty() {
             return Err(WsError::SubscriptionFailed(
                 "asset_ids cannot be empty: at least one asset ID must be provided for subscription"
@@ -281,50 +353,39 @@ impl SubscriptionManager {
         );
 
         // Create filtered stream with its own receiver
-        let mut rx = self.connection.subscribe();
+        let rx = self.connection.subscribe();
+        let state_rx = self.connection.state_receiver();
         let asset_ids_set: HashSet<U256> = asset_ids.into_iter().collect();
 
-        Ok(try_stream! {
-            loop {
-                match rx.recv().await {
-                    Ok(msg) => {
-                        // Filter messages by asset_id
-                        let should_yield = match &msg {
-                            WsMessage::Book(book) => asset_ids_set.contains(&book.asset_id),
-                            WsMessage::PriceChange(price) => {
-                                price
-                                    .price_changes
-                                    .iter()
-                                    .any(|pc| asset_ids_set.contains(&pc.asset_id))
-                            },
-                            WsMessage::LastTradePrice(ltp) => asset_ids_set.contains(&ltp.asset_id),
-                            WsMessage::TickSizeChange(tsc) => asset_ids_set.contains(&tsc.asset_id),
-                            WsMessage::BestBidAsk(bba) => asset_ids_set.contains(&bba.asset_id),
-                            WsMessage::NewMarket(nm) => {
-                                nm.asset_ids.iter().any(|id| asset_ids_set.contains(id))
-                            },
-                            WsMessage::MarketResolved(mr) => {
-                                mr.asset_ids.iter().any(|id| asset_ids_set.contains(id))
-                            },
-                            _ => false,
-                        };
-
-                        if should_yield {
-                            yield msg
+        Ok(feed(rx, state_rx).filter_map(move |item| {
+            let event = match item {
+                Ok(msg) => {
+                    // Filter messages by asset_id
+                    let should_yield = match &msg {
+                        WsMessage::Book(book) => asset_ids_set.contains(&book.asset_id),
+                        WsMessage::PriceChange(price) => price
+                            .price_changes
+                            .iter()
+                            .any(|pc| asset_ids_set.contains(&pc.asset_id)),
+                        WsMessage::LastTradePrice(ltp) => asset_ids_set.contains(&ltp.asset_id),
+                        WsMessage::TickSizeChange(tsc) => asset_ids_set.contains(&tsc.asset_id),
+                        WsMessage::BestBidAsk(bba) => asset_ids_set.contains(&bba.asset_id),
+                        WsMessage::NewMarket(nm) => {
+                            nm.asset_ids.iter().any(|id| asset_ids_set.contains(id))
                         }
-                    }
-                    Err(RecvError::Lagged(n)) => {
-                        #[cfg(not(feature = "tracing"))]
-                        let _ = n;
-                        #[cfg(feature = "tracing")]
-                        tracing::warn!("Subscription lagged, missed {n} messages — continuing");
-                    }
-                    Err(RecvError::Closed) => {
-                        break;
-                    }
+                        WsMessage::MarketResolved(mr) => {
+                            mr.asset_ids.iter().any(|id| asset_ids_set.contains(id))
+                        }
+                        _ => false,
+                    };
+
+                    should_yield.then_some(MarketEvent::Message(msg))
                 }
-            }
-        })
+                Err(reason) => Some(MarketEvent::Stale(reason)),
+            };
+
+            future::ready(event.map(Ok))
+        }))
     }
 
     /// Subscribe to authenticated user channel.
@@ -333,6 +394,35 @@ impl SubscriptionManager {
         markets: Vec<B256>,
         auth: &Credentials,
     ) -> Result<impl Stream<Item = Result<WsMessage>> + use<>> {
+        let events = self.subscribe_user_events(markets, auth)?;
+
+        Ok(events.filter_map(|event| async move {
+            match event {
+                Ok(UserEvent::Message(msg)) => Some(Ok(msg)),
+                Ok(UserEvent::Stale(reason)) => {
+                    #[cfg(not(feature = "tracing"))]
+                    let _ = reason;
+                    #[cfg(feature = "tracing")]
+                    if let StaleReason::Lagged(n) = reason {
+                        tracing::warn!("Subscription lagged, missed {n} messages — continuing");
+                    }
+                    None
+                }
+                Err(e) => Some(Err(e)),
+            }
+        }))
+    }
+
+    /// Subscribe to authenticated user channel, including notifications of gaps in the feed.
+    ///
+    /// Alongside every user message, the stream yields [`UserEvent::Stale`] when the subscriber
+    /// falls behind the connection's buffer or the connection is re-established, since order and
+    /// trade updates may have been missed in both cases.
+    pub fn subscribe_user_events(
+        &self,
+        markets: Vec<B256>,
+        auth: &Credentials,
+    ) -> Result<impl Stream<Item = Result<UserEvent>> + use<>> {
         self.interest.add(MessageInterest::USER);
 
         // Store auth for re-subscription on reconnect.
@@ -390,28 +480,17 @@ impl SubscriptionManager {
         );
 
         // Create stream for user messages
-        let mut rx = self.connection.subscribe();
+        let rx = self.connection.subscribe();
+        let state_rx = self.connection.state_receiver();
 
-        Ok(try_stream! {
-            loop {
-                match rx.recv().await {
-                    Ok(msg) => {
-                        if msg.is_user() {
-                            yield msg;
-                        }
-                    }
-                    Err(RecvError::Lagged(n)) => {
-                        #[cfg(not(feature = "tracing"))]
-                        let _ = n;
-                        #[cfg(feature = "tracing")]
-                        tracing::warn!("Subscription lagged, missed {n} messages — continuing");
-                    }
-                    Err(RecvError::Closed) => {
-                        break;
-                    }
-                }
-            }
-        })
+        Ok(feed(rx, state_rx).filter_map(|item| {
+            let event = match item {
+                Ok(msg) => msg.is_user().then_some(UserEvent::Message(msg)),
+                Err(reason) => Some(UserEvent::Stale(reason)),
+            };
+
+            future::ready(event.map(Ok))
+        }))
     }
 
     /// Get information about all active subscriptions.
@@ -563,3 +642,44 @@ impl SubscriptionManager {
         Ok(())
     }
 }
+
+/// Messages from the connection, interleaved with the [`StaleReason`] whenever the subscriber
+/// falls behind `rx`'s buffer or the connection is re-established. Ends when the connection
+/// closes.
+fn feed(
+    mut rx: broadcast::Receiver<WsMessage>,
+    mut state_rx: watch::Receiver<ConnectionState>,
+) -> impl Stream<Item = std::result::Result<WsMessage, StaleReason>> {
+    stream! {
+        let mut was_connected = state_rx.borrow_and_update().is_connected();
+        let mut watching_state = true;
+
+        loop {
+            let (received, reconnected) = tokio::select! {
+                received = rx.recv() => (Some(received), false),
+                changed = state_rx.changed(), if watching_state => {
+                    let mut reconnected = false;
+                    if changed.is_err() {
+                        // Connection manager is gone, the broadcast channel closes next
+                        watching_state = false;
+                    } else if state_rx.borrow_and_update().is_connected() {
+                        reconnected = was_connected;
+                        was_connected = true;
+                    }
+                    (None, reconnected)
+                }
+            };
+
+            if reconnected {
+                yield Err(StaleReason::Reconnected);
+            }
+
+            match received {
+                Some(Ok(msg)) => yield Ok(msg),
+                Some(Err(RecvError::Lagged(n))) => yield Err(StaleReason::Lagged(n)),
+                Some(Err(RecvError::Closed)) => break,
+                None => {}
+            }
+        }
+    }
+}
@@ -6,6 +6,7 @@ use serde_with::{DefaultOnNull, DisplayFromStr, NoneAsEmptyString, serde_as};
 use tracing::warn;
 
 use crate::auth::ApiKey;
+use crate::clob::types::response::{OrderBookSummaryResponse, OrderSummary};
 use crate::clob::types::{OrderStatusType, Side, TraderSide};
 use crate::clob::ws::interest::MessageInterest;
 use crate::error::Kind;
@@ -86,6 +87,29 @@ pub struct BookUpdate {
     pub hash: Option<String>,
 }
 
+impl From<OrderBookSummaryResponse> for BookUpdate {
+    fn from(summary: OrderBookSummaryResponse) -> Self {
+        let levels = |levels: Vec<OrderSummary>| {
+            levels
+                .into_iter()
+                .map(|level| OrderBookLevel {
+                    price: level.price,
+                    size: level.size,
+                })
+                .collect()
+        };
+
+        Self {
+            asset_id: summary.asset_id,
+            market: summary.market,
+            timestamp: summary.timestamp.timestamp_millis(),
+            bids: levels(summary.bids),
+            asks: levels(summary.asks),
+            hash: summary.hash,
+        }
+    }
+}
+
 /// Individual price level in an orderbook.
 #[non_exhaustive]
 #[derive(Debug, Clone, Deserialize, Builder)]
@@ -317,6 +341,10 @@ pub enum TradeMessageStatus {
     Mined,
     #[serde(alias = "confirmed", alias = "CONFIRMED")]
     Confirmed,
+    #[serde(alias = "retrying", alias = "RETRYING")]
+    Retrying,
+    #[serde(alias = "failed", alias = "FAILED")]
+    Failed,
     #[serde(untagged)]
     Unknown(String),
 }
@@ -24,6 +24,7 @@
 //! # }
 //! ```
 
+use bon::Builder;
 use reqwest::{
     Client as ReqwestClient, Method,
     header::{HeaderMap, HeaderValue},
@@ -41,6 +42,8 @@ use super::types::response::{
     Activity, BuilderLeaderboardEntry, BuilderVolumeEntry, ClosedPosition, Health, LiveVolume,
     MetaHolder, OpenInterest, Position, Trade, Traded, TraderLeaderboardEntry, Value,
 };
+use crate::rate_limit::RateLimiter;
+use crate::retry::RetryPolicy;
 use crate::{Result, ToQueryParams as _};
 
 /// HTTP client for the Polymarket Data API.
@@ -64,9 +67,24 @@ use crate::{Result, ToQueryParams as _};
 /// let client = Client::new("https://custom-api.example.com").unwrap();
 /// ```
 #[derive(Clone, Debug)]
+#[expect(
+    clippy::struct_field_names,
+    reason = "`client` is the underlying HTTP client rather than a reference to this type"
+)]
 pub struct Client {
     host: Url,
     client: ReqwestClient,
+    config: Config,
+}
+
+/// Configuration for [`Client`]
+#[derive(Clone, Debug, Default, Builder)]
+pub struct Config {
+    /// How failed requests are retried. By default, only read-only `GET` requests are retried.
+    #[builder(default)]
+    retry: RetryPolicy,
+    /// Client-side rate limiter applied before each request. Requests are not limited by default.
+    rate_limiter: Option<RateLimiter>,
 }
 
 impl Default for Client {
@@ -87,6 +105,15 @@ impl Client {
     ///
     /// Returns an error if the URL is invalid or the HTTP client cannot be created.
     pub fn new(host: &str) -> Result<Client> {
+        Self::with_config(host, Config::default())
+    }
+
+    /// Creates a new Data API client with a custom host URL and [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL is invalid or the HTTP client cannot be created.
+    pub fn with_config(host: &str, config: Config) -> Result<Client> {
         let mut headers = HeaderMap::new();
 
         headers.insert("User-Agent", HeaderValue::from_static("rs_clob_client"));
@@ -98,6 +125,7 @@ impl Client {
         Ok(Self {
             host: Url::parse(host)?,
             client,
+            config,
         })
     }
 
@@ -117,7 +145,14 @@ impl Client {
             .client
             .request(Method::GET, format!("{}{path}{query}", self.host))
             .build()?;
-        crate::request(&self.client, request, None).await
+        crate::request(
+            &self.client,
+            request,
+            None,
+            &self.config.retry,
+            self.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Performs a health check on the API.
@@ -65,4 +65,4 @@
 pub mod client;
 pub mod types;
 
-pub use client::Client;
+pub use client::{Client, Config};
@@ -26,6 +26,8 @@ pub enum Kind {
     WebSocket,
     /// Error related to geographic restrictions blocking access
     Geoblock,
+    /// Error related to a request exceeding the client-side rate limit
+    RateLimit,
 }
 
 #[derive(Debug)]
@@ -61,6 +63,14 @@ impl Error {
         e.downcast_ref::<E>()
     }
 
+    /// Returns why the CLOB rejected an order, if this is the [`Status`] error of a failed order
+    /// placement. See [`RejectionReason::from_error`](crate::clob::types::RejectionReason::from_error).
+    #[cfg(feature = "clob")]
+    #[must_use]
+    pub fn rejection_reason(&self) -> Option<crate::clob::types::RejectionReason> {
+        crate::clob::types::RejectionReason::from_error(self)
+    }
+
     pub fn validation<S: Into<String>>(message: S) -> Self {
         Validation {
             reason: message.into(),
@@ -0,0 +1,401 @@
+//! CTF Exchange client for managing orders directly on the exchange contracts.
+//!
+//! Orders are settled by the CTF Exchange, or by the Neg Risk CTF Exchange for neg risk markets.
+//! Both record which orders were filled or cancelled and the nonce each maker's orders must be
+//! signed with, independently of the CLOB.
+//!
+//! # Operations
+//!
+//! - **Cancel**: Cancel one or more signed orders on-chain
+//! - **Nonce**: Query, check, and increment the maker's nonce, which invalidates every order
+//!   signed with the previous one
+//! - **Status**: Query whether an order was filled or cancelled, and how much of it is filled
+//!
+//! # Example
+//!
+//! ```no_run
+//! use polymarket_client_sdk::exchange::Client;
+//! use alloy::providers::ProviderBuilder;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let provider = ProviderBuilder::new()
+//!     .connect("https://polygon-rpc.com")
+//!     .await?;
+//!
+//! let client = Client::new(provider, 137)?;
+//! # Ok(())
+//! # }
+//! ```
+
+#![allow(
+    clippy::exhaustive_structs,
+    clippy::exhaustive_enums,
+    reason = "Alloy sol! macro generates code that triggers these lints"
+)]
+
+use alloy::primitives::{Bytes, ChainId, U256};
+use alloy::providers::Provider;
+use alloy::sol;
+
+use super::error::ExchangeError;
+use super::types::{
+    CancelOrdersResponse, IncrementNonceResponse, NonceResponse, OrderStatusResponse,
+};
+use crate::clob::types::{Order, SignedOrder};
+use crate::types::Address;
+use crate::{Result, contract_config};
+
+// CTF Exchange contract interface
+//
+// The Neg Risk CTF Exchange extends the CTF Exchange, so the same interface covers both.
+//
+// Source: https://github.com/Polymarket/ctf-exchange
+//
+// Key functions implemented:
+// - cancelOrder, cancelOrders: Cancel orders, which must be called by their maker
+// - incrementNonce: Invalidate every order signed with the caller's current nonce
+// - nonces, isValidNonce: View functions for the nonce orders must be signed with
+// - getOrderStatus: View function for the fill and cancellation status of an order
+sol! {
+    #[sol(rpc)]
+    interface ICtfExchange {
+        /// An order as signed by its maker, with `side` and `signatureType` as their `uint8`
+        /// values.
+        struct Order {
+            uint256 salt;
+            address maker;
+            address signer;
+            address taker;
+            uint256 tokenId;
+            uint256 makerAmount;
+            uint256 takerAmount;
+            uint256 expiration;
+            uint256 nonce;
+            uint256 feeRateBps;
+            uint8 side;
+            uint8 signatureType;
+            bytes signature;
+        }
+
+        /// The fill and cancellation status of an order.
+        struct OrderStatus {
+            bool isFilledOrCancelled;
+            uint256 remaining;
+        }
+
+        /// Cancels an order. Reverts unless called by the order's maker.
+        function cancelOrder(Order memory order) external;
+
+        /// Cancels a set of orders. Reverts unless called by the maker of every order.
+        function cancelOrders(Order[] memory orders) external;
+
+        /// Increments the caller's nonce, invalidating every order signed with the previous one.
+        function incrementNonce() external;
+
+        /// Returns the current nonce of a maker.
+        function nonces(address usr) external view returns (uint256);
+
+        /// Returns whether `nonce` is the current nonce of `usr`.
+        function isValidNonce(address usr, uint256 nonce) external view returns (bool);
+
+        /// Returns the fill and cancellation status of an order by its hash.
+        function getOrderStatus(bytes32 orderHash) external view returns (OrderStatus memory);
+    }
+}
+
+impl From<&SignedOrder> for ICtfExchange::Order {
+    fn from(signed: &SignedOrder) -> Self {
+        let order = &signed.order;
+
+        Self {
+            salt: order.salt,
+            maker: order.maker,
+            signer: order.signer,
+            taker: order.taker,
+            tokenId: order.tokenId,
+            makerAmount: order.makerAmount,
+            takerAmount: order.takerAmount,
+            expiration: order.expiration,
+            nonce: order.nonce,
+            feeRateBps: order.feeRateBps,
+            side: order.side,
+            signatureType: order.signatureType,
+            signature: Bytes::copy_from_slice(&signed.signature.as_bytes()),
+        }
+    }
+}
+
+/// Client for interacting with the CTF Exchange or Neg Risk CTF Exchange contract.
+///
+/// Transactions are sent from the provider's wallet, which must be the maker of the orders being
+/// cancelled. For proxy and Gnosis Safe wallets, the maker is the wallet contract rather than its
+/// signer, so these transactions have to be relayed through it.
+#[non_exhaustive]
+#[derive(Clone, Debug)]
+pub struct Client<P: Provider> {
+    contract: ICtfExchange::ICtfExchangeInstance<P>,
+    chain_id: ChainId,
+    neg_risk: bool,
+    provider: P,
+}
+
+impl<P: Provider + Clone> Client<P> {
+    /// Creates a new client for the CTF Exchange on the specified chain.
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` - An alloy provider instance
+    /// * `chain_id` - The chain ID (137 for Polygon mainnet, 80002 for Amoy testnet)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the contract configuration is not found for the given chain.
+    pub fn new(provider: P, chain_id: ChainId) -> Result<Self> {
+        Self::for_exchange(provider, chain_id, false)
+    }
+
+    /// Creates a new client for the Neg Risk CTF Exchange on the specified chain.
+    ///
+    /// Use this constructor to manage orders in negative risk markets, which are settled by a
+    /// separate exchange with its own nonces.
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` - An alloy provider instance
+    /// * `chain_id` - The chain ID (137 for Polygon mainnet, 80002 for Amoy testnet)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the contract configuration is not found for the given chain.
+    pub fn with_neg_risk(provider: P, chain_id: ChainId) -> Result<Self> {
+        Self::for_exchange(provider, chain_id, true)
+    }
+
+    fn for_exchange(provider: P, chain_id: ChainId, neg_risk: bool) -> Result<Self> {
+        let config = contract_config(chain_id, neg_risk).ok_or_else(|| {
+            ExchangeError::ContractCall(format!(
+                "Exchange contract configuration not found for chain ID {chain_id} and neg risk \
+                 {neg_risk}"
+            ))
+        })?;
+
+        let contract = ICtfExchange::new(config.exchange, provider.clone());
+
+        Ok(Self {
+            contract,
+            chain_id,
+            neg_risk,
+            provider,
+        })
+    }
+
+    /// Cancels a signed order on-chain, so that it can no longer be matched even if the CLOB
+    /// still has it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The transaction fails to send
+    /// - The transaction fails to be mined
+    /// - The wallet is not the order's maker
+    /// - The order was already filled or cancelled
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip(self, order), fields(
+            maker = %order.order.maker,
+            token_id = %order.order.tokenId
+        ))
+    )]
+    pub async fn cancel_order(&self, order: &SignedOrder) -> Result<CancelOrdersResponse> {
+        let pending_tx = self
+            .contract
+            .cancelOrder(order.into())
+            .send()
+            .await
+            .map_err(|e| {
+                ExchangeError::ContractCall(format!("Failed to send cancel transaction: {e}"))
+            })?;
+
+        let transaction_hash = *pending_tx.tx_hash();
+
+        let receipt = pending_tx.get_receipt().await.map_err(|e| {
+            ExchangeError::ContractCall(format!("Failed to get cancel receipt: {e}"))
+        })?;
+
+        Ok(CancelOrdersResponse {
+            transaction_hash,
+            block_number: receipt.block_number.ok_or_else(|| {
+                ExchangeError::ContractCall("Block number not available in receipt".to_owned())
+            })?,
+        })
+    }
+
+    /// Cancels a set of signed orders on-chain in a single transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The transaction fails to send
+    /// - The transaction fails to be mined
+    /// - The wallet is not the maker of every order
+    /// - Any of the orders was already filled or cancelled
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip(self, orders), fields(
+            orders_len = orders.len()
+        ))
+    )]
+    pub async fn cancel_orders(&self, orders: &[SignedOrder]) -> Result<CancelOrdersResponse> {
+        let pending_tx = self
+            .contract
+            .cancelOrders(orders.iter().map(Into::into).collect())
+            .send()
+            .await
+            .map_err(|e| {
+                ExchangeError::ContractCall(format!("Failed to send cancel transaction: {e}"))
+            })?;
+
+        let transaction_hash = *pending_tx.tx_hash();
+
+        let receipt = pending_tx.get_receipt().await.map_err(|e| {
+            ExchangeError::ContractCall(format!("Failed to get cancel receipt: {e}"))
+        })?;
+
+        Ok(CancelOrdersResponse {
+            transaction_hash,
+            block_number: receipt.block_number.ok_or_else(|| {
+                ExchangeError::ContractCall("Block number not available in receipt".to_owned())
+            })?,
+        })
+    }
+
+    /// Increments the wallet's nonce on this exchange, which invalidates every order it signed
+    /// with the previous nonce. Unlike cancelling through the CLOB, this works even if the CLOB is
+    /// unreachable.
+    ///
+    /// New orders have to be built with the incremented nonce, see [`nonce`](Self::nonce). The
+    /// CTF Exchange and Neg Risk CTF Exchange keep separate nonces.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The transaction fails to send
+    /// - The transaction fails to be mined
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip(self), fields(neg_risk = self.neg_risk))
+    )]
+    pub async fn increment_nonce(&self) -> Result<IncrementNonceResponse> {
+        let pending_tx = self.contract.incrementNonce().send().await.map_err(|e| {
+            ExchangeError::ContractCall(format!("Failed to send increment nonce transaction: {e}"))
+        })?;
+
+        let transaction_hash = *pending_tx.tx_hash();
+
+        let receipt = pending_tx.get_receipt().await.map_err(|e| {
+            ExchangeError::ContractCall(format!("Failed to get increment nonce receipt: {e}"))
+        })?;
+
+        Ok(IncrementNonceResponse {
+            transaction_hash,
+            block_number: receipt.block_number.ok_or_else(|| {
+                ExchangeError::ContractCall("Block number not available in receipt".to_owned())
+            })?,
+        })
+    }
+
+    /// Returns the current nonce of `maker` on this exchange, which orders must be signed with to
+    /// be valid.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the contract call fails, or if the nonce does not fit in a `u64`.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip(self), fields(maker = %maker))
+    )]
+    pub async fn nonce(&self, maker: Address) -> Result<NonceResponse> {
+        let nonce = self
+            .contract
+            .nonces(maker)
+            .call()
+            .await
+            .map_err(|e| ExchangeError::ContractCall(format!("Failed to get nonce: {e}")))?;
+
+        let nonce = u64::try_from(nonce).map_err(|e| {
+            ExchangeError::ContractCall(format!("Nonce {nonce} does not fit into u64: {e}"))
+        })?;
+
+        Ok(NonceResponse { nonce })
+    }
+
+    /// Returns whether `nonce` is the current nonce of `maker` on this exchange.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the contract call fails.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip(self), fields(maker = %maker, nonce = nonce))
+    )]
+    pub async fn is_valid_nonce(&self, maker: Address, nonce: u64) -> Result<bool> {
+        self.contract
+            .isValidNonce(maker, U256::from(nonce))
+            .call()
+            .await
+            .map_err(|e| {
+                ExchangeError::ContractCall(format!("Failed to check nonce validity: {e}")).into()
+            })
+    }
+
+    /// Returns the on-chain fill and cancellation status of `order`, looked up by its hash under
+    /// this exchange's domain (see [`Order::hash`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the contract call fails.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip(self, order), fields(
+            maker = %order.maker,
+            token_id = %order.tokenId
+        ))
+    )]
+    pub async fn order_status(&self, order: &Order) -> Result<OrderStatusResponse> {
+        let order_hash = order.hash(self.chain_id, self.neg_risk)?;
+
+        let status = self
+            .contract
+            .getOrderStatus(order_hash)
+            .call()
+            .await
+            .map_err(|e| ExchangeError::ContractCall(format!("Failed to get order status: {e}")))?;
+
+        // `remaining` is only set once the order is first matched, so a zero `remaining` is
+        // ambiguous for an order that is filled or cancelled
+        let filled = match (status.isFilledOrCancelled, status.remaining) {
+            (false, remaining) if remaining.is_zero() => Some(U256::ZERO),
+            (true, remaining) if remaining.is_zero() => None,
+            (_, remaining) => Some(order.makerAmount.saturating_sub(remaining)),
+        };
+
+        Ok(OrderStatusResponse {
+            order_hash,
+            is_filled_or_cancelled: status.isFilledOrCancelled,
+            remaining: status.remaining,
+            filled,
+        })
+    }
+
+    /// Returns whether this client targets the Neg Risk CTF Exchange.
+    #[must_use]
+    pub const fn neg_risk(&self) -> bool {
+        self.neg_risk
+    }
+
+    /// Returns a reference to the underlying provider.
+    #[must_use]
+    pub const fn provider(&self) -> &P {
+        &self.provider
+    }
+}
@@ -0,0 +1,27 @@
+//! Exchange-specific error types.
+
+use std::error::Error as StdError;
+use std::fmt;
+
+/// Exchange-specific errors.
+#[derive(Debug)]
+pub enum ExchangeError {
+    /// Contract call failed
+    ContractCall(String),
+}
+
+impl fmt::Display for ExchangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ContractCall(msg) => write!(f, "Exchange contract call failed: {msg}"),
+        }
+    }
+}
+
+impl StdError for ExchangeError {}
+
+impl From<ExchangeError> for crate::error::Error {
+    fn from(err: ExchangeError) -> Self {
+        crate::error::Error::with_source(crate::error::Kind::Internal, err)
+    }
+}
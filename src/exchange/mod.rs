@@ -0,0 +1,67 @@
+//! CTF Exchange client for on-chain order management.
+//!
+//! **Feature flag:** `exchange` (required to use this module, and enables `clob`)
+//!
+//! Polymarket orders are matched by the CLOB but settled by the CTF Exchange contract, or by the
+//! Neg Risk CTF Exchange for neg risk markets. The exchange is the final authority on whether an
+//! order can still be filled, so this module can invalidate orders even if the CLOB API is
+//! unreachable.
+//!
+//! # Features
+//!
+//! - **Cancellation**: Cancel signed orders on-chain
+//! - **Nonce Management**: Query the maker's nonce for
+//!   [`OrderBuilder::nonce`](crate::clob::order_builder::OrderBuilder::nonce), and increment it
+//!   to invalidate every order signed with the previous one
+//! - **Order Status**: Query whether an order was filled or cancelled, and its filled amount
+//!
+//! # Example
+//!
+//! ```ignore
+//! use polymarket_client_sdk::exchange::Client;
+//! use polymarket_client_sdk::POLYGON;
+//! use alloy::providers::ProviderBuilder;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! // Create a provider (requires a wallet for state-changing operations)
+//! let provider = ProviderBuilder::new()
+//!     .wallet(signer)
+//!     .connect("https://polygon-rpc.com")
+//!     .await?;
+//!
+//! let exchange = Client::new(provider, POLYGON)?;
+//!
+//! // Invalidate every open order signed with the current nonce
+//! let result = exchange.increment_nonce().await?;
+//! println!("Increment nonce tx: {}", result.transaction_hash);
+//!
+//! // Sign new orders with the incremented nonce
+//! let nonce = exchange.nonce(maker).await?.nonce;
+//! let order = clob_client
+//!     .limit_order()
+//!     .token_id(token_id)
+//!     .price(dec!(0.5))
+//!     .size(dec!(10))
+//!     .side(Side::Buy)
+//!     .nonce(nonce)
+//!     .build()
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Contract Addresses
+//!
+//! - CTF Exchange on Polygon Mainnet: `0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E`
+//! - Neg Risk CTF Exchange on Polygon Mainnet: `0xC5d563A36AE78145C45a50134d48A1215220f80a`
+//! - Polygon Amoy Testnet: Available in contract configuration
+//!
+//! # Resources
+//!
+//! - [CTF Exchange Source Code](https://github.com/Polymarket/ctf-exchange)
+
+pub mod client;
+mod error;
+pub mod types;
+
+pub use client::Client;
@@ -0,0 +1,7 @@
+//! Types for CTF Exchange operations.
+
+mod response;
+
+pub use response::{
+    CancelOrdersResponse, IncrementNonceResponse, NonceResponse, OrderStatusResponse,
+};
@@ -0,0 +1,54 @@
+//! Response types for CTF Exchange operations.
+
+use alloy::primitives::{B256, U256};
+use bon::Builder;
+
+/// Response from a cancel orders transaction.
+#[non_exhaustive]
+#[derive(Debug, Clone, Builder)]
+pub struct CancelOrdersResponse {
+    /// Transaction hash
+    pub transaction_hash: B256,
+    /// Block number where the transaction was mined
+    pub block_number: u64,
+}
+
+/// Response from an increment nonce transaction.
+#[non_exhaustive]
+#[derive(Debug, Clone, Builder)]
+pub struct IncrementNonceResponse {
+    /// Transaction hash
+    pub transaction_hash: B256,
+    /// Block number where the transaction was mined
+    pub block_number: u64,
+}
+
+/// Response from querying a maker's current nonce.
+#[non_exhaustive]
+#[derive(Debug, Clone, Builder)]
+pub struct NonceResponse {
+    /// The nonce orders must be signed with to be valid, which can be passed to
+    /// [`OrderBuilder::nonce`](crate::clob::order_builder::OrderBuilder::nonce)
+    pub nonce: u64,
+}
+
+impl From<NonceResponse> for u64 {
+    fn from(response: NonceResponse) -> Self {
+        response.nonce
+    }
+}
+
+/// Response from querying the on-chain status of an order.
+#[non_exhaustive]
+#[derive(Debug, Clone, Builder)]
+pub struct OrderStatusResponse {
+    /// The order hash the status is recorded under
+    pub order_hash: B256,
+    /// Whether the order was filled in full or cancelled on-chain
+    pub is_filled_or_cancelled: bool,
+    /// The maker amount left to fill, which is zero until the order is first matched
+    pub remaining: U256,
+    /// The maker amount filled so far. `None` when the order was either filled in full or
+    /// cancelled before any fill, which the exchange does not distinguish
+    pub filled: Option<U256>,
+}
@@ -28,6 +28,7 @@
 use std::future::Future;
 
 use async_stream::try_stream;
+use bon::Builder;
 use futures::Stream;
 use reqwest::{
     Client as ReqwestClient, Method,
@@ -51,6 +52,8 @@ use super::types::response::{
     SportsMarketTypesResponse, SportsMetadata, Tag, Team,
 };
 use crate::error::Error;
+use crate::rate_limit::RateLimiter;
+use crate::retry::RetryPolicy;
 use crate::{Result, ToQueryParams as _};
 
 const MAX_LIMIT: i32 = 500;
@@ -76,9 +79,24 @@ const MAX_LIMIT: i32 = 500;
 /// let client = Client::new("https://custom-api.example.com").unwrap();
 /// ```
 #[derive(Clone, Debug)]
+#[expect(
+    clippy::struct_field_names,
+    reason = "`client` is the underlying HTTP client rather than a reference to this type"
+)]
 pub struct Client {
     host: Url,
     client: ReqwestClient,
+    config: Config,
+}
+
+/// Configuration for [`Client`]
+#[derive(Clone, Debug, Default, Builder)]
+pub struct Config {
+    /// How failed requests are retried. By default, only read-only `GET` requests are retried.
+    #[builder(default)]
+    retry: RetryPolicy,
+    /// Client-side rate limiter applied before each request. Requests are not limited by default.
+    rate_limiter: Option<RateLimiter>,
 }
 
 impl Default for Client {
@@ -99,6 +117,15 @@ impl Client {
     ///
     /// Returns an error if the URL is invalid or the HTTP client cannot be created.
     pub fn new(host: &str) -> Result<Client> {
+        Self::with_config(host, Config::default())
+    }
+
+    /// Creates a new Gamma API client with a custom host URL and [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL is invalid or the HTTP client cannot be created.
+    pub fn with_config(host: &str, config: Config) -> Result<Client> {
         let mut headers = HeaderMap::new();
 
         headers.insert("User-Agent", HeaderValue::from_static("rs_clob_client"));
@@ -110,6 +137,7 @@ impl Client {
         Ok(Self {
             host: Url::parse(host)?,
             client,
+            config,
         })
     }
 
@@ -129,7 +157,14 @@ impl Client {
             .client
             .request(Method::GET, format!("{}{path}{query}", self.host))
             .build()?;
-        crate::request(&self.client, request, None).await
+        crate::request(
+            &self.client,
+            request,
+            None,
+            &self.config.retry,
+            self.config.rate_limiter.as_ref(),
+        )
+        .await
     }
 
     /// Performs a health check on the Gamma API.
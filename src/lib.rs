@@ -10,8 +10,24 @@ pub mod ctf;
 #[cfg(feature = "data")]
 pub mod data;
 pub mod error;
+#[cfg(feature = "exchange")]
+pub mod exchange;
 #[cfg(feature = "gamma")]
 pub mod gamma;
+#[cfg(any(
+    feature = "bridge",
+    feature = "clob",
+    feature = "data",
+    feature = "gamma"
+))]
+pub mod rate_limit;
+#[cfg(any(
+    feature = "bridge",
+    feature = "clob",
+    feature = "data",
+    feature = "gamma"
+))]
+pub mod retry;
 #[cfg(feature = "rtds")]
 pub mod rtds;
 pub(crate) mod serde_helpers;
@@ -41,6 +57,20 @@ use serde::Serialize;
 use serde::de::DeserializeOwned;
 
 use crate::error::Error;
+#[cfg(any(
+    feature = "bridge",
+    feature = "clob",
+    feature = "data",
+    feature = "gamma"
+))]
+use crate::rate_limit::{EndpointGroup, RateLimiter};
+#[cfg(any(
+    feature = "bridge",
+    feature = "clob",
+    feature = "data",
+    feature = "gamma"
+))]
+use crate::retry::RetryPolicy;
 use crate::types::{Address, address};
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -244,7 +274,7 @@ impl<T: Serialize> ToQueryParams for T {}
     feature = "tracing",
     tracing::instrument(
         level = "debug",
-        skip(client, request, headers),
+        skip(client, request, headers, retry, limiter),
         fields(
             method = %request.method(),
             path = request.url().path(),
@@ -256,15 +286,58 @@ async fn request<Response: DeserializeOwned>(
     client: &reqwest::Client,
     mut request: Request,
     headers: Option<HeaderMap>,
+    retry: &RetryPolicy,
+    limiter: Option<&RateLimiter>,
 ) -> Result<Response> {
     let method = request.method().clone();
     let path = request.url().path().to_owned();
+    let group = EndpointGroup::of(&method, &path);
 
     if let Some(h) = headers {
         *request.headers_mut() = h;
     }
 
-    let response = client.execute(request).await?;
+    let mut attempt = 1;
+    let response = loop {
+        // Requests with streaming bodies cannot be cloned, and so are never retried
+        let retry_request = retry
+            .allows(&method, attempt)
+            .then(|| request.try_clone())
+            .flatten();
+
+        // Every attempt, including retries, counts against the rate limit
+        if let Some(limiter) = limiter {
+            limiter.acquire(group).await?;
+        }
+
+        let (delay, retry_request) = match client.execute(request).await {
+            Ok(response) => match retry_request {
+                Some(next) if retry.is_retryable(response.status()) => {
+                    (retry.backoff(attempt, Some(response.headers())), next)
+                }
+                _ => break response,
+            },
+            Err(e) => match retry_request {
+                Some(next) if e.is_connect() || e.is_timeout() => {
+                    (retry.backoff(attempt, None), next)
+                }
+                _ => return Err(e.into()),
+            },
+        };
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            method = %method,
+            path = %path,
+            attempt,
+            ?delay,
+            "Retrying API request"
+        );
+
+        tokio::time::sleep(delay).await;
+        request = retry_request;
+        attempt += 1;
+    };
     let status_code = response.status();
 
     #[cfg(feature = "tracing")]
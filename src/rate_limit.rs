@@ -0,0 +1,357 @@
+//! Client-side rate limiting for the HTTP clients.
+//!
+//! Polymarket throttles each group of endpoints separately, and answers requests over the limit
+//! with `429 Too Many Requests`. A [`RateLimiter`] set on the `clob`, `gamma` or `data` client's
+//! `Config` keeps a token bucket per [`EndpointGroup`], so that a burst of calls is spread out
+//! locally instead of being rejected by the server. When a bucket is empty, the call either waits
+//! for its turn or fails immediately with [`RateLimited`], depending on the limiter's [`OnLimit`].
+//!
+//! The limiter is shared by every clone of the `Config` it was built into, so a single limiter
+//! can also be shared between several clients to enforce one budget across all of them.
+
+use std::collections::HashMap;
+use std::error::Error as StdError;
+use std::fmt;
+use std::num::NonZeroU32;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+
+use bon::Builder;
+use reqwest::Method;
+use tokio::time::Instant;
+
+use crate::Result;
+use crate::error::{Error, Kind};
+
+/// Group of endpoints sharing one rate limit bucket.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EndpointGroup {
+    /// Order book and price reads, such as `/book`, `/midpoint`, `/price` and `/spread`, along
+    /// with their batch variants and the market parameters needed to build orders.
+    MarketData,
+    /// Order placement through `POST /order` and `POST /orders`.
+    Orders,
+    /// Order cancellation through `DELETE /order`, `DELETE /orders`, `/cancel-all` and
+    /// `/cancel-market-orders`.
+    Cancels,
+    /// Trade history reads, such as `/data/trades` and the Data API's `/trades`.
+    Trades,
+    /// Every other endpoint.
+    General,
+}
+
+impl EndpointGroup {
+    /// Returns the group that a request with `method` to `path` belongs to.
+    #[must_use]
+    pub fn of(method: &Method, path: &str) -> Self {
+        let path = path.trim_matches('/');
+
+        match (method, path) {
+            (&Method::POST, "order" | "orders") => Self::Orders,
+            (&Method::DELETE, "order" | "orders" | "cancel-all" | "cancel-market-orders") => {
+                Self::Cancels
+            }
+            (_, "trades" | "data/trades" | "builder/trades") => Self::Trades,
+            (
+                _,
+                "book" | "books" | "midpoint" | "midpoints" | "price" | "prices" | "spread"
+                | "spreads" | "last-trade-price" | "last-trades-prices" | "prices-history"
+                | "tick-size" | "neg-risk" | "fee-rate",
+            ) => Self::MarketData,
+            _ => Self::General,
+        }
+    }
+}
+
+impl fmt::Display for EndpointGroup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::MarketData => "market data",
+            Self::Orders => "orders",
+            Self::Cancels => "cancels",
+            Self::Trades => "trades",
+            Self::General => "general",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Number of requests allowed within a period of time.
+///
+/// The full allowance can be spent in a single burst, after which requests are admitted evenly
+/// across the period as the bucket refills.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Quota {
+    /// Maximum number of requests within `period`.
+    pub requests: NonZeroU32,
+    /// Window over which `requests` are allowed.
+    pub period: Duration,
+}
+
+impl Quota {
+    /// Allows `requests` requests every `period`.
+    #[must_use]
+    pub const fn new(requests: NonZeroU32, period: Duration) -> Self {
+        Self { requests, period }
+    }
+
+    /// Allows `requests` requests every second.
+    #[must_use]
+    pub const fn per_second(requests: NonZeroU32) -> Self {
+        Self::new(requests, Duration::from_secs(1))
+    }
+
+    /// Time it takes for the bucket to refill by a single request.
+    fn interval(&self) -> Duration {
+        self.period / self.requests.get()
+    }
+}
+
+/// What a [`RateLimiter`] does with a request that exceeds its group's [`Quota`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OnLimit {
+    /// Wait until the request fits within the quota, then send it.
+    #[default]
+    Queue,
+    /// Return a [`RateLimited`] error without sending the request.
+    FailFast,
+}
+
+/// Token bucket rate limiter with a separate [`Quota`] per [`EndpointGroup`].
+///
+/// Groups without a quota are not limited.
+///
+/// # Example
+///
+/// ```
+/// use std::num::NonZeroU32;
+/// use std::time::Duration;
+///
+/// use polymarket_client_sdk::rate_limit::{OnLimit, Quota, RateLimiter};
+///
+/// let limiter = RateLimiter::builder()
+///     .market_data(Quota::new(NonZeroU32::new(150).unwrap(), Duration::from_secs(10)))
+///     .orders(Quota::per_second(NonZeroU32::new(50).unwrap()))
+///     .on_limit(OnLimit::FailFast)
+///     .build();
+/// ```
+#[non_exhaustive]
+#[derive(Clone, Debug, Builder)]
+pub struct RateLimiter {
+    /// Quota for [`EndpointGroup::MarketData`].
+    market_data: Option<Quota>,
+    /// Quota for [`EndpointGroup::Orders`].
+    orders: Option<Quota>,
+    /// Quota for [`EndpointGroup::Cancels`].
+    cancels: Option<Quota>,
+    /// Quota for [`EndpointGroup::Trades`].
+    trades: Option<Quota>,
+    /// Quota for [`EndpointGroup::General`].
+    general: Option<Quota>,
+    /// What to do with requests over quota. Defaults to [`OnLimit::Queue`].
+    #[builder(default)]
+    on_limit: OnLimit,
+    /// Earliest time at which each group's bucket is full again, shared between clones.
+    #[builder(skip)]
+    buckets: Arc<Mutex<HashMap<EndpointGroup, Instant>>>,
+}
+
+impl RateLimiter {
+    /// Returns the [`Quota`] configured for `group`, if any.
+    #[must_use]
+    pub fn quota(&self, group: EndpointGroup) -> Option<Quota> {
+        match group {
+            EndpointGroup::MarketData => self.market_data,
+            EndpointGroup::Orders => self.orders,
+            EndpointGroup::Cancels => self.cancels,
+            EndpointGroup::Trades => self.trades,
+            EndpointGroup::General => self.general,
+        }
+    }
+
+    /// Takes a request from `group`'s bucket, waiting for it to refill if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RateLimited`] if the bucket is empty and the limiter is configured with
+    /// [`OnLimit::FailFast`].
+    pub async fn acquire(&self, group: EndpointGroup) -> Result<()> {
+        let Some(quota) = self.quota(group) else {
+            return Ok(());
+        };
+
+        let delay = self.reserve(group, quota)?;
+        if !delay.is_zero() {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(%group, ?delay, "Waiting for rate limit");
+
+            tokio::time::sleep(delay).await;
+        }
+
+        Ok(())
+    }
+
+    /// Reserves a slot in `group`'s bucket and returns how long to wait before using it.
+    ///
+    /// This follows the generic cell rate algorithm: rather than counting tokens, each bucket
+    /// stores the time at which it will be full again. A request fits as long as that time is no
+    /// further than one `period` ahead, and moves it forward by one interval.
+    fn reserve(&self, group: EndpointGroup, quota: Quota) -> Result<Duration> {
+        let interval = quota.interval();
+        let tolerance = quota.period.saturating_sub(interval);
+        let now = Instant::now();
+
+        let mut buckets = self.buckets.lock().unwrap_or_else(PoisonError::into_inner);
+        let full_at = buckets.get(&group).copied().unwrap_or(now).max(now);
+        let delay = full_at.duration_since(now).saturating_sub(tolerance);
+
+        if !delay.is_zero() && self.on_limit == OnLimit::FailFast {
+            return Err(RateLimited {
+                group,
+                retry_after: delay,
+            }
+            .into());
+        }
+
+        buckets.insert(group, full_at + interval);
+        Ok(delay)
+    }
+}
+
+/// Error returned when a request exceeds its [`EndpointGroup`]'s [`Quota`] and the
+/// [`RateLimiter`] is configured with [`OnLimit::FailFast`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy)]
+pub struct RateLimited {
+    /// Group whose quota was exceeded.
+    pub group: EndpointGroup,
+    /// How long until the request would fit within the quota.
+    pub retry_after: Duration,
+}
+
+impl fmt::Display for RateLimited {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} rate limit exceeded, retry after {:?}",
+            self.group, self.retry_after
+        )
+    }
+}
+
+impl StdError for RateLimited {}
+
+impl From<RateLimited> for Error {
+    fn from(err: RateLimited) -> Self {
+        Error::with_source(Kind::RateLimit, err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TWO: NonZeroU32 = NonZeroU32::new(2).unwrap();
+
+    #[test]
+    fn endpoints_should_map_to_groups() {
+        assert_eq!(
+            EndpointGroup::of(&Method::GET, "/book"),
+            EndpointGroup::MarketData
+        );
+        assert_eq!(
+            EndpointGroup::of(&Method::POST, "/midpoints"),
+            EndpointGroup::MarketData
+        );
+        assert_eq!(
+            EndpointGroup::of(&Method::POST, "/orders"),
+            EndpointGroup::Orders
+        );
+        assert_eq!(
+            EndpointGroup::of(&Method::GET, "/data/order/0x1"),
+            EndpointGroup::General
+        );
+        assert_eq!(
+            EndpointGroup::of(&Method::DELETE, "/order"),
+            EndpointGroup::Cancels
+        );
+        assert_eq!(
+            EndpointGroup::of(&Method::DELETE, "/cancel-all"),
+            EndpointGroup::Cancels
+        );
+        assert_eq!(
+            EndpointGroup::of(&Method::GET, "/data/trades"),
+            EndpointGroup::Trades
+        );
+        assert_eq!(
+            EndpointGroup::of(&Method::GET, "/trades"),
+            EndpointGroup::Trades
+        );
+        assert_eq!(
+            EndpointGroup::of(&Method::GET, "/markets"),
+            EndpointGroup::General
+        );
+    }
+
+    #[test]
+    fn fail_fast_should_reject_requests_over_quota() {
+        let limiter = RateLimiter::builder()
+            .orders(Quota::new(TWO, Duration::from_secs(60)))
+            .on_limit(OnLimit::FailFast)
+            .build();
+        let quota = limiter.quota(EndpointGroup::Orders).unwrap();
+
+        limiter.reserve(EndpointGroup::Orders, quota).unwrap();
+        limiter.reserve(EndpointGroup::Orders, quota).unwrap();
+
+        let err = limiter.reserve(EndpointGroup::Orders, quota).unwrap_err();
+        let limited = err.downcast_ref::<RateLimited>().unwrap();
+        assert_eq!(err.kind(), Kind::RateLimit);
+        assert_eq!(limited.group, EndpointGroup::Orders);
+        assert!(
+            limited.retry_after > Duration::from_secs(29)
+                && limited.retry_after <= Duration::from_secs(30),
+            "unexpected retry_after {:?}",
+            limited.retry_after
+        );
+    }
+
+    #[test]
+    fn groups_should_not_share_buckets() {
+        let limiter = RateLimiter::builder()
+            .orders(Quota::new(TWO, Duration::from_secs(60)))
+            .cancels(Quota::new(TWO, Duration::from_secs(60)))
+            .on_limit(OnLimit::FailFast)
+            .build();
+        let clone = limiter.clone();
+        let quota = limiter.quota(EndpointGroup::Orders).unwrap();
+
+        limiter.reserve(EndpointGroup::Orders, quota).unwrap();
+        clone.reserve(EndpointGroup::Orders, quota).unwrap();
+        // Clones share buckets, so the third order is over quota
+        limiter.reserve(EndpointGroup::Orders, quota).unwrap_err();
+        limiter.reserve(EndpointGroup::Cancels, quota).unwrap();
+    }
+
+    #[tokio::test]
+    async fn queue_should_delay_requests_over_quota() -> Result<()> {
+        let limiter = RateLimiter::builder()
+            .market_data(Quota::new(TWO, Duration::from_millis(200)))
+            .build();
+
+        let start = Instant::now();
+        for _ in 0..3 {
+            limiter.acquire(EndpointGroup::MarketData).await?;
+        }
+        assert!(start.elapsed() >= Duration::from_millis(100));
+
+        let start = Instant::now();
+        limiter.acquire(EndpointGroup::General).await?;
+        assert!(start.elapsed() < Duration::from_millis(50));
+
+        Ok(())
+    }
+}
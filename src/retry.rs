@@ -0,0 +1,250 @@
+//! Retry policy shared by the HTTP clients.
+//!
+//! Every HTTP client (`clob`, `gamma`, `data` and `bridge`) carries a [`RetryPolicy`] on its
+//! `Config`. By default, read-only `GET` requests that fail with a throttling or server error, or
+//! that could not reach the server, are retried up to three times with exponential backoff.
+//! Requests with any other method, such as placing or cancelling orders, are only retried when
+//! [`retry_non_idempotent`](RetryPolicyBuilder::retry_non_idempotent) is set, since the server may
+//! have acted on a request whose response was lost.
+
+#![expect(
+    clippy::module_name_repetitions,
+    reason = "`RetryPolicy` reads better than `Policy` at the call sites that import it"
+)]
+
+use std::time::Duration;
+
+use bon::Builder;
+use chrono::{DateTime, Utc};
+use rand::RngExt as _;
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::{Method, StatusCode};
+
+/// Policy for retrying failed HTTP requests.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use polymarket_client_sdk::error::StatusCode;
+/// use polymarket_client_sdk::retry::RetryPolicy;
+///
+/// let policy = RetryPolicy::builder()
+///     .max_attempts(5)
+///     .initial_backoff(Duration::from_millis(100))
+///     .retryable_statuses(vec![StatusCode::TOO_MANY_REQUESTS])
+///     .build();
+/// ```
+#[non_exhaustive]
+#[derive(Clone, Debug, Builder)]
+pub struct RetryPolicy {
+    /// Total number of attempts per request, including the first. A value of `1` disables
+    /// retries. Defaults to three (3).
+    #[builder(default = 3)]
+    max_attempts: u32,
+    /// Delay before the first retry, doubled for every retry after it. Defaults to 200ms.
+    #[builder(default = Duration::from_millis(200))]
+    initial_backoff: Duration,
+    /// Upper bound for any single delay, including one requested through `Retry-After`.
+    /// Defaults to ten (10) seconds.
+    #[builder(default = Duration::from_secs(10))]
+    max_backoff: Duration,
+    /// Whether to randomize each delay between half and all of its computed value, so that many
+    /// clients throttled at once do not retry in lockstep. Defaults to `true`.
+    #[builder(default = true)]
+    jitter: bool,
+    /// Whether to wait for the delay requested by the server's `Retry-After` header instead of
+    /// the computed backoff. Defaults to `true`.
+    #[builder(default = true)]
+    respect_retry_after: bool,
+    /// Response statuses that are retried. Defaults to `408`, `429`, `500`, `502`, `503` and
+    /// `504`.
+    #[builder(default = DEFAULT_RETRYABLE_STATUSES.to_vec())]
+    retryable_statuses: Vec<StatusCode>,
+    /// Whether requests other than `GET` and `HEAD` are retried. This includes placing and
+    /// cancelling orders, so only enable it if resubmitting those is acceptable. Defaults to
+    /// `false`.
+    #[builder(default)]
+    retry_non_idempotent: bool,
+}
+
+const DEFAULT_RETRYABLE_STATUSES: [StatusCode; 6] = [
+    StatusCode::REQUEST_TIMEOUT,
+    StatusCode::TOO_MANY_REQUESTS,
+    StatusCode::INTERNAL_SERVER_ERROR,
+    StatusCode::BAD_GATEWAY,
+    StatusCode::SERVICE_UNAVAILABLE,
+    StatusCode::GATEWAY_TIMEOUT,
+];
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries.
+    #[must_use]
+    pub fn disabled() -> Self {
+        Self::builder().max_attempts(1).build()
+    }
+
+    /// Total number of attempts per request, including the first.
+    #[must_use]
+    pub const fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Returns `true` if requests with `method` may be attempted again after `attempt` attempts.
+    pub(crate) fn allows(&self, method: &Method, attempt: u32) -> bool {
+        let idempotent = matches!(*method, Method::GET | Method::HEAD);
+        attempt < self.max_attempts && (idempotent || self.retry_non_idempotent)
+    }
+
+    pub(crate) fn is_retryable(&self, status: StatusCode) -> bool {
+        self.retryable_statuses.contains(&status)
+    }
+
+    /// Delay to wait after the `attempt`-th attempt failed.
+    pub(crate) fn backoff(&self, attempt: u32, headers: Option<&HeaderMap>) -> Duration {
+        if self.respect_retry_after
+            && let Some(delay) = headers.and_then(retry_after)
+        {
+            return delay.min(self.max_backoff);
+        }
+
+        let factor = 2_u32.saturating_pow(attempt.saturating_sub(1));
+        let delay = self
+            .initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff);
+
+        if self.jitter {
+            let half = delay / 2;
+            half + rand::rng().random_range(Duration::ZERO..=delay.saturating_sub(half))
+        } else {
+            delay
+        }
+    }
+}
+
+/// Parses a `Retry-After` header given either in seconds or as an HTTP date.
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let at = DateTime::parse_from_rfc2822(value)
+        .ok()?
+        .with_timezone(&Utc);
+    (at - Utc::now()).to_std().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::HeaderValue;
+
+    use super::*;
+
+    #[test]
+    fn default_policy_should_only_retry_reads() {
+        let policy = RetryPolicy::default();
+
+        assert!(policy.allows(&Method::GET, 1), "GET should be retried");
+        assert!(policy.allows(&Method::GET, 2), "GET should be retried");
+        assert!(!policy.allows(&Method::GET, 3), "attempts are capped");
+        assert!(
+            !policy.allows(&Method::POST, 1),
+            "POST should not be retried"
+        );
+        assert!(
+            !policy.allows(&Method::DELETE, 1),
+            "DELETE should not be retried"
+        );
+    }
+
+    #[test]
+    fn opt_in_should_retry_mutations() {
+        let policy = RetryPolicy::builder().retry_non_idempotent(true).build();
+
+        assert!(policy.allows(&Method::POST, 1), "POST should be retried");
+        assert!(
+            policy.allows(&Method::DELETE, 1),
+            "DELETE should be retried"
+        );
+    }
+
+    #[test]
+    fn disabled_policy_should_never_retry() {
+        let policy = RetryPolicy::disabled();
+
+        assert!(!policy.allows(&Method::GET, 1), "nothing should be retried");
+    }
+
+    #[test]
+    fn backoff_should_grow_exponentially_up_to_max() {
+        let policy = RetryPolicy::builder()
+            .initial_backoff(Duration::from_millis(100))
+            .max_backoff(Duration::from_millis(350))
+            .jitter(false)
+            .build();
+
+        assert_eq!(policy.backoff(1, None), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2, None), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3, None), Duration::from_millis(350));
+        assert_eq!(policy.backoff(40, None), Duration::from_millis(350));
+    }
+
+    #[test]
+    fn backoff_with_jitter_should_stay_within_bounds() {
+        let policy = RetryPolicy::builder()
+            .initial_backoff(Duration::from_millis(100))
+            .build();
+
+        for _ in 0..100 {
+            let delay = policy.backoff(2, None);
+            assert!(
+                (Duration::from_millis(100)..=Duration::from_millis(200)).contains(&delay),
+                "unexpected delay {delay:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn backoff_should_honor_retry_after() {
+        let policy = RetryPolicy::builder().jitter(false).build();
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("3"));
+
+        assert_eq!(policy.backoff(1, Some(&headers)), Duration::from_secs(3));
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("3600"));
+        assert_eq!(policy.backoff(1, Some(&headers)), Duration::from_secs(10));
+
+        let policy = RetryPolicy::builder()
+            .jitter(false)
+            .respect_retry_after(false)
+            .build();
+        assert_eq!(
+            policy.backoff(1, Some(&headers)),
+            Duration::from_millis(200)
+        );
+    }
+
+    #[test]
+    fn retry_after_should_parse_http_dates() {
+        let at = Utc::now() + chrono::TimeDelta::seconds(30);
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, at.to_rfc2822().parse().unwrap());
+
+        let delay = retry_after(&headers).unwrap();
+        assert!(
+            delay <= Duration::from_secs(30) && delay >= Duration::from_secs(28),
+            "unexpected delay {delay:?}"
+        );
+    }
+}
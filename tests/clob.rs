@@ -23,7 +23,7 @@ use uuid::Uuid;
 
 use crate::common::{
     POLY_ADDRESS, POLY_API_KEY, POLY_PASSPHRASE, PRIVATE_KEY, create_authenticated,
-    ensure_requirements, token_1, token_2,
+    create_authenticated_with, ensure_requirements, token_1, token_2,
 };
 
 mod unauthenticated {
@@ -611,6 +611,10 @@ mod unauthenticated {
             expected.hash()?,
             "03196cc4f520d81c0748b4f042f2096441d160e8ef5eac4f0378cb5bd80fd183"
         );
+        assert_eq!(
+            expected.summary_hash()?,
+            "6e29d46a47d8b85b4027e9dc144e0e4af9ada198"
+        );
         mock.assert();
 
         Ok(())
@@ -1383,20 +1387,21 @@ mod authenticated {
     use alloy::signers::local::LocalSigner;
     use chrono::NaiveDate;
     use httpmock::Method::{DELETE, GET, POST};
+    use polymarket_client_sdk::clob::client::POST_ORDERS_CHUNK_SIZE;
     use polymarket_client_sdk::clob::types::request::{
         BalanceAllowanceRequest, CancelMarketOrderRequest, DeleteNotificationsRequest,
         OrdersRequest, TradesRequest, UserRewardsEarningRequest,
     };
     use polymarket_client_sdk::clob::types::response::{
         ApiKeysResponse, BalanceAllowanceResponse, BanStatusResponse, CancelOrdersResponse,
-        CurrentRewardResponse, Earning, HeartbeatResponse, MakerOrder, MarketRewardResponse,
-        MarketRewardsConfig, NotificationPayload, NotificationResponse, OpenOrderResponse,
-        OrderScoringResponse, Page, PostOrderResponse, RewardsConfig, Token,
+        CancelStatus, CurrentRewardResponse, Earning, HeartbeatResponse, MakerOrder,
+        MarketRewardResponse, MarketRewardsConfig, NotificationPayload, NotificationResponse,
+        OpenOrderResponse, OrderScoringResponse, Page, PostOrderResponse, RewardsConfig, Token,
         TotalUserEarningResponse, TradeResponse, UserEarningResponse, UserRewardsEarningResponse,
     };
     use polymarket_client_sdk::clob::types::{
-        AssetType, OrderStatusType, OrderType, Side, SignableOrder, SignedOrder, TickSize,
-        TradeStatusType, TraderSide,
+        AssetType, OrderStatusType, OrderType, RejectionReason, Side, SignableOrder, SignedOrder,
+        TickSize, TradeStatusType, TraderSide,
     };
     #[cfg(feature = "heartbeats")]
     use polymarket_client_sdk::error::Synchronization;
@@ -1540,6 +1545,7 @@ mod authenticated {
             .order(signable_order.order)
             .order_type(OrderType::GTC)
             .post_only(false)
+            .neg_risk(false)
             .signature(Signature::new(
                 U256::from_str(
                     "67938079796141091828598175285011746318151402208362009718761031231176791189384",
@@ -1674,6 +1680,68 @@ mod authenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn post_order_rejection_should_be_typed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/order");
+            then.status(StatusCode::OK).json_body(json!({
+                "errorMsg": "invalid post-only order: order crosses book",
+                "makingAmount": "",
+                "orderID": "",
+                "status": "unmatched",
+                "success": false,
+                "takingAmount": ""
+            }));
+        });
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let signed_order = client.sign(&signer, SignableOrder::default()).await?;
+        let response = client.post_order(signed_order).await?;
+
+        assert_eq!(
+            response.rejection_reason(),
+            Some(RejectionReason::PostOnlyCrossing)
+        );
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn post_order_failure_should_be_typed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/order");
+            then.status(StatusCode::BAD_REQUEST)
+                .json_body(json!({ "error": "not enough balance / allowance" }));
+        });
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let signed_order = client.sign(&signer, SignableOrder::default()).await?;
+        let err = client.post_order(signed_order).await.unwrap_err();
+
+        assert_eq!(
+            err.rejection_reason(),
+            Some(RejectionReason::InsufficientBalance)
+        );
+        assert_eq!(
+            RejectionReason::from_error(&err),
+            Some(RejectionReason::InsufficientBalance)
+        );
+        mock.assert();
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn order_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -1917,6 +1985,168 @@ mod authenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn post_orders_chunked_should_pair_each_order_with_its_response() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        // Every chunk is answered with a full chunk of responses, which the trailing chunk of two
+        // orders does not match
+        let responses: Vec<_> = (0..POST_ORDERS_CHUNK_SIZE)
+            .map(|i| {
+                json!({
+                    "errorMsg": if i == 0 { "not enough balance" } else { "" },
+                    "makingAmount": "",
+                    "orderID": format!("0x{i:064x}"),
+                    "status": "live",
+                    "success": i != 0,
+                    "takingAmount": ""
+                })
+            })
+            .collect();
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/orders");
+            then.status(StatusCode::OK).json_body(json!(responses));
+        });
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let mut orders = Vec::new();
+        for _ in 0..POST_ORDERS_CHUNK_SIZE + 2 {
+            orders.push(client.sign(&signer, SignableOrder::default()).await?);
+        }
+        let results = client.post_orders_chunked(orders).await;
+
+        assert_eq!(results.len(), POST_ORDERS_CHUNK_SIZE + 2);
+        let rejected = results[0].result.as_ref().unwrap();
+        assert!(!rejected.success);
+        assert_eq!(rejected.error_msg.as_deref(), Some("not enough balance"));
+        assert_eq!(
+            results[14].result.as_ref().unwrap().order_id,
+            format!("0x{:064x}", 14)
+        );
+        for result in &results[POST_ORDERS_CHUNK_SIZE..] {
+            let err = result.result.as_ref().unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "Validation: invalid: Expected 2 responses to posted orders, got 15"
+            );
+        }
+        mock.assert_calls(2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn post_orders_chunked_should_use_configured_chunk_size() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let config = Config::builder()
+            .use_server_time(true)
+            .post_orders_chunk_size(2)
+            .build();
+        let client = create_authenticated_with(&server, config).await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        let response = |id: u64| {
+            json!({
+                "errorMsg": "",
+                "makingAmount": "",
+                "orderID": format!("0x{id:064x}"),
+                "status": "live",
+                "success": true,
+                "takingAmount": ""
+            })
+        };
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/orders");
+            then.status(StatusCode::OK)
+                .json_body(json!([response(0), response(1)]));
+        });
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let mut orders = Vec::new();
+        for _ in 0..4 {
+            orders.push(client.sign(&signer, SignableOrder::default()).await?);
+        }
+        let results = client.post_orders_chunked(orders).await;
+
+        assert_eq!(results.len(), 4);
+        assert!(results.iter().all(|result| result.result.is_ok()));
+        mock.assert_calls(2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cancel_orders_chunked_should_pair_each_order_id_with_its_status() -> anyhow::Result<()>
+    {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(DELETE)
+                .path("/orders")
+                .json_body(json!(["1", "2", "3"]));
+            then.status(StatusCode::OK).json_body(json!({
+                "canceled": ["1"],
+                "not_canceled": { "2": "the order is already canceled" }
+            }));
+        });
+
+        let results = client.cancel_orders_chunked(&["1", "2", "3"]).await;
+
+        let inputs: Vec<_> = results.iter().map(|result| result.input.as_str()).collect();
+        assert_eq!(inputs, ["1", "2", "3"]);
+        assert_eq!(results[0].result.as_ref().unwrap(), &CancelStatus::Canceled);
+        assert_eq!(
+            results[1].result.as_ref().unwrap(),
+            &CancelStatus::NotCanceled {
+                reason: "the order is already canceled".to_owned()
+            }
+        );
+        results[2].result.as_ref().unwrap_err();
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cancel_orders_chunked_should_use_configured_chunk_size() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let config = Config::builder()
+            .use_server_time(true)
+            .cancel_orders_chunk_size(2)
+            .build();
+        let client = create_authenticated_with(&server, config).await?;
+
+        let first = server.mock(|when, then| {
+            when.method(DELETE)
+                .path("/orders")
+                .json_body(json!(["1", "2"]));
+            then.status(StatusCode::OK)
+                .json_body(json!({ "canceled": ["1", "2"], "not_canceled": {} }));
+        });
+        let second = server.mock(|when, then| {
+            when.method(DELETE).path("/orders").json_body(json!(["3"]));
+            then.status(StatusCode::OK)
+                .json_body(json!({ "canceled": ["3"], "not_canceled": {} }));
+        });
+
+        let results = client.cancel_orders_chunked(&["1", "2", "3"]).await;
+
+        assert!(
+            results
+                .iter()
+                .all(|result| result.result.as_ref().unwrap() == &CancelStatus::Canceled)
+        );
+        first.assert();
+        second.assert();
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn cancel_all_orders_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -3240,3 +3470,1549 @@ mod builder_authenticated {
         Ok(())
     }
 }
+
+mod retry {
+    use std::time::Duration;
+
+    use httpmock::Method::{GET, POST};
+    use polymarket_client_sdk::clob::types::request::OrderBookSummaryRequest;
+    use polymarket_client_sdk::error::Status;
+    use polymarket_client_sdk::retry::RetryPolicy;
+
+    use super::*;
+    use crate::common::token_1;
+
+    fn client(server: &MockServer, retry: RetryPolicy) -> anyhow::Result<Client> {
+        let config = Config::builder().retry(retry).build();
+        Ok(Client::new(&server.base_url(), config)?)
+    }
+
+    fn fast() -> RetryPolicy {
+        RetryPolicy::builder()
+            .initial_backoff(Duration::from_millis(1))
+            .build()
+    }
+
+    #[tokio::test]
+    async fn get_should_retry_retryable_status() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = client(&server, fast())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/");
+            then.status(StatusCode::SERVICE_UNAVAILABLE)
+                .header("retry-after", "0");
+        });
+
+        let err = client.ok().await.unwrap_err();
+        let status = err.downcast_ref::<Status>().unwrap();
+
+        assert_eq!(status.status_code, StatusCode::SERVICE_UNAVAILABLE);
+        mock.assert_calls(3);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_should_not_retry_other_status() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = client(&server, fast())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/");
+            then.status(StatusCode::BAD_REQUEST);
+        });
+
+        client.ok().await.unwrap_err();
+        mock.assert_calls(1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn post_should_not_retry_by_default() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = client(&server, fast())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/books");
+            then.status(StatusCode::TOO_MANY_REQUESTS);
+        });
+
+        let request = OrderBookSummaryRequest::builder()
+            .token_id(token_1())
+            .build();
+        client.order_books(&[request]).await.unwrap_err();
+        mock.assert_calls(1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn post_should_retry_when_opted_in() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let retry = RetryPolicy::builder()
+            .initial_backoff(Duration::from_millis(1))
+            .max_attempts(2)
+            .retry_non_idempotent(true)
+            .build();
+        let client = client(&server, retry)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/books")
+                .json_body(json!([{ "token_id": token_1().to_string() }]));
+            then.status(StatusCode::TOO_MANY_REQUESTS);
+        });
+
+        let request = OrderBookSummaryRequest::builder()
+            .token_id(token_1())
+            .build();
+        client.order_books(&[request]).await.unwrap_err();
+        mock.assert_calls(2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn disabled_policy_should_not_retry() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = client(&server, RetryPolicy::disabled())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/");
+            then.status(StatusCode::SERVICE_UNAVAILABLE);
+        });
+
+        client.ok().await.unwrap_err();
+        mock.assert_calls(1);
+
+        Ok(())
+    }
+}
+
+mod rate_limit {
+    use std::num::NonZeroU32;
+    use std::time::{Duration, Instant};
+
+    use httpmock::Method::GET;
+    use polymarket_client_sdk::clob::types::request::MidpointRequest;
+    use polymarket_client_sdk::error::Kind;
+    use polymarket_client_sdk::rate_limit::{
+        EndpointGroup, OnLimit, Quota, RateLimited, RateLimiter,
+    };
+
+    use super::*;
+    use crate::common::token_1;
+
+    fn client(server: &MockServer, limiter: RateLimiter) -> anyhow::Result<Client> {
+        let config = Config::builder().rate_limiter(limiter).build();
+        Ok(Client::new(&server.base_url(), config)?)
+    }
+
+    fn mock_midpoint(server: &MockServer) -> httpmock::Mock<'_> {
+        server.mock(|when, then| {
+            when.method(GET).path("/midpoint");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "mid": "0.5" }));
+        })
+    }
+
+    #[tokio::test]
+    async fn fail_fast_should_not_send_requests_over_quota() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let limiter = RateLimiter::builder()
+            .market_data(Quota::new(NonZeroU32::MIN, Duration::from_secs(60)))
+            .on_limit(OnLimit::FailFast)
+            .build();
+        let client = client(&server, limiter)?;
+        let mock = mock_midpoint(&server);
+        let ok = server.mock(|when, then| {
+            when.method(GET).path("/");
+            then.status(StatusCode::OK).body("\"OK\"");
+        });
+
+        let request = MidpointRequest::builder().token_id(token_1()).build();
+        client.midpoint(&request).await?;
+
+        let err = client.midpoint(&request).await.unwrap_err();
+        let limited = err.downcast_ref::<RateLimited>().unwrap();
+
+        assert_eq!(err.kind(), Kind::RateLimit);
+        assert_eq!(limited.group, EndpointGroup::MarketData);
+        mock.assert_calls(1);
+
+        // Other groups are not limited
+        client.ok().await?;
+        ok.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn queue_should_delay_requests_over_quota() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let limiter = RateLimiter::builder()
+            .market_data(Quota::new(NonZeroU32::MIN, Duration::from_millis(200)))
+            .build();
+        let client = client(&server, limiter)?;
+        let mock = mock_midpoint(&server);
+
+        let request = MidpointRequest::builder().token_id(token_1()).build();
+        let start = Instant::now();
+        client.midpoint(&request).await?;
+        client.midpoint(&request).await?;
+
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        mock.assert_calls(2);
+
+        Ok(())
+    }
+}
+
+mod traits {
+    use httpmock::Method::{DELETE, GET};
+    use polymarket_client_sdk::Result;
+    use polymarket_client_sdk::clob::traits::{MarketData, Trading};
+    use polymarket_client_sdk::clob::types::request::MidpointRequest;
+
+    use super::*;
+
+    /// Generic over the backend, as a downstream service would be.
+    async fn spawn_midpoint<M>(backend: M, token_id: U256) -> Result<Decimal>
+    where
+        M: MarketData + Send + Sync + 'static,
+    {
+        tokio::spawn(async move {
+            let request = MidpointRequest::builder().token_id(token_id).build();
+            Ok(backend.midpoint(&request).await?.mid)
+        })
+        .await
+        .unwrap()
+    }
+
+    async fn cancel_everything(backend: &impl Trading) -> Result<Vec<String>> {
+        Ok(backend.cancel_all_orders().await?.canceled)
+    }
+
+    #[tokio::test]
+    async fn market_data_should_be_implemented_by_every_client() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/midpoint")
+                .query_param("token_id", token_1().to_string());
+            then.status(StatusCode::OK)
+                .json_body(json!({ "mid": "0.5" }));
+        });
+
+        let client = Client::new(&server.base_url(), Config::default())?;
+        assert_eq!(spawn_midpoint(client, token_1()).await?, dec!(0.5));
+
+        let client = create_authenticated(&server).await?;
+        assert_eq!(spawn_midpoint(client, token_1()).await?, dec!(0.5));
+
+        mock.assert_calls(2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn trading_should_be_implemented_by_authenticated_clients() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        let mock = server.mock(|when, then| {
+            when.method(DELETE)
+                .path("/cancel-all")
+                .header(POLY_ADDRESS, client.address().to_string().to_lowercase());
+            then.status(StatusCode::OK).json_body(json!({
+                "canceled": ["1", "2"],
+                "notCanceled": {}
+            }));
+        });
+
+        assert_eq!(cancel_everything(&client).await?, vec!["1", "2"]);
+        mock.assert();
+
+        Ok(())
+    }
+}
+
+mod replace_order {
+    use alloy::signers::Signer as _;
+    use alloy::signers::local::LocalSigner;
+    use httpmock::Method::{DELETE, GET, POST};
+    use httpmock::Mock;
+    use polymarket_client_sdk::clob::types::request::ReplaceOrderRequest;
+    use polymarket_client_sdk::clob::types::{ReplaceFailed, ReplaceStrategy, TickSize};
+    use polymarket_client_sdk::error::Kind;
+
+    use super::*;
+
+    const REPLACEMENT_ID: &str =
+        "0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0";
+
+    /// Registers the original order, a resting buy of 10 @ 0.45 with 2.5 matched.
+    fn original(server: &MockServer) {
+        ensure_requirements(server, token_1(), TickSize::Hundredth);
+        server.mock(|when, then| {
+            when.method(GET).path("/data/order/1");
+            then.status(StatusCode::OK).json_body(json!({
+                "id": "1",
+                "status": "LIVE",
+                "owner": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+                "maker_address": "0x2222222222222222222222222222222222222222",
+                "market": "0x000000000000000000000000000000000000000000000000006d61726b657461",
+                "asset_id": token_1(),
+                "side": "buy",
+                "original_size": "10.0",
+                "size_matched": "2.5",
+                "price": "0.45",
+                "associate_trades": [],
+                "outcome": "YES",
+                "created_at": 1_705_322_096,
+                "expiration": "0",
+                "order_type": "GTC"
+            }));
+        });
+    }
+
+    fn cancel(server: &MockServer, canceled: bool) -> Mock<'_> {
+        server.mock(|when, then| {
+            when.method(DELETE)
+                .path("/order")
+                .json_body(json!({ "orderId": "1" }));
+            let body = if canceled {
+                json!({ "canceled": ["1"], "notCanceled": {} })
+            } else {
+                json!({ "canceled": [], "notCanceled": { "1": "order not found" } })
+            };
+            then.status(StatusCode::OK).json_body(body);
+        })
+    }
+
+    fn post(server: &MockServer, success: bool) -> Mock<'_> {
+        server.mock(|when, then| {
+            // 7.5 remaining shares at the new price of 0.46
+            when.method(POST)
+                .path("/order")
+                .body_includes(r#""makerAmount":"3450000""#)
+                .body_includes(r#""takerAmount":"7500000""#)
+                .body_includes(r#""orderType":"GTC""#);
+            then.status(StatusCode::OK).json_body(json!({
+                "errorMsg": if success { "" } else { "not enough balance / allowance" },
+                "makingAmount": "",
+                "orderID": if success { REPLACEMENT_ID } else { "" },
+                "status": "live",
+                "success": success,
+                "takingAmount": ""
+            }));
+        })
+    }
+
+    #[tokio::test]
+    async fn replace_order_should_cancel_then_post() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        original(&server);
+        let cancel = cancel(&server, true);
+        let post = post(&server, true);
+
+        let request = ReplaceOrderRequest::builder()
+            .order_id("1")
+            .price(dec!(0.46))
+            .build();
+        let response = client.replace_order(&signer, &request).await?;
+
+        assert_eq!(response.canceled.canceled, vec!["1"]);
+        assert_eq!(response.posted.order_id, REPLACEMENT_ID);
+        cancel.assert();
+        post.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn replace_order_should_report_cancel_without_post() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        original(&server);
+        cancel(&server, true);
+        post(&server, false);
+
+        let request = ReplaceOrderRequest::builder()
+            .order_id("1")
+            .price(dec!(0.46))
+            .build();
+        let err = client.replace_order(&signer, &request).await.unwrap_err();
+
+        assert_eq!(err.kind(), Kind::Status);
+        let Some(ReplaceFailed::NotPosted {
+            order_id,
+            canceled,
+            reason,
+            source: None,
+            ..
+        }) = err.downcast_ref::<ReplaceFailed>()
+        else {
+            panic!("unexpected error: {err}");
+        };
+        assert_eq!(order_id, "1");
+        assert!(canceled);
+        assert_eq!(reason, "not enough balance / allowance");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn replace_order_should_post_first_and_report_live_replacement() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        original(&server);
+        let cancel = cancel(&server, false);
+        let post = post(&server, true);
+
+        let request = ReplaceOrderRequest::builder()
+            .order_id("1")
+            .price(dec!(0.46))
+            .strategy(ReplaceStrategy::PostFirst)
+            .build();
+        let err = client.replace_order(&signer, &request).await.unwrap_err();
+
+        let Some(ReplaceFailed::NotCanceled {
+            reason,
+            replacement: Some(replacement),
+            ..
+        }) = err.downcast_ref::<ReplaceFailed>()
+        else {
+            panic!("unexpected error: {err}");
+        };
+        assert_eq!(reason, "order not found");
+        assert_eq!(replacement.order_id, REPLACEMENT_ID);
+        post.assert();
+        cancel.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn replace_order_should_carry_post_only() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        original(&server);
+        cancel(&server, true);
+        let post = server.mock(|when, then| {
+            when.method(POST)
+                .path("/order")
+                .body_includes(r#""postOnly":true"#);
+            then.status(StatusCode::OK).json_body(json!({
+                "errorMsg": "",
+                "makingAmount": "",
+                "orderID": REPLACEMENT_ID,
+                "status": "live",
+                "success": true,
+                "takingAmount": ""
+            }));
+        });
+
+        let request = ReplaceOrderRequest::builder()
+            .order_id("1")
+            .price(dec!(0.46))
+            .post_only(true)
+            .build();
+        client.replace_order(&signer, &request).await?;
+
+        post.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn replace_order_should_keep_request_error_as_source() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        original(&server);
+        server.mock(|when, then| {
+            when.method(DELETE).path("/order");
+            then.status(StatusCode::BAD_REQUEST)
+                .json_body(json!({ "error": "invalid order id" }));
+        });
+
+        let request = ReplaceOrderRequest::builder()
+            .order_id("1")
+            .price(dec!(0.46))
+            .build();
+        let err = client.replace_order(&signer, &request).await.unwrap_err();
+
+        let failed = err.downcast_ref::<ReplaceFailed>().unwrap();
+        let source = std::error::Error::source(failed)
+            .and_then(|source| source.downcast_ref::<polymarket_client_sdk::error::Error>())
+            .unwrap();
+        assert_eq!(source.kind(), Kind::Status);
+        assert!(source.to_string().contains("invalid order id"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn replace_order_without_changes_should_fail() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+
+        let request = ReplaceOrderRequest::builder().order_id("1").build();
+        let err = client.replace_order(&signer, &request).await.unwrap_err();
+
+        assert_eq!(err.kind(), Kind::Validation);
+
+        Ok(())
+    }
+}
+
+mod caches {
+    use std::time::Duration;
+
+    use httpmock::Method::GET;
+    use polymarket_client_sdk::clob::types::{CacheEntry, CacheSnapshot, MarketState, TickSize};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn exported_caches_should_round_trip_through_bytes() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let market_state = MarketState::builder()
+            .active(true)
+            .closed(false)
+            .enable_order_book(true)
+            .accepting_orders(true)
+            .minimum_order_size(dec!(5))
+            .build();
+        client.set_tick_size(token_1(), TickSize::Thousandth);
+        client.set_neg_risk(token_1(), true);
+        client.set_fee_rate_bps(token_1(), 25);
+        client.set_market_state(token_1(), market_state);
+
+        let snapshot = client.export_caches();
+        let restored = CacheSnapshot::from_bytes(&snapshot.to_bytes()?)?;
+        assert_eq!(restored, snapshot);
+
+        // A fresh client loaded from the snapshot answers without any HTTP calls
+        let client = Client::new(&server.base_url(), Config::default())?;
+        client.import_caches(restored);
+
+        assert_eq!(
+            client.tick_size(token_1()).await?.minimum_tick_size,
+            TickSize::Thousandth
+        );
+        assert!(client.neg_risk(token_1()).await?.neg_risk);
+        assert_eq!(client.fee_rate_bps(token_1()).await?.base_fee, 25);
+        assert_eq!(client.market_state(token_1()).await?, market_state);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn expired_entries_should_be_fetched_again() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let config = Config::builder().cache_ttl(Duration::from_secs(60)).build();
+        let client = Client::new(&server.base_url(), config)?;
+
+        let mut stale = CacheEntry::new(TickSize::Tenth);
+        stale.cached_at = Utc::now() - chrono::Duration::minutes(5);
+        let mut snapshot = CacheSnapshot::default();
+        snapshot.tick_sizes.insert(token_1(), stale);
+        snapshot
+            .tick_sizes
+            .insert(token_2(), CacheEntry::new(TickSize::Hundredth));
+        client.import_caches(snapshot);
+
+        assert_eq!(client.export_caches().tick_sizes.len(), 1);
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/tick-size")
+                .query_param("token_id", token_1().to_string());
+            then.status(StatusCode::OK)
+                .json_body(json!({ "minimum_tick_size": "0.001" }));
+        });
+
+        assert_eq!(
+            client.tick_size(token_1()).await?.minimum_tick_size,
+            TickSize::Thousandth
+        );
+        assert_eq!(
+            client.tick_size(token_2()).await?.minimum_tick_size,
+            TickSize::Hundredth
+        );
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn warm_caches_should_fetch_every_token_once() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let tick_size = server.mock(|when, then| {
+            when.method(GET).path("/tick-size");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "minimum_tick_size": "0.01" }));
+        });
+        let neg_risk = server.mock(|when, then| {
+            when.method(GET).path("/neg-risk");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "neg_risk": false }));
+        });
+        let fee_rate = server.mock(|when, then| {
+            when.method(GET).path("/fee-rate");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "base_fee": 0 }));
+        });
+
+        let token_ids = [token_1(), token_2()];
+        client.warm_caches(&token_ids).await?;
+        client.warm_caches(&token_ids).await?;
+
+        tick_size.assert_calls(2);
+        neg_risk.assert_calls(2);
+        fee_rate.assert_calls(2);
+
+        let snapshot = client.export_caches();
+        assert_eq!(snapshot.tick_sizes.len(), 2);
+        assert_eq!(snapshot.neg_risk.len(), 2);
+        assert_eq!(snapshot.fee_rate_bps.len(), 2);
+
+        Ok(())
+    }
+}
+
+mod check_funds {
+    use alloy::primitives::ChainId;
+    use httpmock::Method::GET;
+    use polymarket_client_sdk::clob::types::{AssetType, InsufficientFunds, Side, TickSize};
+    use polymarket_client_sdk::error::Kind;
+    use polymarket_client_sdk::{AMOY, contract_config};
+
+    use super::*;
+
+    const UNLIMITED: &str =
+        "115792089237316195423570985008687907853269984665640564039457584007913129639935";
+
+    /// Registers the market parameters, the balance and exchange allowance (in base units) for
+    /// `asset_type`, and a single open buy of 10 @ 0.45 with 2.5 matched.
+    fn funds(server: &MockServer, asset_type: &str, balance: u64, allowance: &str) {
+        funds_on(server, POLYGON, asset_type, balance, allowance);
+    }
+
+    /// Like [`funds`], but grants the allowance to the exchange contract on `chain_id`.
+    fn funds_on(
+        server: &MockServer,
+        chain_id: ChainId,
+        asset_type: &str,
+        balance: u64,
+        allowance: &str,
+    ) {
+        ensure_requirements(server, token_1(), TickSize::Hundredth);
+        let exchange = contract_config(chain_id, false).unwrap().exchange;
+
+        server.mock(|when, then| {
+            when.method(GET)
+                .path("/balance-allowance")
+                .query_param("asset_type", asset_type);
+            then.status(StatusCode::OK).json_body(json!({
+                "balance": balance.to_string(),
+                "allowances": { exchange.to_string(): allowance }
+            }));
+        });
+        server.mock(|when, then| {
+            when.method(GET).path("/data/orders");
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [{
+                    "id": "1",
+                    "status": "LIVE",
+                    "owner": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+                    "maker_address": "0x2222222222222222222222222222222222222222",
+                    "market": "0x000000000000000000000000000000000000000000000000006d61726b657461",
+                    "asset_id": token_1(),
+                    "side": "BUY",
+                    "original_size": "10",
+                    "size_matched": "2.5",
+                    "price": "0.45",
+                    "associate_trades": [],
+                    "outcome": "YES",
+                    "created_at": 1_705_322_096,
+                    "expiration": "0",
+                    "order_type": "GTC"
+                }],
+                "limit": 1,
+                "count": 1,
+                "next_cursor": "LTE="
+            }));
+        });
+    }
+
+    #[tokio::test]
+    async fn buy_should_fail_when_open_orders_leave_too_little_collateral() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        funds(&server, "COLLATERAL", 10_000_000, UNLIMITED);
+
+        let err = client
+            .limit_order()
+            .token_id(token_1())
+            .side(Side::Buy)
+            .price(dec!(0.4))
+            .size(dec!(20))
+            .check_funds(true)
+            .build()
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.kind(), Kind::Validation);
+        let insufficient = err.downcast_ref::<InsufficientFunds>().unwrap();
+        assert_eq!(insufficient.asset_type, AssetType::Collateral);
+        assert_eq!(insufficient.required, dec!(8));
+        assert_eq!(insufficient.reserved, dec!(3.375));
+        assert_eq!(insufficient.balance, dec!(10));
+        assert_eq!(insufficient.shortfall(), dec!(1.375));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sell_should_ignore_open_buys() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        funds(&server, "CONDITIONAL", 20_000_000, UNLIMITED);
+
+        let order = client
+            .limit_order()
+            .token_id(token_1())
+            .side(Side::Sell)
+            .price(dec!(0.4))
+            .size(dec!(20))
+            .check_funds(true)
+            .build()
+            .await?;
+
+        client.check_funds(&order).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sell_should_only_fetch_open_orders_of_the_sold_token() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+        let exchange = contract_config(POLYGON, false).unwrap().exchange;
+
+        server.mock(|when, then| {
+            when.method(GET)
+                .path("/balance-allowance")
+                .query_param("asset_type", "CONDITIONAL");
+            then.status(StatusCode::OK).json_body(json!({
+                "balance": "10000000",
+                "allowances": { exchange.to_string(): UNLIMITED }
+            }));
+        });
+        let orders = server.mock(|when, then| {
+            when.method(GET)
+                .path("/data/orders")
+                .query_param("asset_id", token_1().to_string());
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [{
+                    "id": "1",
+                    "status": "LIVE",
+                    "owner": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+                    "maker_address": "0x2222222222222222222222222222222222222222",
+                    "market": "0x000000000000000000000000000000000000000000000000006d61726b657461",
+                    "asset_id": token_1(),
+                    "side": "SELL",
+                    "original_size": "10",
+                    "size_matched": "2.5",
+                    "price": "0.6",
+                    "associate_trades": [],
+                    "outcome": "YES",
+                    "created_at": 1_705_322_096,
+                    "expiration": "0",
+                    "order_type": "GTC"
+                }],
+                "limit": 1,
+                "count": 1,
+                "next_cursor": "LTE="
+            }));
+        });
+
+        let order = client
+            .limit_order()
+            .token_id(token_1())
+            .side(Side::Sell)
+            .price(dec!(0.4))
+            .size(dec!(5))
+            .build()
+            .await?;
+        let err = client.check_funds(&order).await.unwrap_err();
+
+        let insufficient = err.downcast_ref::<InsufficientFunds>().unwrap();
+        assert_eq!(insufficient.reserved, dec!(7.5));
+        assert_eq!(insufficient.shortfall(), dec!(2.5));
+        orders.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sell_should_fail_without_exchange_allowance() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        funds(&server, "CONDITIONAL", 20_000_000, "0");
+
+        let order = client
+            .limit_order()
+            .token_id(token_1())
+            .side(Side::Sell)
+            .price(dec!(0.4))
+            .size(dec!(5))
+            .build()
+            .await?;
+        let err = client.check_funds(&order).await.unwrap_err();
+
+        let insufficient = err.downcast_ref::<InsufficientFunds>().unwrap();
+        assert_eq!(insufficient.token_id, Some(token_1()));
+        assert_eq!(insufficient.allowance, Decimal::ZERO);
+        assert_eq!(insufficient.shortfall(), dec!(5));
+        assert!(err.to_string().contains("allowance"), "{err}");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_ignore_allowance_granted_on_another_chain() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        funds_on(&server, AMOY, "CONDITIONAL", 20_000_000, UNLIMITED);
+
+        let order = client
+            .limit_order()
+            .token_id(token_1())
+            .side(Side::Sell)
+            .price(dec!(0.4))
+            .size(dec!(5))
+            .build()
+            .await?;
+        let err = client.check_funds(&order).await.unwrap_err();
+
+        let insufficient = err.downcast_ref::<InsufficientFunds>().unwrap();
+        assert_eq!(insufficient.allowance, Decimal::ZERO);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_fail_on_unparseable_allowance() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        funds(&server, "CONDITIONAL", 20_000_000, "unlimited");
+
+        let order = client
+            .limit_order()
+            .token_id(token_1())
+            .side(Side::Sell)
+            .price(dec!(0.4))
+            .size(dec!(5))
+            .build()
+            .await?;
+        let err = client.check_funds(&order).await.unwrap_err();
+
+        assert_eq!(err.kind(), Kind::Validation);
+        assert!(err.downcast_ref::<InsufficientFunds>().is_none());
+        assert!(err.to_string().contains("allowance"), "{err}");
+
+        Ok(())
+    }
+}
+
+mod check_market {
+    use httpmock::Method::GET;
+    use httpmock::Mock;
+    use polymarket_client_sdk::clob::types::{MarketState, MarketStateViolation, Side, TickSize};
+    use polymarket_client_sdk::error::Kind;
+
+    use super::*;
+
+    const CONDITION_ID: &str = "0x00000000000000000000000000000000000000000000000000000000aabbcc00";
+
+    /// Registers the market parameters, and a market listing both tokens with the given flags and
+    /// a minimum order size of 5. Returns the order book and market mocks used for the lookup.
+    fn market(server: &MockServer, closed: bool, accepting_orders: bool) -> (Mock<'_>, Mock<'_>) {
+        ensure_requirements(server, token_1(), TickSize::Hundredth);
+
+        let book = server.mock(|when, then| {
+            when.method(GET)
+                .path("/book")
+                .query_param("token_id", token_1().to_string());
+            then.status(StatusCode::OK).json_body(json!({
+                "market": CONDITION_ID,
+                "asset_id": token_1(),
+                "tick_size": TickSize::Hundredth.as_decimal(),
+                "min_order_size": "5",
+                "neg_risk": false,
+                "timestamp": "123456789",
+                "bids": [],
+                "asks": []
+            }));
+        });
+        let market = listing(server, closed, accepting_orders);
+
+        (book, market)
+    }
+
+    /// Registers a market listing both tokens with the given flags and a minimum order size of 5.
+    fn listing(server: &MockServer, closed: bool, accepting_orders: bool) -> Mock<'_> {
+        server.mock(|when, then| {
+            when.method(GET).path(format!("/markets/{CONDITION_ID}"));
+            then.status(StatusCode::OK).json_body(json!({
+                "enable_order_book": true,
+                "active": true,
+                "closed": closed,
+                "archived": false,
+                "accepting_orders": accepting_orders,
+                "accepting_order_timestamp": null,
+                "minimum_order_size": "5",
+                "minimum_tick_size": "0.01",
+                "condition_id": CONDITION_ID,
+                "question_id": "",
+                "question": "Will BTC close above $50k today?",
+                "description": "",
+                "market_slug": "btc-close-above-50k",
+                "end_date_iso": null,
+                "game_start_time": null,
+                "seconds_delay": 0,
+                "fpmm": "",
+                "maker_base_fee": "0",
+                "taker_base_fee": "0",
+                "notifications_enabled": false,
+                "neg_risk": false,
+                "neg_risk_market_id": "",
+                "neg_risk_request_id": "",
+                "icon": "",
+                "image": "",
+                "rewards": {
+                    "rates": null,
+                    "min_size": "0",
+                    "max_spread": "0"
+                },
+                "is_50_50_outcome": false,
+                "tokens": [
+                    { "token_id": token_1(), "outcome": "YES", "price": "0.55" },
+                    { "token_id": token_2(), "outcome": "NO", "price": "0.45" }
+                ]
+            }));
+        })
+    }
+
+    /// Registers the market parameters, and an order book lookup that returns 404.
+    fn without_book(server: &MockServer) -> Mock<'_> {
+        ensure_requirements(server, token_1(), TickSize::Hundredth);
+
+        server.mock(|when, then| {
+            when.method(GET)
+                .path("/book")
+                .query_param("token_id", token_1().to_string());
+            then.status(StatusCode::NOT_FOUND)
+                .json_body(json!({ "error": "No orderbook exists for the requested token id" }));
+        })
+    }
+
+    #[tokio::test]
+    async fn market_state_should_be_cached_for_every_token() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+        let (book, market) = market(&server, false, true);
+
+        let state = client.market_state(token_1()).await?;
+        assert!(state.accepting_orders);
+        assert_eq!(state.minimum_order_size, dec!(5));
+
+        assert_eq!(client.market_state(token_1()).await?, state);
+        assert_eq!(client.market_state(token_2()).await?, state);
+        book.assert_calls(1);
+        market.assert_calls(1);
+
+        client.invalidate_internal_caches();
+        client.market_state(token_1()).await?;
+        market.assert_calls(2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn build_should_fail_for_closed_market() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        market(&server, true, false);
+
+        let err = client
+            .limit_order()
+            .token_id(token_1())
+            .side(Side::Buy)
+            .price(dec!(0.4))
+            .size(dec!(20))
+            .check_market(true)
+            .build()
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.kind(), Kind::Validation);
+        assert_eq!(
+            err.downcast_ref::<MarketStateViolation>(),
+            Some(&MarketStateViolation::Closed {
+                token_id: token_1()
+            })
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn build_should_fail_without_order_book() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        let book = without_book(&server);
+
+        let err = client
+            .limit_order()
+            .token_id(token_1())
+            .side(Side::Buy)
+            .price(dec!(0.4))
+            .size(dec!(20))
+            .check_market(true)
+            .build()
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<MarketStateViolation>(),
+            Some(&MarketStateViolation::OrderBookDisabled {
+                token_id: token_1()
+            })
+        );
+        book.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn closed_market_fetched_by_condition_id_should_skip_order_book() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        let book = without_book(&server);
+        let market = listing(&server, true, false);
+
+        client.market(CONDITION_ID).await?;
+        let err = client
+            .limit_order()
+            .token_id(token_1())
+            .side(Side::Buy)
+            .price(dec!(0.4))
+            .size(dec!(20))
+            .check_market(true)
+            .build()
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<MarketStateViolation>(),
+            Some(&MarketStateViolation::Closed {
+                token_id: token_1()
+            })
+        );
+        book.assert_calls(0);
+        market.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn build_should_fail_below_minimum_size() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        market(&server, false, true);
+
+        let err = client
+            .ladder_order()
+            .token_id(token_1())
+            .side(Side::Sell)
+            .price(dec!(0.6))
+            .sizes([dec!(10), dec!(4.99)])
+            .check_market(true)
+            .build()
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<MarketStateViolation>(),
+            Some(&MarketStateViolation::BelowMinimumSize {
+                token_id: token_1(),
+                size: dec!(4.99),
+                minimum_order_size: dec!(5),
+            })
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn set_market_state_should_skip_lookup() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        let (book, market) = market(&server, true, false);
+
+        let state = MarketState::builder()
+            .active(true)
+            .closed(false)
+            .enable_order_book(true)
+            .accepting_orders(true)
+            .minimum_order_size(dec!(5))
+            .build();
+        client.set_market_state(token_1(), state);
+
+        let order = client
+            .limit_order()
+            .token_id(token_1())
+            .side(Side::Buy)
+            .price(dec!(0.4))
+            .size(dec!(20))
+            .check_market(true)
+            .build()
+            .await?;
+
+        assert_eq!(order.size, Some(dec!(20)));
+        book.assert_calls(0);
+        market.assert_calls(0);
+
+        Ok(())
+    }
+}
+
+mod resubmit {
+    use alloy::signers::Signer as _;
+    use alloy::signers::local::LocalSigner;
+    use httpmock::Method::{GET, POST};
+    use polymarket_client_sdk::POLYGON;
+    use polymarket_client_sdk::clob::types::response::Resubmission;
+    use polymarket_client_sdk::clob::types::{Side, SignedOrder, TickSize};
+
+    use super::*;
+    use crate::common::TestClient;
+
+    async fn signed_order(
+        client: &TestClient,
+        client_order_id: &str,
+    ) -> anyhow::Result<SignedOrder> {
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let order = client
+            .limit_order()
+            .token_id(token_1())
+            .side(Side::Buy)
+            .price(dec!(0.4))
+            .size(dec!(20))
+            .client_order_id(client_order_id)
+            .build()
+            .await?;
+
+        Ok(client.sign(&signer, order).await?)
+    }
+
+    /// Registers the `/data/order` lookup of `order_id`, which has `status` if the CLOB knows it
+    /// and is not found otherwise.
+    fn lookup<'server>(
+        server: &'server MockServer,
+        order_id: &str,
+        status: Option<&str>,
+    ) -> httpmock::Mock<'server> {
+        server.mock(|when, then| {
+            when.method(GET).path(format!("/data/order/{order_id}"));
+            match status {
+                Some(status) => then.status(StatusCode::OK).json_body(json!({
+                    "id": order_id,
+                    "status": status,
+                    "owner": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+                    "maker_address": "0x2222222222222222222222222222222222222222",
+                    "market": "0x000000000000000000000000000000000000000000000000006d61726b657461",
+                    "asset_id": token_1(),
+                    "side": "BUY",
+                    "original_size": "20",
+                    "size_matched": "0",
+                    "price": "0.4",
+                    "associate_trades": [],
+                    "outcome": "YES",
+                    "created_at": 1_705_322_096,
+                    "expiration": "0",
+                    "order_type": "GTC"
+                })),
+                None => then
+                    .status(StatusCode::NOT_FOUND)
+                    .json_body(json!({ "error": "order not found" })),
+            };
+        })
+    }
+
+    #[tokio::test]
+    async fn client_order_id_should_reproduce_signed_order() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        let first = signed_order(&client, "quote-1").await?;
+        let second = signed_order(&client, "quote-1").await?;
+        let other = signed_order(&client, "quote-2").await?;
+
+        assert_eq!(first, second);
+        assert_eq!(
+            first.order_id(POLYGON, false)?,
+            second.order_id(POLYGON, false)?
+        );
+        assert_ne!(first.order.salt, other.order.salt);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn client_order_id_should_salt_ladder_levels_apart() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        let ladder = || {
+            client
+                .ladder_order()
+                .token_id(token_1())
+                .side(Side::Buy)
+                .price(dec!(0.4))
+                .sizes([dec!(10), dec!(10)])
+                .client_order_id("quote-1")
+                .build()
+        };
+        let first = ladder().await?;
+        let second = ladder().await?;
+
+        assert_ne!(first[0].order.salt, first[1].order.salt);
+        assert_eq!(first[0].order.salt, second[0].order.salt);
+        assert_eq!(first[1].order.salt, second[1].order.salt);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn resubmit_should_skip_open_order() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        let order = signed_order(&client, "quote-1").await?;
+        let order_id = order.order_id(POLYGON, false)?;
+        let lookup = lookup(&server, &order_id, Some("LIVE"));
+        let post = server.mock(|when, then| {
+            when.method(POST).path("/order");
+            then.status(StatusCode::OK).json_body(json!({}));
+        });
+
+        let resubmission = client.resubmit_order(order).await?;
+
+        let Resubmission::Existing(existing) = resubmission else {
+            panic!("expected the open order, got {resubmission:?}");
+        };
+        assert_eq!(existing.id, order_id);
+        lookup.assert();
+        post.assert_calls(0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn resubmit_should_skip_matched_order() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        let order = signed_order(&client, "quote-1").await?;
+        let order_id = order.order_id(POLYGON, false)?;
+        let lookup = lookup(&server, &order_id, Some("MATCHED"));
+        let post = server.mock(|when, then| {
+            when.method(POST).path("/order");
+            then.status(StatusCode::OK).json_body(json!({}));
+        });
+
+        let resubmission = client.resubmit_order(order).await?;
+
+        assert!(matches!(resubmission, Resubmission::Existing(_)));
+        lookup.assert();
+        post.assert_calls(0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn resubmit_should_post_missing_order() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        let order = signed_order(&client, "quote-1").await?;
+        let order_id = order.order_id(POLYGON, false)?;
+        let lookup = lookup(&server, &order_id, None);
+        let post = server.mock(|when, then| {
+            when.method(POST).path("/order");
+            then.status(StatusCode::OK).json_body(json!({
+                "makingAmount": "8",
+                "takingAmount": "20",
+                "orderID": order_id,
+                "status": "live",
+                "success": true
+            }));
+        });
+
+        let resubmission = client.resubmit_order(order).await?;
+
+        let Resubmission::Posted(posted) = resubmission else {
+            panic!("expected the posted order, got {resubmission:?}");
+        };
+        assert_eq!(posted.order_id, order_id);
+        lookup.assert();
+        post.assert();
+
+        Ok(())
+    }
+}
+
+mod clock_sync {
+    use std::time::Duration;
+
+    use alloy::signers::Signer as _;
+    use alloy::signers::local::LocalSigner;
+    use chrono::TimeDelta;
+    use httpmock::Method::GET;
+    use polymarket_client_sdk::clob::types::{OrderType, Side, TickSize};
+
+    use super::*;
+    use crate::common::{
+        API_KEY, PASSPHRASE, POLY_NONCE, POLY_SIGNATURE, POLY_TIMESTAMP, SECRET, SIGNATURE,
+    };
+
+    const SERVER_TIME: i64 = 100_000;
+
+    fn time(server: &MockServer) -> httpmock::Mock<'_> {
+        server.mock(|when, then| {
+            when.method(GET).path("/time");
+            then.status(StatusCode::OK).json_body(SERVER_TIME);
+        })
+    }
+
+    #[tokio::test]
+    async fn sync_clock_should_measure_offset_and_drift() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let time = time(&server);
+
+        let client = Client::new(&server.base_url(), Config::default())?;
+        assert!(client.clock_offset().is_none());
+
+        let before = Utc::now();
+        let first = client.sync_clock().await?;
+        let expected = DateTime::from_timestamp(SERVER_TIME, 500_000_000).unwrap() - before;
+
+        assert!((first.offset - expected).abs() < TimeDelta::seconds(1));
+        assert!(first.round_trip >= TimeDelta::zero());
+        assert!(first.drift.is_none());
+        assert!(first.skewed);
+        assert_eq!(client.clock_offset(), Some(first));
+        assert_eq!(client.corrected_now().timestamp(), SERVER_TIME);
+
+        let second = client.sync_clock().await?;
+        let drift = second.drift.unwrap();
+
+        assert_eq!(drift, second.offset - first.offset);
+        assert!(drift.abs() < TimeDelta::seconds(1));
+        time.assert_calls(2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn skew_below_threshold_should_not_be_flagged() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        time(&server);
+
+        let threshold = Duration::from_secs(u64::try_from(Utc::now().timestamp())?);
+        let config = Config::builder().clock_skew_threshold(threshold).build();
+        let client = Client::new(&server.base_url(), config)?;
+
+        assert!(!client.sync_clock().await?.skewed);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn headers_should_use_corrected_clock_without_round_trip_per_request()
+    -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let time = time(&server);
+        let derive = server.mock(|when, then| {
+            when.method(GET)
+                .path("/auth/derive-api-key")
+                .header(POLY_ADDRESS, signer.address().to_string().to_lowercase())
+                .header(POLY_NONCE, "0")
+                .header(POLY_SIGNATURE, SIGNATURE)
+                .header(POLY_TIMESTAMP, SERVER_TIME.to_string());
+            then.status(StatusCode::OK).json_body(json!({
+                "apiKey": API_KEY.to_string(),
+                "passphrase": PASSPHRASE,
+                "secret": SECRET
+            }));
+        });
+        let api_keys = server.mock(|when, then| {
+            when.method(GET)
+                .path("/auth/api-keys")
+                .header(POLY_API_KEY, API_KEY)
+                .header(POLY_TIMESTAMP, SERVER_TIME.to_string());
+            then.status(StatusCode::OK)
+                .json_body(json!({"apiKeys": [API_KEY]}));
+        });
+
+        let config = Config::builder()
+            .clock_sync_interval(Duration::from_secs(3600))
+            .use_server_time(true)
+            .build();
+        let client = Client::new(&server.base_url(), config)?
+            .authentication_builder(&signer)
+            .authenticate()
+            .await?;
+
+        client.api_keys().await?;
+        client.api_keys().await?;
+
+        derive.assert();
+        api_keys.assert_calls(2);
+        time.assert_calls(1);
+        assert!(client.clock_offset().is_some());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn stale_offset_should_be_measured_once_for_concurrent_requests() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let time = time(&server);
+        server.mock(|when, then| {
+            when.method(GET).path("/auth/derive-api-key");
+            then.status(StatusCode::OK).json_body(json!({
+                "apiKey": API_KEY.to_string(),
+                "passphrase": PASSPHRASE,
+                "secret": SECRET
+            }));
+        });
+        let api_keys = server.mock(|when, then| {
+            when.method(GET).path("/auth/api-keys");
+            then.status(StatusCode::OK)
+                .json_body(json!({"apiKeys": [API_KEY]}));
+        });
+
+        let config = Config::builder()
+            .clock_sync_interval(Duration::from_millis(200))
+            .build();
+        let client = Client::new(&server.base_url(), config)?
+            .authentication_builder(&signer)
+            .authenticate()
+            .await?;
+        time.assert_calls(1);
+
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        let responses =
+            futures::future::join_all(std::iter::repeat_with(|| client.api_keys()).take(4)).await;
+
+        assert!(responses.iter().all(Result::is_ok));
+        api_keys.assert_calls(4);
+        time.assert_calls(2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn failed_re_measurement_should_keep_last_offset() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let mut time = time(&server);
+        server.mock(|when, then| {
+            when.method(GET).path("/auth/derive-api-key");
+            then.status(StatusCode::OK).json_body(json!({
+                "apiKey": API_KEY.to_string(),
+                "passphrase": PASSPHRASE,
+                "secret": SECRET
+            }));
+        });
+        let api_keys = server.mock(|when, then| {
+            when.method(GET).path("/auth/api-keys");
+            then.status(StatusCode::OK)
+                .json_body(json!({"apiKeys": [API_KEY]}));
+        });
+
+        let config = Config::builder()
+            .clock_sync_interval(Duration::from_millis(200))
+            .build();
+        let client = Client::new(&server.base_url(), config)?
+            .authentication_builder(&signer)
+            .authenticate()
+            .await?;
+        let measured = client.clock_offset().unwrap();
+
+        time.delete();
+        let unavailable = server.mock(|when, then| {
+            when.method(GET).path("/time");
+            then.status(StatusCode::BAD_REQUEST);
+        });
+        tokio::time::sleep(Duration::from_millis(300)).await;
+
+        client.api_keys().await?;
+
+        api_keys.assert();
+        unavailable.assert();
+        assert_eq!(client.clock_offset(), Some(measured));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn expires_in_should_use_corrected_clock() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        client.sync_clock().await?;
+
+        let order = client
+            .limit_order()
+            .token_id(token_1())
+            .side(Side::Buy)
+            .price(dec!(0.4))
+            .size(dec!(20))
+            .order_type(OrderType::GTD)
+            .expires_in(TimeDelta::hours(1))
+            .build()
+            .await?;
+
+        assert_eq!(order.order.expiration, U256::from(SERVER_TIME + 60 + 3600));
+
+        Ok(())
+    }
+}
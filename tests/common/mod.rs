@@ -68,6 +68,14 @@ pub fn token_2() -> U256 {
 }
 
 pub async fn create_authenticated(server: &MockServer) -> anyhow::Result<TestClient> {
+    create_authenticated_with(server, Config::builder().use_server_time(true).build()).await
+}
+
+/// Like [`create_authenticated`], but with `config`, which must use the server time.
+pub async fn create_authenticated_with(
+    server: &MockServer,
+    config: Config,
+) -> anyhow::Result<TestClient> {
     let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
 
     let mock = server.mock(|when, then| {
@@ -89,7 +97,6 @@ pub async fn create_authenticated(server: &MockServer) -> anyhow::Result<TestCli
             .json_body(TIMESTAMP.parse::<i64>().unwrap());
     });
 
-    let config = Config::builder().use_server_time(true).build();
     let client = Client::new(&server.base_url(), config)?
         .authentication_builder(&signer)
         .authenticate()
@@ -926,7 +926,8 @@ mod error_handling {
         assert!(result.is_err());
         let err = result.unwrap_err();
         assert_eq!(err.kind(), Kind::Status);
-        mock.assert();
+        // Server errors on reads are retried by the default policy
+        mock.assert_calls(3);
 
         Ok(())
     }
@@ -1538,3 +1539,41 @@ mod request_query_string_extended {
         assert_eq!(ClosedPositionSortBy::RealizedPnl.to_string(), "REALIZEDPNL");
     }
 }
+
+mod rate_limit {
+    use std::num::NonZeroU32;
+    use std::time::Duration;
+
+    use httpmock::{Method::GET, MockServer};
+    use polymarket_client_sdk::data::{Client, Config, types::request::TradesRequest};
+    use polymarket_client_sdk::rate_limit::{
+        EndpointGroup, OnLimit, Quota, RateLimited, RateLimiter,
+    };
+    use reqwest::StatusCode;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn with_config_should_apply_rate_limiter() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let limiter = RateLimiter::builder()
+            .trades(Quota::new(NonZeroU32::MIN, Duration::from_secs(60)))
+            .on_limit(OnLimit::FailFast)
+            .build();
+        let config = Config::builder().rate_limiter(limiter).build();
+        let client = Client::with_config(&server.base_url(), config)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/trades");
+            then.status(StatusCode::OK).json_body(json!([]));
+        });
+
+        client.trades(&TradesRequest::default()).await?;
+        let err = client.trades(&TradesRequest::default()).await.unwrap_err();
+
+        let limited = err.downcast_ref::<RateLimited>().unwrap();
+        assert_eq!(limited.group, EndpointGroup::Trades);
+        mock.assert_calls(1);
+
+        Ok(())
+    }
+}
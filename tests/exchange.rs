@@ -0,0 +1,207 @@
+#![cfg(feature = "exchange")]
+#![allow(clippy::unwrap_used, reason = "Fine for tests")]
+
+mod common;
+
+use alloy::primitives::U256;
+use alloy::providers::ProviderBuilder;
+use httpmock::{Method::POST, MockServer};
+use polymarket_client_sdk::POLYGON;
+use polymarket_client_sdk::exchange::Client;
+use polymarket_client_sdk::types::address;
+use serde_json::json;
+
+mod contract_calls {
+    use polymarket_client_sdk::clob::types::Order;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn nonce() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let provider = ProviderBuilder::new().connect(&server.base_url()).await?;
+        let client = Client::new(provider, POLYGON)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/");
+            then.json_body(json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": "0x0000000000000000000000000000000000000000000000000000000000000003"
+            }));
+        });
+
+        let response = client
+            .nonce(address!("0x0000000000000000000000000000000000000001"))
+            .await?;
+
+        assert_eq!(response.nonce, 3);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn is_valid_nonce() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let provider = ProviderBuilder::new().connect(&server.base_url()).await?;
+        let client = Client::with_neg_risk(provider, POLYGON)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/");
+            then.json_body(json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": "0x0000000000000000000000000000000000000000000000000000000000000001"
+            }));
+        });
+
+        let valid = client
+            .is_valid_nonce(address!("0x0000000000000000000000000000000000000001"), 3)
+            .await?;
+
+        assert!(valid);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn order_status_should_report_partial_fill() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let provider = ProviderBuilder::new().connect(&server.base_url()).await?;
+        let client = Client::new(provider, POLYGON)?;
+
+        // `remaining` of 40 USDC out of the 100 USDC maker amount
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/");
+            then.json_body(json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": "0x\
+                    0000000000000000000000000000000000000000000000000000000000000000\
+                    0000000000000000000000000000000000000000000000000000000002625a00"
+            }));
+        });
+
+        let mut order = Order::default();
+        order.makerAmount = U256::from(100_000_000);
+        let response = client.order_status(&order).await?;
+
+        assert_eq!(response.order_hash, order.hash(POLYGON, false)?);
+        assert!(!response.is_filled_or_cancelled);
+        assert_eq!(response.remaining, U256::from(40_000_000));
+        assert_eq!(response.filled, Some(U256::from(60_000_000)));
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn order_status_should_not_report_fill_of_closed_order() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let provider = ProviderBuilder::new().connect(&server.base_url()).await?;
+        let client = Client::new(provider, POLYGON)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/");
+            then.json_body(json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": "0x\
+                    0000000000000000000000000000000000000000000000000000000000000001\
+                    0000000000000000000000000000000000000000000000000000000000000000"
+            }));
+        });
+
+        let mut order = Order::default();
+        order.makerAmount = U256::from(100_000_000);
+        let response = client.order_status(&order).await?;
+
+        assert!(response.is_filled_or_cancelled);
+        assert_eq!(response.filled, None);
+        mock.assert();
+
+        Ok(())
+    }
+}
+
+mod order_nonce {
+    use polymarket_client_sdk::clob::types::{Side, TickSize};
+    use polymarket_client_sdk::types::Decimal;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::common::{create_authenticated, ensure_requirements, token_1};
+
+    #[tokio::test]
+    async fn nonce_should_sign_into_order() -> anyhow::Result<()> {
+        let rpc = MockServer::start();
+        let provider = ProviderBuilder::new().connect(&rpc.base_url()).await?;
+        let exchange = Client::new(provider, POLYGON)?;
+        rpc.mock(|when, then| {
+            when.method(POST).path("/");
+            then.json_body(json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": "0x0000000000000000000000000000000000000000000000000000000000000007"
+            }));
+        });
+
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        let nonce = exchange.nonce(client.address()).await?;
+        let order = client
+            .limit_order()
+            .token_id(token_1())
+            .side(Side::Buy)
+            .price(dec!(0.5))
+            .size(Decimal::TEN)
+            .nonce(nonce.into())
+            .build()
+            .await?;
+
+        assert_eq!(order.order.nonce, U256::from(7));
+
+        Ok(())
+    }
+}
+
+mod client_creation {
+    use super::*;
+
+    #[tokio::test]
+    async fn polygon_mainnet_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let provider = ProviderBuilder::new().connect(&server.base_url()).await?;
+
+        let client = Client::new(provider, POLYGON)?;
+        assert!(!client.neg_risk());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn neg_risk_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let provider = ProviderBuilder::new().connect(&server.base_url()).await?;
+
+        let client = Client::with_neg_risk(provider, polymarket_client_sdk::AMOY)?;
+        assert!(client.neg_risk());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn invalid_chain_should_fail() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let provider = ProviderBuilder::new().connect(&server.base_url()).await?;
+
+        let client = Client::new(provider, 999);
+        client.unwrap_err();
+
+        Ok(())
+    }
+}
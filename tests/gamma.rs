@@ -1580,3 +1580,37 @@ mod query_string {
         assert!(qs.is_empty());
     }
 }
+
+mod retry {
+    use std::time::Duration;
+
+    use httpmock::{Method::GET, MockServer};
+    use polymarket_client_sdk::gamma::types::request::TagsRequest;
+    use polymarket_client_sdk::gamma::{Client, Config};
+    use polymarket_client_sdk::retry::RetryPolicy;
+    use reqwest::StatusCode;
+
+    #[tokio::test]
+    async fn with_config_should_apply_retry_policy() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let retry = RetryPolicy::builder()
+            .initial_backoff(Duration::from_millis(1))
+            .max_attempts(4)
+            .build();
+        let client =
+            Client::with_config(&server.base_url(), Config::builder().retry(retry).build())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/tags");
+            then.status(StatusCode::BAD_GATEWAY);
+        });
+
+        client
+            .tags(&TagsRequest::builder().build())
+            .await
+            .unwrap_err();
+        mock.assert_calls(4);
+
+        Ok(())
+    }
+}
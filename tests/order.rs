@@ -1335,6 +1335,368 @@ mod limit {
 
         Ok(())
     }
+
+    mod rounding {
+        use polymarket_client_sdk::clob::types::Rounding;
+
+        use super::*;
+
+        #[tokio::test]
+        async fn usdc_amount_should_size_at_limit_price() -> anyhow::Result<()> {
+            let server = MockServer::start();
+            let client = create_authenticated(&server).await?;
+
+            ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+            let signable_order = client
+                .limit_order()
+                .token_id(token_1())
+                .price(dec!(0.37))
+                .amount(Amount::usdc(dec!(250))?)
+                .side(Side::Buy)
+                .build()
+                .await?;
+
+            // 250 / 0.37 = 675.675..., rounded down to the lot size
+            assert_eq!(signable_order.size, Some(dec!(675.67)));
+            assert_eq!(signable_order.limit_price, Some(dec!(0.37)));
+            assert_eq!(signable_order.order.makerAmount, U256::from(249_997_900));
+            assert_eq!(signable_order.order.takerAmount, U256::from(675_670_000));
+
+            let signable_order = client
+                .limit_order()
+                .token_id(token_1())
+                .price(dec!(0.37))
+                .amount(Amount::usdc(dec!(250))?)
+                .size_rounding(Rounding::Up)
+                .side(Side::Buy)
+                .build()
+                .await?;
+
+            assert_eq!(signable_order.size, Some(dec!(675.68)));
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn should_fail_on_both_size_and_amount() -> anyhow::Result<()> {
+            let server = MockServer::start();
+            let client = create_authenticated(&server).await?;
+
+            ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+            let err = client
+                .limit_order()
+                .token_id(token_1())
+                .price(dec!(0.37))
+                .size(dec!(100))
+                .amount(Amount::usdc(dec!(250))?)
+                .side(Side::Buy)
+                .build()
+                .await
+                .unwrap_err();
+            let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+
+            assert_eq!(msg, "Unable to build Order with both a size and an amount");
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn price_should_round_to_tick() -> anyhow::Result<()> {
+            let server = MockServer::start();
+            let client = create_authenticated(&server).await?;
+
+            ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+            let cases = [
+                (Side::Buy, Rounding::Down, dec!(0.37)),
+                (Side::Buy, Rounding::Up, dec!(0.38)),
+                (Side::Buy, Rounding::Nearest, dec!(0.37)),
+                (Side::Buy, Rounding::Passive, dec!(0.37)),
+                (Side::Buy, Rounding::Aggressive, dec!(0.38)),
+                (Side::Sell, Rounding::Passive, dec!(0.38)),
+                (Side::Sell, Rounding::Aggressive, dec!(0.37)),
+            ];
+
+            for (side, rounding, expected) in cases {
+                let signable_order = client
+                    .limit_order()
+                    .token_id(token_1())
+                    .price(dec!(0.3712))
+                    .price_rounding(rounding)
+                    .size(dec!(100))
+                    .side(side)
+                    .build()
+                    .await?;
+
+                assert_eq!(
+                    signable_order.limit_price,
+                    Some(expected),
+                    "{side} {rounding}"
+                );
+            }
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn size_should_round_to_lot_size() -> anyhow::Result<()> {
+            let server = MockServer::start();
+            let client = create_authenticated(&server).await?;
+
+            ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+            let signable_order = client
+                .limit_order()
+                .token_id(token_1())
+                .price(dec!(0.5))
+                .size(dec!(10.005))
+                .size_rounding(Rounding::Nearest)
+                .side(Side::Sell)
+                .build()
+                .await?;
+
+            assert_eq!(signable_order.size, Some(dec!(10.01)));
+            assert_eq!(signable_order.order.makerAmount, U256::from(10_010_000));
+            assert_eq!(signable_order.order.takerAmount, U256::from(5_005_000));
+
+            Ok(())
+        }
+    }
+
+    mod crossing {
+        use httpmock::Method::{DELETE, GET};
+        use polymarket_client_sdk::clob::types::{
+            CrossingDetected, CrossingKind, CrossingPolicy, MarketState, MarketStateViolation,
+            TopOfBook,
+        };
+        use serde_json::json;
+
+        use super::*;
+
+        /// Registers the market parameters and a single resting order of ours for `token_1` with
+        /// ID `1`, or none if `side` is `None`.
+        fn resting(server: &MockServer, side: Option<&str>, price: &str) {
+            ensure_requirements(server, token_1(), TickSize::Hundredth);
+
+            let data: Vec<_> = side
+                .into_iter()
+                .map(|side| {
+                    json!({
+                        "id": "1",
+                        "status": "LIVE",
+                        "owner": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+                        "maker_address": "0x2222222222222222222222222222222222222222",
+                        "market": "0x000000000000000000000000000000000000000000000000006d61726b657461",
+                        "asset_id": token_1(),
+                        "side": side,
+                        "original_size": "10",
+                        "size_matched": "0",
+                        "price": price,
+                        "associate_trades": [],
+                        "outcome": "YES",
+                        "created_at": 1_705_322_096,
+                        "expiration": "0",
+                        "order_type": "GTC"
+                    })
+                })
+                .collect();
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/data/orders")
+                    .query_param("asset_id", token_1().to_string());
+                then.status(StatusCode::OK).json_body(json!({
+                    "data": data,
+                    "limit": 1,
+                    "count": 1,
+                    "next_cursor": "LTE="
+                }));
+            });
+        }
+
+        #[tokio::test]
+        async fn post_only_crossing_supplied_book_should_be_rejected() -> anyhow::Result<()> {
+            let server = MockServer::start();
+            let client = create_authenticated(&server).await?;
+            resting(&server, None, "0");
+
+            let top = TopOfBook::builder()
+                .best_bid(dec!(0.43))
+                .best_ask(dec!(0.45))
+                .build();
+            let err = client
+                .limit_order()
+                .token_id(token_1())
+                .side(Side::Buy)
+                .price(dec!(0.46))
+                .size(dec!(10))
+                .post_only(true)
+                .crossing_policy(CrossingPolicy::Reject)
+                .book(top)
+                .build()
+                .await
+                .unwrap_err();
+
+            let detected = err.downcast_ref::<CrossingDetected>().unwrap();
+            assert_eq!(detected.kind, CrossingKind::PostOnly);
+            assert_eq!(detected.crossed_price, dec!(0.45));
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn self_trade_should_be_rejected() -> anyhow::Result<()> {
+            let server = MockServer::start();
+            let client = create_authenticated(&server).await?;
+            resting(&server, Some("SELL"), "0.45");
+
+            let err = client
+                .limit_order()
+                .token_id(token_1())
+                .side(Side::Buy)
+                .price(dec!(0.5))
+                .size(dec!(10))
+                .crossing_policy(CrossingPolicy::Reject)
+                .build()
+                .await
+                .unwrap_err();
+
+            let detected = err.downcast_ref::<CrossingDetected>().unwrap();
+            assert_eq!(detected.kind, CrossingKind::SelfTrade);
+            assert_eq!(detected.crossed_price, dec!(0.45));
+            assert_eq!(detected.order_ids, vec!["1".to_owned()]);
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn reprice_should_move_one_tick_behind() -> anyhow::Result<()> {
+            let server = MockServer::start();
+            let client = create_authenticated(&server).await?;
+            resting(&server, Some("BUY"), "0.55");
+            server.mock(|when, then| {
+                when.method(GET).path("/book");
+                then.status(StatusCode::OK).json_body(json!({
+                    "market": "0x000000000000000000000000000000000000000000000000006d61726b657461",
+                    "asset_id": token_1(),
+                    "tick_size": "0.01",
+                    "min_order_size": "5",
+                    "neg_risk": false,
+                    "timestamp": "123456789",
+                    "bids": [{ "price": "0.5", "size": "100" }, { "price": "0.57", "size": "100" }],
+                    "asks": [{ "price": "0.6", "size": "100" }]
+                }));
+            });
+
+            let signable_order = client
+                .limit_order()
+                .token_id(token_1())
+                .side(Side::Sell)
+                .price(dec!(0.5))
+                .size(dec!(10))
+                .post_only(true)
+                .crossing_policy(CrossingPolicy::Reprice)
+                .build()
+                .await?;
+
+            // The best bid of 0.57 is crossed before our own bid of 0.55
+            assert_eq!(signable_order.limit_price, Some(dec!(0.58)));
+            assert_eq!(signable_order.order.takerAmount, U256::from(5_800_000));
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn cancel_resting_should_cancel_crossed_orders() -> anyhow::Result<()> {
+            let server = MockServer::start();
+            let client = create_authenticated(&server).await?;
+            resting(&server, Some("SELL"), "0.45");
+            let cancel = server.mock(|when, then| {
+                when.method(DELETE).path("/orders").json_body(json!(["1"]));
+                then.status(StatusCode::OK)
+                    .json_body(json!({ "canceled": ["1"] }));
+            });
+
+            let signable_order = client
+                .limit_order()
+                .token_id(token_1())
+                .side(Side::Buy)
+                .price(dec!(0.5))
+                .size(dec!(10))
+                .crossing_policy(CrossingPolicy::CancelResting)
+                .build()
+                .await?;
+
+            cancel.assert();
+            assert_eq!(signable_order.limit_price, Some(dec!(0.5)));
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn cancel_resting_should_not_cancel_when_build_fails() -> anyhow::Result<()> {
+            let server = MockServer::start();
+            let client = create_authenticated(&server).await?;
+            resting(&server, Some("SELL"), "0.45");
+            let cancel = server.mock(|when, then| {
+                when.method(DELETE).path("/orders");
+                then.status(StatusCode::OK)
+                    .json_body(json!({ "canceled": ["1"] }));
+            });
+            client.set_market_state(
+                token_1(),
+                MarketState::builder()
+                    .active(true)
+                    .closed(false)
+                    .enable_order_book(true)
+                    .accepting_orders(true)
+                    .minimum_order_size(dec!(5))
+                    .build(),
+            );
+
+            let err = client
+                .limit_order()
+                .token_id(token_1())
+                .side(Side::Buy)
+                .price(dec!(0.5))
+                .size(dec!(4))
+                .crossing_policy(CrossingPolicy::CancelResting)
+                .check_market(true)
+                .build()
+                .await
+                .unwrap_err();
+
+            assert!(matches!(
+                err.downcast_ref::<MarketStateViolation>(),
+                Some(MarketStateViolation::BelowMinimumSize { .. })
+            ));
+            cancel.assert_calls(0);
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn non_crossing_order_should_build_unchanged() -> anyhow::Result<()> {
+            let server = MockServer::start();
+            let client = create_authenticated(&server).await?;
+            resting(&server, Some("SELL"), "0.55");
+
+            let signable_order = client
+                .limit_order()
+                .token_id(token_1())
+                .side(Side::Buy)
+                .price(dec!(0.5))
+                .size(dec!(10))
+                .crossing_policy(CrossingPolicy::Reject)
+                .build()
+                .await?;
+
+            assert_eq!(signable_order.limit_price, Some(dec!(0.5)));
+
+            Ok(())
+        }
+    }
 }
 
 mod market {
@@ -3175,4 +3537,582 @@ mod market {
         assert_eq!(msg, "Sell Orders must specify their `amount`s in shares");
         Ok(())
     }
+
+    mod simulate {
+        use polymarket_client_sdk::clob::types::response::FillSimulation;
+
+        use super::*;
+
+        #[tokio::test]
+        async fn buy_should_report_vwap_levels_and_fee() -> anyhow::Result<()> {
+            let server = MockServer::start();
+            let client = create_authenticated(&server).await?;
+
+            ensure_requirements_for_market_price(
+                &server,
+                token_1(),
+                &[],
+                &[
+                    OrderSummary::builder()
+                        .price(dec!(0.5))
+                        .size(Decimal::ONE_HUNDRED)
+                        .build(),
+                    OrderSummary::builder()
+                        .price(dec!(0.4))
+                        .size(Decimal::ONE_HUNDRED)
+                        .build(),
+                ],
+            );
+
+            let simulation = client
+                .market_order()
+                .token_id(token_1())
+                .amount(Amount::usdc(dec!(60))?)
+                .side(Side::Buy)
+                .fee_rate_bps(100)
+                .simulate()
+                .await?;
+
+            let expected = FillSimulation::builder()
+                .average_price(dec!(60) / dec!(140))
+                .worst_price(dec!(0.5))
+                .shares(dec!(140))
+                .usdc(dec!(60))
+                .fee(dec!(0.6))
+                .levels(vec![
+                    OrderSummary::builder()
+                        .price(dec!(0.4))
+                        .size(Decimal::ONE_HUNDRED)
+                        .build(),
+                    OrderSummary::builder()
+                        .price(dec!(0.5))
+                        .size(dec!(40))
+                        .build(),
+                ])
+                .filled(true)
+                .build();
+
+            assert_eq!(simulation, expected);
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn sell_should_report_partial_fill_on_thin_book() -> anyhow::Result<()> {
+            let server = MockServer::start();
+            let client = create_authenticated(&server).await?;
+
+            ensure_requirements_for_market_price(
+                &server,
+                token_1(),
+                &[
+                    OrderSummary::builder()
+                        .price(dec!(0.3))
+                        .size(Decimal::TEN)
+                        .build(),
+                    OrderSummary::builder()
+                        .price(dec!(0.4))
+                        .size(Decimal::TEN)
+                        .build(),
+                ],
+                &[],
+            );
+
+            let simulation = client
+                .market_order()
+                .token_id(token_1())
+                .amount(Amount::shares(dec!(50))?)
+                .side(Side::Sell)
+                .simulate()
+                .await?;
+
+            assert!(!simulation.filled);
+            assert_eq!(simulation.shares, dec!(20));
+            assert_eq!(simulation.usdc, dec!(7));
+            assert_eq!(simulation.average_price, dec!(0.35));
+            assert_eq!(simulation.worst_price, dec!(0.3));
+            assert_eq!(simulation.fee, Decimal::ZERO);
+            assert_eq!(simulation.levels.len(), 2);
+
+            Ok(())
+        }
+    }
+
+    mod slippage {
+        use polymarket_client_sdk::clob::types::{
+            MaxSlippage, SlippageBound, SlippageExceeded, SlippageReference,
+        };
+
+        use super::*;
+
+        fn ensure_thin_asks(server: &MockServer) {
+            ensure_requirements_for_market_price(
+                server,
+                token_1(),
+                &[],
+                &[
+                    OrderSummary::builder()
+                        .price(dec!(0.5))
+                        .size(Decimal::ONE_HUNDRED)
+                        .build(),
+                    OrderSummary::builder()
+                        .price(dec!(0.4))
+                        .size(Decimal::ONE_HUNDRED)
+                        .build(),
+                ],
+            );
+        }
+
+        #[tokio::test]
+        async fn buy_beyond_bound_should_fail() -> anyhow::Result<()> {
+            let server = MockServer::start();
+            let client = create_authenticated(&server).await?;
+
+            ensure_thin_asks(&server);
+
+            let err = client
+                .market_order()
+                .token_id(token_1())
+                .amount(Amount::usdc(dec!(60))?)
+                .side(Side::Buy)
+                .max_slippage(
+                    MaxSlippage::builder()
+                        .bound(SlippageBound::Percent(dec!(10)))
+                        .build(),
+                )
+                .build()
+                .await
+                .unwrap_err();
+            let exceeded = err.downcast_ref::<SlippageExceeded>().unwrap();
+
+            assert_eq!(exceeded.reference, SlippageReference::BestPrice);
+            assert_eq!(exceeded.reference_price, dec!(0.4));
+            assert_eq!(exceeded.bound_price, dec!(0.4));
+            assert_eq!(exceeded.limit_price, dec!(0.5));
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn buy_beyond_bound_should_clamp() -> anyhow::Result<()> {
+            let server = MockServer::start();
+            let client = create_authenticated(&server).await?;
+
+            ensure_thin_asks(&server);
+
+            let signable_order = client
+                .market_order()
+                .token_id(token_1())
+                .amount(Amount::usdc(dec!(60))?)
+                .side(Side::Buy)
+                .order_type(OrderType::FAK)
+                .max_slippage(
+                    MaxSlippage::builder()
+                        .bound(SlippageBound::Ticks(0))
+                        .clamp(true)
+                        .build(),
+                )
+                .build()
+                .await?;
+
+            assert_eq!(signable_order.limit_price, Some(dec!(0.4)));
+            assert_eq!(signable_order.order.makerAmount, U256::from(60_000_000));
+            assert_eq!(signable_order.order.takerAmount, U256::from(150_000_000));
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn sell_within_bound_from_midpoint_should_succeed() -> anyhow::Result<()> {
+            let server = MockServer::start();
+            let client = create_authenticated(&server).await?;
+
+            ensure_requirements_for_market_price(
+                &server,
+                token_1(),
+                &[
+                    OrderSummary::builder()
+                        .price(dec!(0.3))
+                        .size(Decimal::TEN)
+                        .build(),
+                    OrderSummary::builder()
+                        .price(dec!(0.4))
+                        .size(Decimal::TEN)
+                        .build(),
+                ],
+                &[OrderSummary::builder()
+                    .price(dec!(0.6))
+                    .size(Decimal::TEN)
+                    .build()],
+            );
+
+            let signable_order = client
+                .market_order()
+                .token_id(token_1())
+                .amount(Amount::shares(Decimal::TEN)?)
+                .side(Side::Sell)
+                .max_slippage(
+                    MaxSlippage::builder()
+                        .bound(SlippageBound::Percent(dec!(20)))
+                        .reference(SlippageReference::Midpoint)
+                        .build(),
+                )
+                .build()
+                .await?;
+
+            assert_eq!(signable_order.limit_price, Some(dec!(0.4)));
+            assert_eq!(signable_order.order.takerAmount, U256::from(4_000_000));
+
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn explicit_price_beyond_bound_should_fail() -> anyhow::Result<()> {
+            let server = MockServer::start();
+            let client = create_authenticated(&server).await?;
+
+            ensure_thin_asks(&server);
+
+            let err = client
+                .market_order()
+                .token_id(token_1())
+                .amount(Amount::usdc(dec!(10))?)
+                .side(Side::Buy)
+                .price(dec!(0.7))
+                .max_slippage(
+                    MaxSlippage::builder()
+                        .bound(SlippageBound::Ticks(2))
+                        .build(),
+                )
+                .build()
+                .await
+                .unwrap_err();
+            let exceeded = err.downcast_ref::<SlippageExceeded>().unwrap();
+
+            assert_eq!(exceeded.bound_price, dec!(0.6));
+            assert_eq!(exceeded.limit_price, dec!(0.7));
+
+            Ok(())
+        }
+    }
+}
+
+mod ladder {
+    use polymarket_client_sdk::error::Validation;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn buy_should_step_down_from_first_price() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        let orders = client
+            .ladder_order()
+            .token_id(token_1())
+            .side(Side::Buy)
+            .price(dec!(0.5))
+            .levels(3)
+            .size(Decimal::TEN)
+            .build()
+            .await?;
+
+        let prices: Vec<_> = orders.iter().map(|order| order.limit_price).collect();
+        assert_eq!(
+            prices,
+            vec![Some(dec!(0.5)), Some(dec!(0.49)), Some(dec!(0.48))]
+        );
+        for order in &orders {
+            assert_eq!(order.order.takerAmount, U256::from(10_000_000));
+            assert_eq!(order.order_type, OrderType::GTC);
+        }
+        assert_eq!(orders[2].order.makerAmount, U256::from(4_800_000));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sell_should_follow_size_curve() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        let orders = client
+            .ladder_order()
+            .token_id(token_1())
+            .side(Side::Sell)
+            .price(dec!(0.6))
+            .step_ticks(5)
+            .sizes([Decimal::TEN, dec!(20), dec!(40)])
+            .build()
+            .await?;
+
+        let levels: Vec<_> = orders
+            .iter()
+            .map(|order| (order.limit_price, order.order.makerAmount))
+            .collect();
+        assert_eq!(
+            levels,
+            vec![
+                (Some(dec!(0.6)), U256::from(10_000_000)),
+                (Some(dec!(0.65)), U256::from(20_000_000)),
+                (Some(dec!(0.7)), U256::from(40_000_000)),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_fail_when_last_level_is_out_of_range() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Tenth);
+
+        let err = client
+            .ladder_order()
+            .token_id(token_1())
+            .side(Side::Buy)
+            .price(dec!(0.3))
+            .levels(4)
+            .size(Decimal::TEN)
+            .build()
+            .await
+            .unwrap_err();
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+
+        assert_eq!(
+            msg,
+            "Ladder prices from 0.3 to 0.0 are too small or too large for the minimum tick size 0.1"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn should_fail_when_sizes_do_not_match_levels() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let err = client
+            .ladder_order()
+            .token_id(token_1())
+            .side(Side::Buy)
+            .price(dec!(0.3))
+            .levels(3)
+            .sizes([Decimal::TEN, Decimal::TEN])
+            .build()
+            .await
+            .unwrap_err();
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+
+        assert_eq!(msg, "Unable to build ladder with 2 sizes for 3 levels");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn build_offline_should_not_contact_clob() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let orders = client
+            .ladder_order()
+            .token_id(token_1())
+            .side(Side::Sell)
+            .price(dec!(0.9))
+            .levels(2)
+            .size(Decimal::ONE)
+            .tick_size(TickSize::Thousandth)
+            .fee_rate_bps(0)
+            .neg_risk(false)
+            .build_offline()?;
+
+        assert_eq!(orders.len(), 2);
+        assert_eq!(orders[1].limit_price, Some(dec!(0.901)));
+
+        Ok(())
+    }
+}
+
+mod offline {
+    use alloy::signers::Signer as _;
+    use alloy::signers::local::LocalSigner;
+    use polymarket_client_sdk::POLYGON;
+    use polymarket_client_sdk::auth::Credentials;
+    use polymarket_client_sdk::clob::types::{CrossingPolicy, MaxSlippage, SlippageBound};
+    use polymarket_client_sdk::clob::{Client, Config};
+    use polymarket_client_sdk::error::Validation;
+
+    use super::*;
+    use crate::common::{API_KEY, PASSPHRASE, PRIVATE_KEY, SECRET, TestClient};
+
+    /// Creates a client from existing credentials, which does not contact the CLOB.
+    async fn client(server: &MockServer) -> anyhow::Result<TestClient> {
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let credentials = Credentials::new(API_KEY, SECRET.to_owned(), PASSPHRASE.to_owned());
+
+        Ok(Client::new(&server.base_url(), Config::default())?
+            .authentication_builder(&signer)
+            .credentials(credentials)
+            .salt_generator(|| 1)
+            .authenticate()
+            .await?)
+    }
+
+    #[tokio::test]
+    async fn limit_order_should_build_and_sign_without_network() -> anyhow::Result<()> {
+        // No mocks are registered, so any request would fail
+        let server = MockServer::start();
+        let client = client(&server).await?;
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+
+        let signable_order = client
+            .limit_order()
+            .token_id(token_1())
+            .price(dec!(0.34))
+            .size(Decimal::ONE_HUNDRED)
+            .side(Side::Buy)
+            .tick_size(TickSize::Hundredth)
+            .fee_rate_bps(10)
+            .neg_risk(true)
+            .build_offline()?;
+
+        assert_eq!(signable_order.neg_risk, Some(true));
+        assert_eq!(signable_order.order.feeRateBps, U256::from(10));
+
+        let signed_order = client.sign(&signer, signable_order).await?;
+        signed_order.verify(POLYGON, true)?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn build_offline_should_match_build() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = client(&server).await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Thousandth);
+
+        let online = client
+            .market_order()
+            .token_id(token_1())
+            .price(dec!(0.512))
+            .amount(Amount::usdc(Decimal::ONE_HUNDRED)?)
+            .side(Side::Buy)
+            .build()
+            .await?;
+
+        let offline = client
+            .market_order()
+            .token_id(token_1())
+            .price(dec!(0.512))
+            .amount(Amount::usdc(Decimal::ONE_HUNDRED)?)
+            .side(Side::Buy)
+            .tick_size(TickSize::Thousandth)
+            .fee_rate_bps(0)
+            .neg_risk(false)
+            .build_offline()?;
+
+        assert_eq!(online.order, offline.order);
+        assert_eq!(online.order_type, offline.order_type);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn build_offline_without_market_parameters_should_fail() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = client(&server).await?;
+
+        let err = client
+            .limit_order()
+            .token_id(token_1())
+            .price(dec!(0.34))
+            .size(Decimal::ONE_HUNDRED)
+            .side(Side::Buy)
+            .tick_size(TickSize::Hundredth)
+            .fee_rate_bps(0)
+            .build_offline()
+            .unwrap_err();
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+        assert_eq!(
+            msg,
+            "Unable to build Order offline without tick size, fee rate and neg risk"
+        );
+
+        let err = client
+            .market_order()
+            .token_id(token_1())
+            .amount(Amount::shares(Decimal::ONE_HUNDRED)?)
+            .side(Side::Sell)
+            .tick_size(TickSize::Hundredth)
+            .fee_rate_bps(0)
+            .neg_risk(false)
+            .build_offline()
+            .unwrap_err();
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+        assert_eq!(msg, "Unable to build market Order offline without a price");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn build_offline_with_max_slippage_should_fail() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = client(&server).await?;
+
+        let err = client
+            .market_order()
+            .token_id(token_1())
+            .amount(Amount::shares(Decimal::ONE_HUNDRED)?)
+            .side(Side::Sell)
+            .price(dec!(0.34))
+            .tick_size(TickSize::Hundredth)
+            .fee_rate_bps(0)
+            .neg_risk(false)
+            .max_slippage(
+                MaxSlippage::builder()
+                    .bound(SlippageBound::Ticks(1))
+                    .build(),
+            )
+            .build_offline()
+            .unwrap_err();
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+        assert_eq!(
+            msg,
+            "Unable to bound slippage offline without an order book"
+        );
+
+        Ok(())
+    }
+    #[tokio::test]
+    async fn build_offline_with_crossing_policy_should_fail() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = client(&server).await?;
+
+        let err = client
+            .limit_order()
+            .token_id(token_1())
+            .size(Decimal::ONE_HUNDRED)
+            .side(Side::Buy)
+            .price(dec!(0.34))
+            .tick_size(TickSize::Hundredth)
+            .fee_rate_bps(0)
+            .neg_risk(false)
+            .crossing_policy(CrossingPolicy::Reject)
+            .build_offline()
+            .unwrap_err();
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+        assert_eq!(
+            msg,
+            "Unable to check crossing offline without our open orders"
+        );
+
+        Ok(())
+    }
 }
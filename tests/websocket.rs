@@ -265,6 +265,8 @@ pub mod payloads {
 mod market_channel {
     use std::str::FromStr as _;
 
+    use polymarket_client_sdk::clob::types::TickSize;
+    use polymarket_client_sdk::clob::{Client as RestClient, Config as RestConfig};
     use rust_decimal_macros::dec;
 
     use super::*;
@@ -366,6 +368,59 @@ mod market_channel {
         assert_eq!(tsc.timestamp, 100_000_000);
     }
 
+    #[tokio::test]
+    async fn subscribe_synced_tick_sizes_updates_rest_cache() {
+        let mut server = MockWsServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+
+        let client = Client::new(&endpoint, Config::default()).unwrap();
+        // No REST server is listening, so the tick size must come from the cache
+        let rest = RestClient::new("http://127.0.0.1:1", RestConfig::default()).unwrap();
+        rest.set_tick_size(payloads::asset_id(), TickSize::Hundredth);
+
+        let stream = client
+            .subscribe_synced_tick_sizes(vec![payloads::asset_id()], rest.clone())
+            .unwrap();
+        let mut stream = Box::pin(stream);
+
+        let _: Option<String> = server.recv_subscription().await;
+        server.send(&payloads::book().to_string());
+        server.send(&payloads::tick_size_change().to_string());
+
+        let result = timeout(Duration::from_secs(2), stream.next()).await;
+        let change = result.unwrap().unwrap().unwrap();
+        assert_eq!(change.new_tick_size, dec!(0.001));
+
+        let cached = rest.tick_size(payloads::asset_id()).await.unwrap();
+        assert_eq!(cached.minimum_tick_size, TickSize::Thousandth);
+    }
+
+    #[tokio::test]
+    async fn subscribe_synced_tick_sizes_rejects_unknown_tick_size() {
+        let mut server = MockWsServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+
+        let client = Client::new(&endpoint, Config::default()).unwrap();
+        let rest = RestClient::new("http://127.0.0.1:1", RestConfig::default()).unwrap();
+        rest.set_tick_size(payloads::asset_id(), TickSize::Hundredth);
+
+        let stream = client
+            .subscribe_synced_tick_sizes(vec![payloads::asset_id()], rest.clone())
+            .unwrap();
+        let mut stream = Box::pin(stream);
+
+        let _: Option<String> = server.recv_subscription().await;
+        let mut change = payloads::tick_size_change();
+        change["new_tick_size"] = json!("0.005");
+        server.send(&change.to_string());
+
+        let result = timeout(Duration::from_secs(2), stream.next()).await;
+        result.unwrap().unwrap().unwrap_err();
+
+        let cached = rest.tick_size(payloads::asset_id()).await.unwrap();
+        assert_eq!(cached.minimum_tick_size, TickSize::Hundredth);
+    }
+
     #[tokio::test]
     async fn filters_messages_by_asset_id() {
         let mut server = MockWsServer::start().await;
@@ -457,6 +512,81 @@ mod market_channel {
         let midpoint = result.unwrap().unwrap().unwrap();
         assert_eq!(midpoint.midpoint, dec!(0.50));
     }
+
+    #[tokio::test]
+    async fn subscribe_local_orderbook_applies_price_changes() {
+        let mut server = MockWsServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+
+        let config = Config::default();
+        let client = Client::new(&endpoint, config).unwrap();
+
+        let stream = client
+            .subscribe_local_orderbook(vec![payloads::asset_id()])
+            .unwrap();
+        let mut stream = Box::pin(stream);
+
+        let _: Option<String> = server.recv_subscription().await;
+
+        // Price changes before the first snapshot are ignored
+        server.send(&payloads::price_change_batch(payloads::asset_id()).to_string());
+        server.send(&payloads::book().to_string());
+
+        let result = timeout(Duration::from_secs(2), stream.next()).await;
+        let book = result.unwrap().unwrap().unwrap();
+        assert_eq!(book.best_bid().unwrap().price, dec!(0.50));
+        assert_eq!(book.best_ask().unwrap().price, dec!(0.52));
+        assert_eq!(book.depth(), (3, 3));
+
+        let mut change = payloads::price_change_batch(payloads::asset_id());
+        change["price_changes"][0]["price"] = json!("0.51");
+        change["price_changes"][0]["size"] = json!("5");
+        change["price_changes"][0]["best_bid"] = json!("0.51");
+        change["price_changes"][0]["best_ask"] = json!("0.52");
+        server.send(&change.to_string());
+
+        let result = timeout(Duration::from_secs(2), stream.next()).await;
+        let book = result.unwrap().unwrap().unwrap();
+        assert_eq!(book.best_bid().unwrap().price, dec!(0.51));
+        assert_eq!(book.best_bid().unwrap().size, dec!(5));
+        assert_eq!(book.depth(), (4, 3));
+        assert_eq!(
+            book.hash(),
+            Some("56621a121a47ed9333273e21c83b660cff37ae50")
+        );
+    }
+
+    #[tokio::test]
+    async fn subscribe_local_orderbook_reports_out_of_sync_book() {
+        let mut server = MockWsServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+
+        let config = Config::default();
+        let client = Client::new(&endpoint, config).unwrap();
+
+        let stream = client
+            .subscribe_local_orderbook(vec![payloads::asset_id()])
+            .unwrap();
+        let mut stream = Box::pin(stream);
+
+        let _: Option<String> = server.recv_subscription().await;
+
+        server.send(&payloads::book().to_string());
+        let result = timeout(Duration::from_secs(2), stream.next()).await;
+        result.unwrap().unwrap().unwrap();
+
+        // Server claims a best ask the local book has never seen
+        let mut change = payloads::price_change_batch(payloads::asset_id());
+        change["price_changes"][0]["best_ask"] = json!("0.51");
+        server.send(&change.to_string());
+
+        let result = timeout(Duration::from_secs(2), stream.next()).await;
+        let err = result.unwrap().unwrap().unwrap_err();
+        assert!(
+            err.to_string().contains("out of sync"),
+            "unexpected error: {err}"
+        );
+    }
 }
 
 mod user_channel {
@@ -730,9 +860,526 @@ mod user_channel {
     }
 }
 
+mod order_manager {
+    use std::str::FromStr as _;
+
+    use alloy::signers::Signer as _;
+    use alloy::signers::local::LocalSigner;
+    use httpmock::Method::{DELETE, GET, POST};
+    use httpmock::MockServer;
+    use polymarket_client_sdk::POLYGON;
+    use polymarket_client_sdk::clob::types::{OrderStatusType, Side, TickSize};
+    use polymarket_client_sdk::clob::ws::types::response::TradeMessageStatus;
+    use polymarket_client_sdk::clob::ws::{OrderEvent, OrderManager};
+    use polymarket_client_sdk::types::Decimal;
+    use reqwest::StatusCode;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::common::{PRIVATE_KEY, create_authenticated, token_1};
+
+    const ORDER_ID: &str = "0xff354cd7ca7539dfa9c28d90943ab5779a4eac34b9b37a757d7b32bdfb11790b";
+
+    fn open_order(status: &str, size_matched: &str) -> serde_json::Value {
+        json!({
+            "id": ORDER_ID,
+            "status": status,
+            "owner": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+            "maker_address": "0x2222222222222222222222222222222222222222",
+            "market": payloads::MARKET_STR,
+            "asset_id": payloads::ASSET_ID_STR,
+            "side": "SELL",
+            "original_size": "10",
+            "size_matched": size_matched,
+            "price": "0.57",
+            "associate_trades": [],
+            "outcome": "YES",
+            "created_at": 1_705_322_096,
+            "expiration": "0",
+            "order_type": "GTC"
+        })
+    }
+
+    async fn next_event(
+        events: &mut (impl futures_util::Stream<Item = OrderEvent> + Unpin),
+    ) -> OrderEvent {
+        timeout(Duration::from_secs(2), events.next())
+            .await
+            .unwrap()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn run_should_reconcile_then_follow_user_channel() -> anyhow::Result<()> {
+        let mut ws_server = MockWsServer::start().await;
+        let server = MockServer::start();
+        let rest = create_authenticated(&server).await?;
+        let orders = server.mock(|when, then| {
+            when.method(GET).path("/data/orders");
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [open_order("LIVE", "0")],
+                "limit": 1,
+                "count": 1,
+                "next_cursor": "LTE="
+            }));
+        });
+
+        let ws = Client::new(&format!("ws://{}", ws_server.addr), Config::default())?
+            .authenticate(rest.credentials().clone(), rest.address())?;
+        let manager = OrderManager::new(rest);
+        let mut events = Box::pin(manager.events());
+
+        let runner = manager.clone();
+        tokio::spawn(async move { runner.run(&ws, vec![]).await });
+
+        let OrderEvent::Placed(order) = next_event(&mut events).await else {
+            panic!("expected the open order to be placed by reconciliation");
+        };
+        assert_eq!(order.id, ORDER_ID);
+        assert_eq!(order.status, OrderStatusType::Live);
+        orders.assert();
+
+        ws_server.recv_subscription().await.unwrap();
+        let mut update = payloads::order();
+        update["type"] = json!("UPDATE");
+        update["size_matched"] = json!("4");
+        ws_server.send(&update.to_string());
+
+        let OrderEvent::Updated(order) = next_event(&mut events).await else {
+            panic!("expected the order to be updated");
+        };
+        assert_eq!(order.size_matched, dec!(4));
+        assert_eq!(order.remaining_size(), dec!(6));
+        assert!(order.is_open());
+
+        ws_server.send(&payloads::trade().to_string());
+
+        let OrderEvent::Trade { order_ids, trade } = next_event(&mut events).await else {
+            panic!("expected the trade to be reported");
+        };
+        assert_eq!(order_ids, vec![ORDER_ID.to_owned()]);
+        assert_eq!(trade.status, TradeMessageStatus::Matched);
+        assert_eq!(
+            manager.order(ORDER_ID).unwrap().trades[&trade.id],
+            TradeMessageStatus::Matched
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reconcile_should_fetch_orders_closed_while_away() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let rest = create_authenticated(&server).await?;
+        let mut orders = server.mock(|when, then| {
+            when.method(GET).path("/data/orders");
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [open_order("LIVE", "0")],
+                "limit": 1,
+                "count": 1,
+                "next_cursor": "LTE="
+            }));
+        });
+
+        let manager = OrderManager::new(rest);
+        manager.reconcile().await?;
+        assert_eq!(manager.open_orders().len(), 1);
+
+        orders.delete();
+        server.mock(|when, then| {
+            when.method(GET).path("/data/orders");
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [],
+                "limit": 0,
+                "count": 0,
+                "next_cursor": "LTE="
+            }));
+        });
+        let order = server.mock(|when, then| {
+            when.method(GET).path(format!("/data/order/{ORDER_ID}"));
+            then.status(StatusCode::OK)
+                .json_body(open_order("MATCHED", "10"));
+        });
+
+        let mut events = Box::pin(manager.events());
+        manager.reconcile().await?;
+
+        let OrderEvent::Updated(tracked) = next_event(&mut events).await else {
+            panic!("expected the closed order to be updated");
+        };
+        assert_eq!(tracked.status, OrderStatusType::Matched);
+        assert_eq!(tracked.remaining_size(), Decimal::ZERO);
+        assert!(manager.open_orders().is_empty());
+        order.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn post_and_cancel_should_track_order() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let rest = create_authenticated(&server).await?;
+        let order_id = "0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0";
+        server.mock(|when, then| {
+            when.method(POST).path("/order");
+            then.status(StatusCode::OK).json_body(json!({
+                "error_msg": "",
+                "makingAmount": "",
+                "orderID": order_id,
+                "status": "live",
+                "success": true,
+                "takingAmount": ""
+            }));
+        });
+        server.mock(|when, then| {
+            when.method(DELETE).path("/order");
+            then.status(StatusCode::OK).json_body(json!({
+                "canceled": [order_id],
+                "notCanceled": {}
+            }));
+        });
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let order = rest
+            .limit_order()
+            .token_id(token_1())
+            .price(dec!(0.34))
+            .size(Decimal::ONE_HUNDRED)
+            .side(Side::Buy)
+            .tick_size(TickSize::Hundredth)
+            .fee_rate_bps(0)
+            .neg_risk(false)
+            .build_offline()?;
+        let signed_order = rest.sign(&signer, order).await?;
+
+        let manager = OrderManager::new(rest);
+        let mut events = Box::pin(manager.events());
+        manager.post_order(signed_order).await?;
+
+        let OrderEvent::Placed(tracked) = next_event(&mut events).await else {
+            panic!("expected the posted order to be placed");
+        };
+        assert_eq!(tracked.id, order_id);
+        assert_eq!(tracked.side, Side::Buy);
+        assert_eq!(tracked.price, dec!(0.34));
+        assert_eq!(tracked.original_size, Decimal::ONE_HUNDRED);
+        assert_eq!(tracked.asset_id, token_1());
+
+        manager.cancel_order(order_id).await?;
+
+        let OrderEvent::Canceled(tracked) = next_event(&mut events).await else {
+            panic!("expected the order to be canceled");
+        };
+        assert_eq!(tracked.status, OrderStatusType::Canceled);
+        assert!(manager.open_orders().is_empty());
+
+        Ok(())
+    }
+}
+
+mod paper_exchange {
+    use alloy::primitives::Signature;
+    use alloy::signers::SignerSync as _;
+    use alloy::signers::local::PrivateKeySigner;
+    use polymarket_client_sdk::POLYGON;
+    use polymarket_client_sdk::clob::traits::Trading;
+    use polymarket_client_sdk::clob::types::request::{
+        CancelMarketOrderRequest, OrdersRequest, TradesRequest,
+    };
+    use polymarket_client_sdk::clob::types::response::{OpenOrderResponse, TradeResponse};
+    use polymarket_client_sdk::clob::types::{
+        Order, OrderStatusType, OrderType, Side, SignedOrder, TraderSide,
+    };
+    use polymarket_client_sdk::clob::ws::PaperExchange;
+    use polymarket_client_sdk::clob::ws::types::response::OrderMessageType;
+    use polymarket_client_sdk::error::Kind as ErrorKind;
+    use polymarket_client_sdk::types::Decimal;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::common::{API_KEY, PRIVATE_KEY};
+
+    fn snapshot(timestamp: i64, bids: &[(&str, &str)], asks: &[(&str, &str)]) -> WsMessage {
+        let levels = |levels: &[(&str, &str)]| {
+            levels
+                .iter()
+                .map(|(price, size)| json!({ "price": price, "size": size }))
+                .collect::<Vec<_>>()
+        };
+
+        serde_json::from_value(json!({
+            "event_type": "book",
+            "asset_id": payloads::ASSET_ID_STR,
+            "market": payloads::MARKET_STR,
+            "timestamp": timestamp.to_string(),
+            "bids": levels(bids),
+            "asks": levels(asks)
+        }))
+        .unwrap()
+    }
+
+    /// Creates an order for `size` shares at `price`, as fixed point amounts with 6 decimals.
+    fn order(side: Side, price: u64, size: u64, order_type: OrderType) -> SignedOrder {
+        let notional = price * size / 1_000_000;
+        let (maker_amount, taker_amount) = match side {
+            Side::Buy => (notional, size),
+            _ => (size, notional),
+        };
+
+        let mut order = Order::default();
+        order.salt = U256::from(price ^ size);
+        order.tokenId = payloads::asset_id();
+        order.makerAmount = U256::from(maker_amount);
+        order.takerAmount = U256::from(taker_amount);
+        order.side = side as u8;
+
+        SignedOrder::builder()
+            .order(order)
+            .signature(Signature::new(U256::ZERO, U256::ZERO, false))
+            .order_type(order_type)
+            .owner(API_KEY)
+            .build()
+    }
+
+    async fn next_message(
+        events: &mut (impl futures_util::Stream<Item = WsMessage> + Unpin),
+    ) -> WsMessage {
+        timeout(Duration::from_secs(2), events.next())
+            .await
+            .unwrap()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn post_order_should_take_liquidity_best_price_first() -> anyhow::Result<()> {
+        let exchange = PaperExchange::new(POLYGON);
+        exchange.apply(&snapshot(
+            1_000_000,
+            &[("0.48", "10")],
+            &[("0.50", "10"), ("0.52", "10")],
+        ));
+        let mut events = Box::pin(exchange.events());
+
+        let response = exchange
+            .post_order(order(Side::Buy, 520_000, 15_000_000, OrderType::GTC))
+            .await?;
+
+        assert!(response.success);
+        assert_eq!(response.status, OrderStatusType::Matched);
+        assert_eq!(response.making_amount, dec!(7.6));
+        assert_eq!(response.taking_amount, dec!(15));
+        assert_eq!(response.trade_ids.len(), 2);
+
+        for (price, size) in [(dec!(0.50), dec!(10)), (dec!(0.52), dec!(5))] {
+            let WsMessage::Trade(trade) = next_message(&mut events).await else {
+                panic!("expected a trade");
+            };
+            assert_eq!(trade.price, price);
+            assert_eq!(trade.size, size);
+            assert_eq!(
+                trade.taker_order_id.as_deref(),
+                Some(response.order_id.as_str())
+            );
+            assert_eq!(trade.trader_side, Some(TraderSide::Taker));
+        }
+
+        let book = exchange.book(payloads::asset_id()).unwrap();
+        assert_eq!(book.best_ask().unwrap().price, dec!(0.52));
+        assert_eq!(book.best_ask().unwrap().size, dec!(5));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn resting_order_should_fill_when_snapshot_crosses_it() -> anyhow::Result<()> {
+        let exchange = PaperExchange::new(POLYGON);
+        exchange.apply(&snapshot(1_000_000, &[("0.40", "10")], &[("0.50", "10")]));
+        let mut events = Box::pin(exchange.events());
+
+        let first = exchange
+            .post_order(order(Side::Buy, 450_000, 10_000_000, OrderType::GTC))
+            .await?;
+        let second = exchange
+            .post_order(order(Side::Buy, 450_000, 5_000_000, OrderType::GTC))
+            .await?;
+        assert_eq!(first.status, OrderStatusType::Live);
+        assert_eq!(first.taking_amount, Decimal::ZERO);
+
+        for order_id in [&first.order_id, &second.order_id] {
+            let WsMessage::Order(placed) = next_message(&mut events).await else {
+                panic!("expected the order to be placed");
+            };
+            assert_eq!(&placed.id, order_id);
+            assert_eq!(placed.msg_type, Some(OrderMessageType::Placement));
+        }
+
+        // Only enough liquidity for the earlier of the two orders at the same price
+        exchange.apply(&snapshot(2_000_000, &[("0.40", "10")], &[("0.44", "4")]));
+
+        let WsMessage::Trade(trade) = next_message(&mut events).await else {
+            panic!("expected a trade");
+        };
+        assert_eq!(trade.price, dec!(0.45));
+        assert_eq!(trade.size, dec!(4));
+        assert_eq!(trade.trader_side, Some(TraderSide::Maker));
+        assert_eq!(trade.maker_orders[0].order_id, first.order_id);
+        assert_eq!(trade.matchtime, Some(2000));
+
+        let WsMessage::Order(update) = next_message(&mut events).await else {
+            panic!("expected the order to be updated");
+        };
+        assert_eq!(update.id, first.order_id);
+        assert_eq!(update.size_matched, Some(dec!(4)));
+        assert_eq!(update.status, Some(OrderStatusType::Live));
+
+        let response = exchange.cancel_all_orders().await?;
+        assert_eq!(response.canceled.len(), 2);
+
+        let response = exchange.cancel_order(&first.order_id).await?;
+        assert!(response.canceled.is_empty());
+        assert!(response.not_canceled.contains_key(&first.order_id));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn post_order_should_reject_what_the_clob_would() -> anyhow::Result<()> {
+        let exchange = PaperExchange::new(POLYGON);
+
+        let response = exchange
+            .post_order(order(Side::Sell, 500_000, 10_000_000, OrderType::GTC))
+            .await?;
+        assert!(!response.success);
+        assert!(response.error_msg.unwrap().contains("does not exist"));
+
+        exchange.apply(&snapshot(1_000_000, &[("0.50", "4")], &[("0.55", "10")]));
+
+        let response = exchange
+            .post_order(order(Side::Sell, 500_000, 10_000_000, OrderType::FOK))
+            .await?;
+        assert!(!response.success);
+        assert_eq!(response.status, OrderStatusType::Unmatched);
+
+        let mut post_only = order(Side::Sell, 500_000, 2_000_000, OrderType::GTC);
+        post_only.post_only = Some(true);
+        let response = exchange.post_order(post_only).await?;
+        assert!(!response.success);
+        assert!(response.error_msg.unwrap().contains("post-only"));
+
+        // Rejected orders leave the book untouched, and whatever is left of a FAK is dropped
+        let response = exchange
+            .post_order(order(Side::Sell, 500_000, 10_000_000, OrderType::FAK))
+            .await?;
+        assert!(response.success);
+        assert_eq!(response.making_amount, dec!(4));
+        assert_eq!(response.taking_amount, dec!(2));
+        assert_eq!(response.status, OrderStatusType::Matched);
+        assert!(exchange.cancel_all_orders().await?.canceled.is_empty());
+
+        Ok(())
+    }
+    #[tokio::test]
+    async fn order_ids_should_use_the_neg_risk_exchange_for_neg_risk_orders() -> anyhow::Result<()>
+    {
+        let exchange = PaperExchange::new(POLYGON);
+        exchange.apply(&snapshot(1_000_000, &[("0.40", "10")], &[("0.60", "10")]));
+
+        let signer: PrivateKeySigner = PRIVATE_KEY.parse()?;
+        let mut signed = order(Side::Buy, 500_000, 10_000_000, OrderType::GTC);
+        signed.order.signer = signer.address();
+        signed.order.maker = signer.address();
+        signed.signature = signer.sign_hash_sync(&signed.order.hash(POLYGON, true)?)?;
+
+        let order_id = signed.order_id(POLYGON, true)?;
+        let response = exchange.post_order(signed).await?;
+        assert_eq!(response.order_id, order_id);
+
+        // Unsigned orders follow the neg risk flag of their asset
+        let unsigned = order(Side::Buy, 450_000, 10_000_000, OrderType::GTC);
+        exchange.set_neg_risk(payloads::asset_id(), true);
+
+        let order_id = unsigned.order_id(POLYGON, true)?;
+        let response = exchange.post_order(unsigned).await?;
+        assert_eq!(response.order_id, order_id);
+
+        Ok(())
+    }
+
+    /// Places `order` and reads back its state through [`Trading`] alone, as a strategy would.
+    async fn place(
+        backend: &impl Trading,
+        order: SignedOrder,
+    ) -> anyhow::Result<(OpenOrderResponse, Vec<TradeResponse>)> {
+        let response = backend.post_order(order).await?;
+        let order = backend.order(&response.order_id).await?;
+        let trades = backend
+            .trades(
+                &TradesRequest::builder().asset_id(order.asset_id).build(),
+                None,
+            )
+            .await?;
+
+        Ok((order, trades.data))
+    }
+
+    #[tokio::test]
+    async fn trading_should_report_orders_and_trades() -> anyhow::Result<()> {
+        let exchange = PaperExchange::new(POLYGON);
+        exchange.apply(&snapshot(1_000_000, &[("0.48", "10")], &[("0.50", "10")]));
+
+        let (filled, trades) = place(
+            &exchange,
+            order(Side::Buy, 500_000, 4_000_000, OrderType::GTC),
+        )
+        .await?;
+        assert_eq!(filled.status, OrderStatusType::Matched);
+        assert_eq!(filled.size_matched, dec!(4));
+        assert_eq!(trades.len(), 1);
+        assert_eq!(filled.associate_trades, vec![trades[0].id.clone()]);
+        assert_eq!(trades[0].taker_order_id, filled.id);
+        assert_eq!(trades[0].size, dec!(4));
+        assert_eq!(trades[0].price, dec!(0.50));
+
+        let (resting, _) = place(
+            &exchange,
+            order(Side::Buy, 450_000, 2_000_000, OrderType::GTC),
+        )
+        .await?;
+        assert_eq!(resting.status, OrderStatusType::Live);
+
+        let open = Trading::orders(&exchange, &OrdersRequest::default(), None).await?;
+        assert_eq!(open.data, vec![resting.clone()]);
+
+        let canceled = Trading::cancel_market_orders(
+            &exchange,
+            &CancelMarketOrderRequest::builder()
+                .asset_id(resting.asset_id)
+                .build(),
+        )
+        .await?;
+        assert_eq!(canceled.canceled, vec![resting.id.clone()]);
+        assert_eq!(
+            Trading::order(&exchange, &resting.id).await?.status,
+            OrderStatusType::Canceled
+        );
+        let err = Trading::order(&exchange, "0x01").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Status);
+
+        Ok(())
+    }
+}
+
 mod reconnection {
     use std::sync::atomic::{AtomicBool, Ordering};
 
+    use httpmock::MockServer;
+    use polymarket_client_sdk::clob::types::TickSize;
+    use polymarket_client_sdk::clob::types::response::OrderBookSummaryResponse;
+    use polymarket_client_sdk::clob::ws::{BookEvent, Divergence, OutOfSync, StaleReason};
+    use polymarket_client_sdk::clob::{Client as RestClient, Config as RestConfig};
+    use reqwest::StatusCode;
+    use rust_decimal_macros::dec;
+
     use super::*;
 
     /// Mock WebSocket server that can simulate disconnections and send messages.
@@ -891,6 +1538,55 @@ mod reconnection {
         );
     }
 
+    #[tokio::test]
+    async fn synced_tick_sizes_drop_cache_after_reconnect() {
+        let mut server = ReconnectableMockServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+        let rest_server = MockServer::start();
+        let tick_size = rest_server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/tick-size")
+                .query_param("token_id", payloads::OTHER_ASSET_ID_STR);
+            then.status(StatusCode::OK)
+                .json_body(json!({ "minimum_tick_size": "0.1" }));
+        });
+
+        let client = Client::new(&endpoint, config()).unwrap();
+        let rest = RestClient::new(&rest_server.base_url(), RestConfig::default()).unwrap();
+        rest.set_tick_size(payloads::asset_id(), TickSize::Hundredth);
+        rest.set_tick_size(payloads::other_asset_id(), TickSize::Hundredth);
+
+        let stream = client
+            .subscribe_synced_tick_sizes(
+                vec![payloads::asset_id(), payloads::other_asset_id()],
+                rest.clone(),
+            )
+            .unwrap();
+        // Keep polling throughout, as the cache is only maintained while the stream is driven
+        let (tx, mut changes) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            let mut stream = Box::pin(stream);
+            while let Some(change) = stream.next().await {
+                _ = tx.send(change);
+            }
+        });
+        let _: Option<String> = server.recv_subscription().await;
+
+        server.disconnect_all();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        server.allow_reconnect();
+        let _: Option<String> = server.recv_subscription().await;
+
+        server.send(&payloads::tick_size_change().to_string());
+        let result = timeout(Duration::from_secs(5), changes.recv()).await;
+        assert_eq!(result.unwrap().unwrap().unwrap().new_tick_size, dec!(0.001));
+
+        // The other asset's tick size may have changed while disconnected, so it is re-fetched
+        let other = rest.tick_size(payloads::other_asset_id()).await.unwrap();
+        assert_eq!(other.minimum_tick_size, TickSize::Tenth);
+        tick_size.assert();
+    }
+
     #[tokio::test]
     async fn resubscribes_all_assets_after_reconnect() {
         let mut server = ReconnectableMockServer::start().await;
@@ -1036,6 +1732,183 @@ mod reconnection {
             "Should receive best_bid_ask message after reconnection - this was the bug in issue #185"
         );
     }
+
+    fn summary(timestamp: &str) -> serde_json::Value {
+        let mut summary = json!({
+            "market": payloads::MARKET_STR,
+            "asset_id": payloads::ASSET_ID_STR,
+            "tick_size": "0.01",
+            "min_order_size": "5",
+            "neg_risk": false,
+            "timestamp": timestamp,
+            "hash": "",
+            "bids": [{ "price": "0.45", "size": "10" }],
+            "asks": [{ "price": "0.55", "size": "12" }]
+        });
+        let response: OrderBookSummaryResponse = serde_json::from_value(summary.clone()).unwrap();
+        summary["hash"] = json!(response.summary_hash().unwrap());
+
+        json!([summary])
+    }
+
+    #[tokio::test]
+    async fn synced_orderbook_refetches_snapshot_after_reconnect() {
+        let mut server = ReconnectableMockServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+        let rest_server = MockServer::start();
+        let books = rest_server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/books")
+                .json_body(json!([{ "token_id": payloads::ASSET_ID_STR }]));
+            then.status(StatusCode::OK)
+                .json_body(summary("123456790000"));
+        });
+
+        let client = Client::new(&endpoint, config()).unwrap();
+        let rest = RestClient::new(&rest_server.base_url(), RestConfig::default()).unwrap();
+
+        let stream = client
+            .subscribe_synced_orderbook(vec![payloads::asset_id()], rest)
+            .unwrap();
+        let mut stream = Box::pin(stream);
+
+        let _: Option<String> = server.recv_subscription().await;
+        server.send(&payloads::book().to_string());
+
+        let event = timeout(Duration::from_secs(2), stream.next()).await;
+        let BookEvent::Updated(book) = event.unwrap().unwrap().unwrap() else {
+            panic!("expected the WebSocket snapshot");
+        };
+        assert_eq!(book.depth(), (3, 3));
+
+        server.disconnect_all();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        server.allow_reconnect();
+
+        let event = timeout(Duration::from_secs(5), stream.next()).await;
+        let BookEvent::Stale { asset_ids, reason } = event.unwrap().unwrap().unwrap() else {
+            panic!("expected books to be marked stale after reconnect");
+        };
+        assert_eq!(asset_ids, vec![payloads::asset_id()]);
+        assert_eq!(reason, StaleReason::Reconnected);
+
+        let event = timeout(Duration::from_secs(2), stream.next()).await;
+        let BookEvent::Updated(book) = event.unwrap().unwrap().unwrap() else {
+            panic!("expected the REST snapshot");
+        };
+        assert_eq!(book.depth(), (1, 1));
+        assert_eq!(book.best_bid().unwrap().price, dec!(0.45));
+        assert_eq!(book.hash(), summary("123456790000")[0]["hash"].as_str());
+        books.assert();
+    }
+
+    #[tokio::test]
+    async fn synced_orderbook_rejects_snapshot_with_mismatched_hash() {
+        let mut server = ReconnectableMockServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+        let rest_server = MockServer::start();
+        let mut tampered = summary("123456790000");
+        tampered[0]["bids"][0]["size"] = json!("11");
+        let books = rest_server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/books");
+            then.status(StatusCode::OK).json_body(tampered);
+        });
+
+        let client = Client::new(&endpoint, config()).unwrap();
+        let rest = RestClient::new(&rest_server.base_url(), RestConfig::default()).unwrap();
+
+        let stream = client
+            .subscribe_synced_orderbook(vec![payloads::asset_id()], rest)
+            .unwrap();
+        let mut stream = Box::pin(stream);
+
+        let _: Option<String> = server.recv_subscription().await;
+        server.send(&payloads::book().to_string());
+        let event = timeout(Duration::from_secs(2), stream.next()).await;
+        event.unwrap().unwrap().unwrap();
+
+        server.disconnect_all();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        server.allow_reconnect();
+
+        let event = timeout(Duration::from_secs(5), stream.next()).await;
+        let BookEvent::Stale { .. } = event.unwrap().unwrap().unwrap() else {
+            panic!("expected books to be marked stale after reconnect");
+        };
+
+        let event = timeout(Duration::from_secs(2), stream.next()).await;
+        let err = event.unwrap().unwrap().unwrap_err();
+        let out_of_sync = err.downcast_ref::<OutOfSync>().unwrap();
+        assert_eq!(out_of_sync.asset_id, payloads::asset_id());
+        assert!(
+            matches!(out_of_sync.divergence, Divergence::Hash { .. }),
+            "unexpected divergence: {:?}",
+            out_of_sync.divergence
+        );
+        books.assert();
+    }
+
+    #[tokio::test]
+    async fn synced_orderbook_refetches_snapshot_when_out_of_sync() {
+        let mut server = ReconnectableMockServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+        let rest_server = MockServer::start();
+        let books = rest_server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/books");
+            then.status(StatusCode::OK)
+                .json_body(summary("123456790000"));
+        });
+
+        let client = Client::new(&endpoint, config()).unwrap();
+        let rest = RestClient::new(&rest_server.base_url(), RestConfig::default()).unwrap();
+
+        let stream = client
+            .subscribe_synced_orderbook(vec![payloads::asset_id()], rest)
+            .unwrap();
+        let mut stream = Box::pin(stream);
+
+        let _: Option<String> = server.recv_subscription().await;
+        server.send(&payloads::book().to_string());
+        let event = timeout(Duration::from_secs(2), stream.next()).await;
+        event.unwrap().unwrap().unwrap();
+
+        // Server reports a best ask the local book has never seen
+        let mut change = payloads::price_change_batch(payloads::asset_id());
+        change["timestamp"] = json!("123456789500");
+        change["price_changes"][0]["best_ask"] = json!("0.51");
+        server.send(&change.to_string());
+
+        let event = timeout(Duration::from_secs(2), stream.next()).await;
+        let BookEvent::Stale { reason, .. } = event.unwrap().unwrap().unwrap() else {
+            panic!("expected the book to be marked stale");
+        };
+        assert_eq!(reason, StaleReason::OutOfSync);
+
+        let event = timeout(Duration::from_secs(2), stream.next()).await;
+        let BookEvent::Updated(book) = event.unwrap().unwrap().unwrap() else {
+            panic!("expected the REST snapshot");
+        };
+        assert_eq!(book.best_ask().unwrap().price, dec!(0.55));
+        books.assert();
+
+        // Changes older than the REST snapshot are skipped
+        let mut change = payloads::price_change_batch(payloads::asset_id());
+        change["timestamp"] = json!("123456789900");
+        server.send(&change.to_string());
+        let mut change = payloads::price_change_batch(payloads::asset_id());
+        change["timestamp"] = json!("123456791000");
+        change["price_changes"][0]["price"] = json!("0.46");
+        change["price_changes"][0]["best_bid"] = json!("0.46");
+        change["price_changes"][0]["best_ask"] = json!("0.55");
+        server.send(&change.to_string());
+
+        let event = timeout(Duration::from_secs(2), stream.next()).await;
+        let BookEvent::Updated(book) = event.unwrap().unwrap().unwrap() else {
+            panic!("expected the level update");
+        };
+        assert_eq!(book.depth(), (2, 1));
+        assert_eq!(book.best_bid().unwrap().price, dec!(0.46));
+    }
 }
 
 mod unsubscribe {